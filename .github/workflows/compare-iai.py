@@ -3,10 +3,14 @@
 import json
 import sys
 
-with open(sys.argv[1]) as json_file:
-    old = json.load(json_file)
-with open(sys.argv[2]) as json_file:
-    new = json.load(json_file)
+def read_instructions(path):
+    # A report written by the advent-of-code-benchmark tool:
+    with open(path) as json_file:
+        report = json.load(json_file)
+    return {m["name"]: m["instructions"] for m in report["measurements"]}
+
+old = read_instructions(sys.argv[1])
+new = read_instructions(sys.argv[2])
 
 # Table titles:
 title_name = "Name"
@@ -21,11 +25,10 @@ max_new_time_len = len(title_new)
 max_old_time_len = len(title_old)
 
 for benchmark_name in old:
-    old_values = old[benchmark_name]
-    new_values = new[benchmark_name]
-
-    old_time = old_values["instructions"]
-    new_time = new_values["instructions"]
+    if benchmark_name not in new:
+        continue
+    old_time = old[benchmark_name]
+    new_time = new[benchmark_name]
 
     total_time += new_time
 
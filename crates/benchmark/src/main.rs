@@ -0,0 +1,165 @@
+#![forbid(unsafe_code)]
+/*!
+Benchmarks the solutions to all puzzles, writing the results as JSON or CSV and flagging
+regressions compared to a baseline saved from an earlier run.
+*/
+mod measure;
+mod report;
+
+use std::env;
+use std::process::Command;
+
+use measure::Options;
+use report::{Format, Report};
+
+const USAGE: &str = "usage: advent-of-code-benchmark [--variant scalar|simd|wasm|wasm-simd] [--format json|csv] [--output file] [--baseline file] [--threshold percent] [--iterations count] [--instructions] [filter]";
+
+/// Removes an option with a value from the arguments, returning the value.
+fn take_option(args: &mut Vec<String>, name: &str) -> Result<Option<String>, String> {
+    match args.iter().position(|arg| arg == name) {
+        Some(index) if index + 1 < args.len() => {
+            let value = args.remove(index + 1);
+            args.remove(index);
+            Ok(Some(value))
+        }
+        Some(_) => Err(USAGE.to_string()),
+        None => Ok(None),
+    }
+}
+
+/// Removes a flag from the arguments, returning whether it was present.
+fn take_flag(args: &mut Vec<String>, name: &str) -> bool {
+    args.iter()
+        .position(|arg| arg == name)
+        .map(|index| args.remove(index))
+        .is_some()
+}
+
+/// Builds and runs this tool as another variant with cargo, passing on the arguments.
+fn run_variant(variant: &str, args: &[String]) -> Result<(), String> {
+    let (simd, wasm) = match variant {
+        "scalar" => (false, false),
+        "simd" => (true, false),
+        "wasm" => (false, true),
+        "wasm-simd" => (true, true),
+        _ => return Err(format!("Invalid variant '{variant}'\n{USAGE}")),
+    };
+
+    let mut command = Command::new("cargo");
+    if simd {
+        // The simd feature requires a nightly toolchain:
+        let toolchain = env::var("NIGHTLY_TOOLCHAIN").unwrap_or_else(|_| "nightly".to_string());
+        command.arg(format!("+{toolchain}"));
+    }
+    command.args(["run", "--release", "--package", env!("CARGO_PKG_NAME")]);
+
+    let mut features = Vec::new();
+    if simd {
+        features.push("simd");
+    }
+    if cfg!(feature = "count-allocations") {
+        features.push("count-allocations");
+    }
+    if !features.is_empty() {
+        command.args(["--features", &features.join(",")]);
+    }
+
+    if wasm {
+        command.args(["--target", "wasm32-wasip1"]);
+        // Give the runtime access to the inputs, and to the current directory for output:
+        command.env(
+            "CARGO_TARGET_WASM32_WASIP1_RUNNER",
+            format!("wasmtime --dir=. --dir={}", measure::INPUT_DIRECTORY),
+        );
+        if simd {
+            command.env("RUSTFLAGS", "-C target-feature=+simd128");
+        }
+    } else {
+        command.env("RUSTFLAGS", "-C target-cpu=native");
+    }
+
+    let status = command
+        .arg("--")
+        .args(args)
+        .status()
+        .map_err(|error| format!("Unable to run cargo: {error}"))?;
+    if !status.success() {
+        std::process::exit(status.code().unwrap_or(1));
+    }
+    Ok(())
+}
+
+#[allow(clippy::print_stdout)]
+#[allow(clippy::print_stderr)]
+fn main() -> Result<(), String> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+
+    // Used internally when counting instructions:
+    if args.first().is_some_and(|arg| arg == "--solve-once") {
+        return measure::solve_once(&args[1..]);
+    }
+
+    if let Some(variant) = take_option(&mut args, "--variant")? {
+        return run_variant(&variant, &args);
+    }
+
+    let format = take_option(&mut args, "--format")?
+        .map_or(Ok(Format::Json), |name| Format::parse(&name))?;
+    let output = take_option(&mut args, "--output")?;
+    let baseline = take_option(&mut args, "--baseline")?;
+    let threshold = take_option(&mut args, "--threshold")?
+        .map_or(Ok(10.), |value| value.parse::<f64>())
+        .map_err(|_| "Invalid threshold")?;
+    let iterations = take_option(&mut args, "--iterations")?
+        .map_or(Ok(10), |value| value.parse::<usize>())
+        .map_err(|_| "Invalid iterations")?;
+    let count_instructions = take_flag(&mut args, "--instructions");
+    if args.len() > 1 || args.first().is_some_and(|arg| arg.starts_with('-')) {
+        return Err(USAGE.to_string());
+    }
+
+    // Read the baseline before measuring, so that an invalid one is noticed directly:
+    let baseline = baseline
+        .map(|path| {
+            std::fs::read_to_string(&path)
+                .map_err(|error| format!("Unable to read {path}: {error}"))
+                .and_then(|json| Report::from_json(&json))
+        })
+        .transpose()?;
+
+    let report = measure::run(&Options {
+        filter: args.pop(),
+        iterations,
+        count_instructions,
+    })?;
+
+    let written = report.write(format)?;
+    match output {
+        Some(path) => std::fs::write(&path, written)
+            .map_err(|error| format!("Unable to write {path}: {error}"))?,
+        None => print!("{written}"),
+    }
+
+    if let Some(baseline) = baseline {
+        if baseline.variant != report.variant {
+            eprintln!(
+                "Warning: Comparing the {} variant against a baseline of the {} variant",
+                report.variant, baseline.variant
+            );
+        }
+        let changes = report.compare(&baseline, threshold);
+        for change in &changes {
+            eprintln!("{change}");
+        }
+        let regressions = changes
+            .iter()
+            .filter(|change| change.is_regression())
+            .count();
+        if regressions > 0 {
+            return Err(format!(
+                "{regressions} regression(s) compared to the baseline"
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use std::hint::black_box;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use advent_of_code::{Puzzle, puzzles, solve};
+
+use crate::report::{Measurement, Report};
+
+/// The directory containing the puzzle inputs, as in `year2022/day17_input.txt`.
+pub const INPUT_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../core/src");
+
+/// The build variant of this binary.
+pub const VARIANT: &str = match (cfg!(target_family = "wasm"), cfg!(feature = "simd")) {
+    (false, false) => "scalar",
+    (false, true) => "simd",
+    (true, false) => "wasm",
+    (true, true) => "wasm-simd",
+};
+
+/// Timing of a solution stops after this, if it has been solved at least once.
+const MAX_TIMING_DURATION: Duration = Duration::from_secs(1);
+
+/// Options for which puzzles to measure, and how.
+pub struct Options {
+    /// Only measures benchmarks whose names start with this, as in `2022` or `2022_17`.
+    pub filter: Option<String>,
+    /// The maximum number of timed solves of each part.
+    pub iterations: usize,
+    /// Whether to count executed instructions, which requires valgrind.
+    pub count_instructions: bool,
+}
+
+fn read_input(year: u16, day: u8) -> Result<String, String> {
+    let path = format!("{INPUT_DIRECTORY}/year{year}/day{day:02}_input.txt");
+    std::fs::read_to_string(&path).map_err(|error| format!("Unable to read {path}: {error}"))
+}
+
+/// Measures the parts of all puzzles matching the filter, reporting progress on stderr.
+#[allow(clippy::print_stderr)]
+pub fn run(options: &Options) -> Result<Report, String> {
+    // The instructions executed by a run of this binary solving nothing, to be subtracted:
+    let calibration = if options.count_instructions {
+        count_instructions(&[])?
+    } else {
+        0
+    };
+
+    let mut measurements = Vec::new();
+    for puzzle in puzzles() {
+        for part in 1..=puzzle.parts {
+            let name = format!("{}_{:02}_{part}", puzzle.year, puzzle.day);
+            if options
+                .filter
+                .as_ref()
+                .is_some_and(|filter| !name.starts_with(filter.as_str()))
+            {
+                continue;
+            }
+            let input = read_input(puzzle.year, puzzle.day)?;
+            let mut measurement = measure(name, puzzle, part, &input, options.iterations)?;
+            if options.count_instructions {
+                let args = [puzzle.year, u16::from(puzzle.day), u16::from(part)]
+                    .map(|arg| arg.to_string());
+                measurement.instructions =
+                    Some(count_instructions(&args)?.saturating_sub(calibration));
+            }
+            eprintln!(
+                "{} {:>10.3} ms",
+                measurement.name,
+                Duration::from_nanos(measurement.median_nanos).as_secs_f64() * 1000.
+            );
+            measurements.push(measurement);
+        }
+    }
+    Ok(Report {
+        variant: VARIANT.to_string(),
+        measurements,
+    })
+}
+
+fn measure(
+    name: String,
+    puzzle: Puzzle,
+    part: u8,
+    input: &str,
+    iterations: usize,
+) -> Result<Measurement, String> {
+    let solve_once = || solve(puzzle.year, puzzle.day, part, black_box(input));
+
+    // Warm up, and check that the puzzle is solved:
+    solve_once().map_err(|error| format!("{name}: {error}"))?;
+
+    let timing_start = Instant::now();
+    let mut durations = Vec::with_capacity(iterations);
+    while durations.len() < iterations.max(1)
+        && (durations.is_empty() || timing_start.elapsed() < MAX_TIMING_DURATION)
+    {
+        let start = Instant::now();
+        let _ = black_box(solve_once());
+        durations.push(u64::try_from(start.elapsed().as_nanos()).unwrap_or(u64::MAX));
+    }
+    durations.sort_unstable();
+
+    #[cfg(feature = "count-allocations")]
+    let (allocations, allocated_bytes, peak_heap_bytes) = {
+        let (_, stats) =
+            advent_of_code::solve_with_allocation_stats(puzzle.year, puzzle.day, part, input);
+        (
+            Some(stats.allocations),
+            Some(stats.allocated_bytes),
+            Some(stats.peak_heap_bytes),
+        )
+    };
+    #[cfg(not(feature = "count-allocations"))]
+    let (allocations, allocated_bytes, peak_heap_bytes) = (None, None, None);
+
+    Ok(Measurement {
+        name,
+        year: puzzle.year,
+        day: puzzle.day,
+        part,
+        iterations: durations.len(),
+        median_nanos: durations[durations.len() / 2],
+        min_nanos: durations[0],
+        allocations,
+        allocated_bytes,
+        peak_heap_bytes,
+        instructions: None,
+    })
+}
+
+/// Solves a puzzle given as `[year, day, part]` once, for counting instructions under
+/// valgrind. Solves nothing if no puzzle is given, for calibration.
+pub fn solve_once(args: &[String]) -> Result<(), String> {
+    if let [year, day, part] = args {
+        let (Ok(year), Ok(day), Ok(part)) = (year.parse(), day.parse(), part.parse()) else {
+            return Err(format!("Invalid puzzle: {}", args.join(" ")));
+        };
+        let input = read_input(year, day)?;
+        black_box(solve(year, day, part, &input))?;
+    }
+    Ok(())
+}
+
+/// Counts the instructions executed by this binary solving the puzzle given by `args`
+/// with `--solve-once`, using the cachegrind tool of valgrind.
+fn count_instructions(args: &[String]) -> Result<u64, String> {
+    let executable = std::env::current_exe()
+        .map_err(|error| format!("Unable to find the benchmark executable: {error}"))?;
+    let output = Command::new("valgrind")
+        .args([
+            "--tool=cachegrind",
+            "--cache-sim=no",
+            "--cachegrind-out-file=/dev/null",
+        ])
+        .arg(executable)
+        .arg("--solve-once")
+        .args(args)
+        .output()
+        .map_err(|error| format!("Counting instructions requires valgrind: {error}"))?;
+    let summary = String::from_utf8_lossy(&output.stderr);
+    if !output.status.success() {
+        return Err(format!("Counting instructions failed: {summary}"));
+    }
+    summary
+        .lines()
+        .find_map(parse_instruction_count)
+        .ok_or_else(|| format!("No instruction count in valgrind output: {summary}"))
+}
+
+/// Parses the instruction count from a summary line such as `==123== I refs: 1,234,567`.
+fn parse_instruction_count(line: &str) -> Option<u64> {
+    let (label, count) = line.split_once("refs:")?;
+    if !label.trim_end().ends_with('I') {
+        return None;
+    }
+    count.trim().replace(',', "").parse().ok()
+}
+
+#[test]
+fn instruction_count() {
+    assert_eq!(
+        parse_instruction_count("==4711== I   refs:      1,234,567"),
+        Some(1_234_567)
+    );
+    assert_eq!(parse_instruction_count("==4711== I refs: 89"), Some(89));
+    assert_eq!(
+        parse_instruction_count("==4711== D   refs:      1,234"),
+        None
+    );
+    assert_eq!(parse_instruction_count("==4711== Collected : 1,234"), None);
+}
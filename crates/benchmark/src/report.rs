@@ -0,0 +1,262 @@
+use std::fmt::{self, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+/// The measurements of one benchmark run, all made with the same build variant.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    /// The build variant which was measured, as in `scalar`, `simd` or `wasm`.
+    pub variant: String,
+    pub measurements: Vec<Measurement>,
+}
+
+/// The measurements of solving one part of a puzzle.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Measurement {
+    /// The name of the benchmark, as in `2022_17_2`.
+    pub name: String,
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The number of timed solves.
+    pub iterations: usize,
+    /// The median wall time of the timed solves.
+    pub median_nanos: u64,
+    /// The shortest wall time of the timed solves.
+    pub min_nanos: u64,
+    /// The number of heap allocations, with the `count-allocations` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocations: Option<u64>,
+    /// The total number of bytes allocated, with the `count-allocations` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allocated_bytes: Option<u64>,
+    /// The highest number of bytes allocated at the same time, with the `count-allocations`
+    /// feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_heap_bytes: Option<u64>,
+    /// The number of executed instructions, if counted with `--instructions`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<u64>,
+}
+
+/// The format to write a report in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "json" => Ok(Self::Json),
+            "csv" => Ok(Self::Csv),
+            _ => Err(format!("Invalid format '{name}' - must be json or csv")),
+        }
+    }
+}
+
+/// A metric which changed by more than the threshold compared to a baseline.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Change {
+    /// The name of the benchmark, as in `2022_17_2`.
+    pub name: String,
+    /// The name of the metric, as in `medianNanos`.
+    pub metric: &'static str,
+    pub baseline: u64,
+    pub current: u64,
+}
+
+impl Change {
+    pub const fn is_regression(&self) -> bool {
+        self.current > self.baseline
+    }
+
+    /// The relative change in percent, or `None` if the baseline is zero.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn percent(&self) -> Option<f64> {
+        (self.baseline != 0)
+            .then(|| (self.current as f64 - self.baseline as f64) * 100. / self.baseline as f64)
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}: {} -> {}",
+            self.name, self.metric, self.baseline, self.current
+        )?;
+        if let Some(percent) = self.percent() {
+            write!(f, " ({percent:+.1}%)")?;
+        }
+        f.write_str(if self.is_regression() {
+            " REGRESSION"
+        } else {
+            " improvement"
+        })
+    }
+}
+
+impl Report {
+    pub fn from_json(json: &str) -> Result<Self, String> {
+        serde_json::from_str(json).map_err(|error| format!("Invalid report: {error}"))
+    }
+
+    pub fn write(&self, format: Format) -> Result<String, String> {
+        match format {
+            Format::Json => serde_json::to_string_pretty(self)
+                .map(|json| json + "\n")
+                .map_err(|error| error.to_string()),
+            Format::Csv => Ok(self.to_csv()),
+        }
+    }
+
+    fn to_csv(&self) -> String {
+        let optional =
+            |value: Option<u64>| value.map(|value| value.to_string()).unwrap_or_default();
+        let mut csv = String::from(
+            "variant,name,year,day,part,iterations,medianNanos,minNanos,allocations,allocatedBytes,peakHeapBytes,instructions\n",
+        );
+        for m in &self.measurements {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{},{},{},{},{},{},{}\n",
+                self.variant,
+                m.name,
+                m.year,
+                m.day,
+                m.part,
+                m.iterations,
+                m.median_nanos,
+                m.min_nanos,
+                optional(m.allocations),
+                optional(m.allocated_bytes),
+                optional(m.peak_heap_bytes),
+                optional(m.instructions)
+            ));
+        }
+        csv
+    }
+
+    /// Returns the metrics which changed by more than `threshold_percent` compared to
+    /// `baseline`, for benchmarks and metrics present in both reports.
+    ///
+    /// Allocations are compared exactly, since they do not vary between runs.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn compare(&self, baseline: &Self, threshold_percent: f64) -> Vec<Change> {
+        let mut changes = Vec::new();
+        for current in &self.measurements {
+            let Some(previous) = baseline
+                .measurements
+                .iter()
+                .find(|previous| previous.name == current.name)
+            else {
+                continue;
+            };
+            for (metric, threshold, baseline_value, current_value) in [
+                (
+                    "medianNanos",
+                    threshold_percent,
+                    Some(previous.median_nanos),
+                    Some(current.median_nanos),
+                ),
+                (
+                    "instructions",
+                    threshold_percent,
+                    previous.instructions,
+                    current.instructions,
+                ),
+                ("allocations", 0., previous.allocations, current.allocations),
+            ] {
+                if let (Some(baseline_value), Some(current_value)) = (baseline_value, current_value)
+                {
+                    let limit = baseline_value as f64 * threshold / 100.;
+                    if (current_value as f64 - baseline_value as f64).abs() > limit {
+                        changes.push(Change {
+                            name: current.name.clone(),
+                            metric,
+                            baseline: baseline_value,
+                            current: current_value,
+                        });
+                    }
+                }
+            }
+        }
+        changes
+    }
+}
+
+#[cfg(test)]
+fn measurement(name: &str, median_nanos: u64, allocations: Option<u64>) -> Measurement {
+    Measurement {
+        name: name.to_string(),
+        year: name[0..4].parse().unwrap_or_default(),
+        day: name[5..7].parse().unwrap_or_default(),
+        part: name[8..9].parse().unwrap_or_default(),
+        iterations: 10,
+        median_nanos,
+        min_nanos: median_nanos / 2,
+        allocations,
+        allocated_bytes: allocations.map(|count| count * 16),
+        peak_heap_bytes: allocations.map(|count| count * 8),
+        instructions: None,
+    }
+}
+
+#[test]
+fn write_and_read() {
+    let report = Report {
+        variant: "scalar".to_string(),
+        measurements: vec![
+            measurement("2022_17_2", 1000, Some(3)),
+            measurement("2023_01_1", 200, None),
+        ],
+    };
+    assert_eq!(
+        report.write(Format::Csv),
+        Ok("variant,name,year,day,part,iterations,medianNanos,minNanos,allocations,allocatedBytes,peakHeapBytes,instructions
+scalar,2022_17_2,2022,17,2,10,1000,500,3,48,24,
+scalar,2023_01_1,2023,1,1,10,200,100,,,,
+"
+        .to_string())
+    );
+
+    let json = report.write(Format::Json).unwrap_or_default();
+    assert!(json.contains(r#""medianNanos": 1000"#));
+    assert!(!json.contains("instructions"));
+    assert_eq!(Report::from_json(&json), Ok(report));
+    assert!(Report::from_json("{}").is_err());
+}
+
+#[test]
+fn compare_with_baseline() {
+    let baseline = Report {
+        variant: "scalar".to_string(),
+        measurements: vec![
+            measurement("2022_17_1", 1000, Some(0)),
+            measurement("2022_17_2", 1000, Some(5)),
+            measurement("2022_18_1", 1000, None),
+        ],
+    };
+    let current = Report {
+        variant: "scalar".to_string(),
+        measurements: vec![
+            measurement("2022_17_1", 1050, Some(1)),
+            measurement("2022_17_2", 800, Some(5)),
+            measurement("2022_18_1", 1200, None),
+            measurement("2022_19_1", 1000, None),
+        ],
+    };
+    let changes = current.compare(&baseline, 10.);
+    let descriptions = changes.iter().map(ToString::to_string).collect::<Vec<_>>();
+    assert_eq!(
+        descriptions,
+        [
+            "2022_17_1 allocations: 0 -> 1 REGRESSION",
+            "2022_17_2 medianNanos: 1000 -> 800 (-20.0%) improvement",
+            "2022_18_1 medianNanos: 1000 -> 1200 (+20.0%) REGRESSION",
+        ]
+    );
+    assert!(current.compare(&current, 10.).is_empty());
+}
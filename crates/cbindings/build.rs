@@ -5,7 +5,10 @@ fn main() {
     #![allow(clippy::unwrap_used, clippy::expect_used)]
 
     let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let generated_header_path = target_dir().join("advent-of-code.h");
+    // The checked in header in include/, which C users can build against without running
+    // cbindgen, is compared with this generated one by a test.
+    let generated_header_path =
+        PathBuf::from(env::var("OUT_DIR").unwrap()).join("advent-of-code.h");
 
     cbindgen::Builder::new()
         .with_crate(crate_dir)
@@ -15,15 +18,6 @@ fn main() {
         .generate()
         .expect("Unable to generate bindings")
         .write_to_file(generated_header_path);
-}
 
-/// Find the location of the `target/` directory. Note that this may be
-/// overridden by `cmake`, so we also need to check the `CARGO_TARGET_DIR`
-/// variable.
-fn target_dir() -> PathBuf {
-    #![allow(clippy::unwrap_used)]
-    env::var("CARGO_TARGET_DIR").map_or_else(
-        |_| PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("target"),
-        PathBuf::from,
-    )
+    println!("cargo::rerun-if-changed=src/lib.rs");
 }
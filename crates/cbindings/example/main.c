@@ -1,14 +1,31 @@
 #include <advent-of-code.h>
 #include <stdio.h>
+#include <string.h>
+
+// Passing NULL for %s is undefined behaviour, so print it as glibc does:
+const char* or_null(const char* string) {
+    return string == NULL ? "(null)" : string;
+}
 
 void test(uint16_t year, uint8_t day, uint8_t part, const char* input) {
     bool ok;
 
     char* result = advent_of_code_solve(year, day, part, input, &ok);
 
-    printf("%d-%d-%d: Input='%s' -> ok=%s, output='%s'\n", year, day, part, input, ok ? "true" : "false", result);
+    printf("%d-%d-%d: Input='%s' -> ok=%s, output='%s'\n", year, day, part, or_null(input), ok ? "true" : "false", or_null(result));
+
+    advent_of_code_free_string(result);
+}
+
+void test_into(uint16_t year, uint8_t day, uint8_t part, const char* input, size_t buffer_size) {
+    char buffer[64];
+    size_t required_size = 0;
 
-    free(result);
+    int32_t code = advent_of_code_solve_into(year, day, part, input, buffer, buffer_size, &required_size);
+
+    printf("%d-%d-%d: Input='%s', buffer_size=%zu -> code=%d ('%s'), required_size=%zu, output='%s'\n",
+        year, day, part, or_null(input), buffer_size, code, advent_of_code_error_message(code), required_size,
+        code == ADVENT_OF_CODE_OK || code == ADVENT_OF_CODE_ERROR_INVALID_INPUT ? buffer : "");
 }
 
 int main() {
@@ -19,5 +36,26 @@ int main() {
     test(2019, 1, 1, NULL);
     test(2019, 1, 1, "\xc3\x28");
     test(2021, 7, 2, "16,1,2,0,4,2,7,1,2,14");
+
+    // Not leaking or crashing:
+    advent_of_code_free_string(advent_of_code_solve(2019, 1, 1, "14", NULL));
+    advent_of_code_free_string(NULL);
+
+    test_into(2019, 1, 1, "14", 64);
+    test_into(2019, 1, 1, "14", 1);
+    test_into(2019, 1, 1, "hej", 64);
+    test_into(2019, 30, 1, "14", 64);
+    test_into(2019, 1, 1, NULL, 64);
+
+    printf("version: %s\n", strlen(advent_of_code_version()) > 0 ? "present" : "missing");
+
+    size_t puzzle_count = advent_of_code_puzzle_count();
+    AdventOfCodePuzzle puzzle;
+    advent_of_code_puzzle(0, &puzzle);
+    printf("puzzles: %zu, first=%d-%d (%d parts)", puzzle_count, puzzle.year, puzzle.day, puzzle.parts);
+    advent_of_code_puzzle(puzzle_count - 1, &puzzle);
+    printf(", last=%d-%d (%d parts)\n", puzzle.year, puzzle.day, puzzle.parts);
+    int32_t code = advent_of_code_puzzle(puzzle_count, &puzzle);
+    printf("puzzle out of range: '%s'\n", advent_of_code_error_message(code));
     return 0;
 }
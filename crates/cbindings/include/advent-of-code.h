@@ -0,0 +1,142 @@
+#ifndef ADVENT_OF_CODE_H
+#define ADVENT_OF_CODE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The call succeeded.
+ */
+#define ADVENT_OF_CODE_OK 0
+
+/**
+ * A required pointer argument was NULL.
+ */
+#define ADVENT_OF_CODE_ERROR_NULL_ARGUMENT 1
+
+/**
+ * The input was not valid UTF-8.
+ */
+#define ADVENT_OF_CODE_ERROR_INVALID_UTF8 2
+
+/**
+ * There is no solution for the specified year, day and part.
+ */
+#define ADVENT_OF_CODE_ERROR_UNSUPPORTED_PUZZLE 3
+
+/**
+ * The input could not be solved, for instance due to being malformed.
+ */
+#define ADVENT_OF_CODE_ERROR_INVALID_INPUT 4
+
+/**
+ * The caller-provided buffer is too small.
+ */
+#define ADVENT_OF_CODE_ERROR_BUFFER_TOO_SMALL 5
+
+/**
+ * The puzzle index is outside the puzzle catalog.
+ */
+#define ADVENT_OF_CODE_ERROR_INVALID_INDEX 6
+
+/**
+ * A puzzle which this library has a solution for.
+ */
+typedef struct AdventOfCodePuzzle {
+  /**
+   * The year of the puzzle, as in 2018 or 2019.
+   */
+  uint16_t year;
+  /**
+   * The day of the puzzle, from 1 to 25.
+   */
+  uint8_t day;
+  /**
+   * The number of parts: 1 for the last day of a year, otherwise 2.
+   */
+  uint8_t parts;
+} AdventOfCodePuzzle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns the answer for the specified problem and input.
+ *
+ * Arguments:
+ * year: The year of the problem, as in 2018 or 2019.
+ * day: The day of the problem, from 1 to 25.
+ * part: The part of the problem, either 1 or 2.
+ * input: The input to the problem.
+ * ok: Set to whether the problem was solved, if not NULL.
+ *
+ * Returns:
+ * The computed answer as text, or an error message if not ok. The returned string
+ * must be released with advent_of_code_free_string().
+ */
+char *advent_of_code_solve(uint16_t year, uint8_t day, uint8_t part, const char *input, bool *ok);
+
+/**
+ * Releases a string returned by advent_of_code_solve(). Passing NULL does nothing.
+ */
+void advent_of_code_free_string(char *string);
+
+/**
+ * Solves the specified problem, writing the answer into a caller-provided buffer.
+ *
+ * Arguments:
+ * year: The year of the problem, as in 2018 or 2019.
+ * day: The day of the problem, from 1 to 25.
+ * part: The part of the problem, either 1 or 2.
+ * input: The input to the problem.
+ * buffer: Where to write the NUL-terminated answer, or error message on failure.
+ * buffer_size: The size of the buffer in bytes.
+ * required_size: Set to the buffer size needed for the text, including the NUL byte, if not NULL.
+ *
+ * Returns:
+ * ADVENT_OF_CODE_OK on success, otherwise an error code. If the text does not fit in the
+ * buffer ADVENT_OF_CODE_ERROR_BUFFER_TOO_SMALL is returned and nothing is written.
+ */
+int32_t advent_of_code_solve_into(uint16_t year,
+                                  uint8_t day,
+                                  uint8_t part,
+                                  const char *input,
+                                  char *buffer,
+                                  uintptr_t buffer_size,
+                                  uintptr_t *required_size);
+
+/**
+ * Returns a static description of an error code. The returned string must not be freed.
+ */
+const char *advent_of_code_error_message(int32_t code);
+
+/**
+ * Returns the version of this library, as in "2025.6.0". The returned string must not be freed.
+ */
+const char *advent_of_code_version(void);
+
+/**
+ * Returns the number of puzzles in the catalog enumerated by advent_of_code_puzzle().
+ */
+uintptr_t advent_of_code_puzzle_count(void);
+
+/**
+ * Looks up a puzzle in the catalog, which is ordered by year and day.
+ *
+ * Arguments:
+ * index: The index of the puzzle, from 0 to advent_of_code_puzzle_count() - 1.
+ * puzzle: Where to write the puzzle.
+ *
+ * Returns:
+ * ADVENT_OF_CODE_OK on success, otherwise an error code.
+ */
+int32_t advent_of_code_puzzle(uintptr_t index, struct AdventOfCodePuzzle *puzzle);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* ADVENT_OF_CODE_H */
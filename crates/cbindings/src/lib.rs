@@ -1,8 +1,76 @@
+use advent_of_code_service::{ErrorKind, solve_input};
 use libc::c_char;
 
 use std::ffi::CStr;
 use std::ffi::CString;
 
+/// The call succeeded.
+pub const ADVENT_OF_CODE_OK: i32 = 0;
+/// A required pointer argument was NULL.
+pub const ADVENT_OF_CODE_ERROR_NULL_ARGUMENT: i32 = 1;
+/// The input was not valid UTF-8.
+pub const ADVENT_OF_CODE_ERROR_INVALID_UTF8: i32 = 2;
+/// There is no solution for the specified year, day and part.
+pub const ADVENT_OF_CODE_ERROR_UNSUPPORTED_PUZZLE: i32 = 3;
+/// The input could not be solved, for instance due to being malformed.
+pub const ADVENT_OF_CODE_ERROR_INVALID_INPUT: i32 = 4;
+/// The caller-provided buffer is too small.
+pub const ADVENT_OF_CODE_ERROR_BUFFER_TOO_SMALL: i32 = 5;
+/// The puzzle index is outside the puzzle catalog.
+pub const ADVENT_OF_CODE_ERROR_INVALID_INDEX: i32 = 6;
+
+/// A puzzle which this library has a solution for.
+#[repr(C)]
+pub struct AdventOfCodePuzzle {
+    /// The year of the puzzle, as in 2018 or 2019.
+    pub year: u16,
+    /// The day of the puzzle, from 1 to 25.
+    pub day: u8,
+    /// The number of parts: 1 for the last day of a year, otherwise 2.
+    pub parts: u8,
+}
+
+/// Solves a problem, returning the answer or an error code together with an error message.
+fn solve_checked(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const c_char,
+) -> Result<String, (i32, String)> {
+    if input.is_null() {
+        return Err((
+            ADVENT_OF_CODE_ERROR_NULL_ARGUMENT,
+            "Input is NULL".to_string(),
+        ));
+    }
+
+    // SAFETY: Responsibility of the caller.
+    let c_str = unsafe { CStr::from_ptr(input) };
+    let input_string = c_str.to_str().map_err(|error| {
+        (
+            ADVENT_OF_CODE_ERROR_INVALID_UTF8,
+            format!("Invalid UTF-8 input: {error}"),
+        )
+    })?;
+
+    solve_input(year, day, part, input_string).map_err(|error| {
+        let code = match error.kind {
+            ErrorKind::UnsupportedPuzzle => ADVENT_OF_CODE_ERROR_UNSUPPORTED_PUZZLE,
+            ErrorKind::InvalidInput | ErrorKind::Timeout | ErrorKind::Cancelled => {
+                ADVENT_OF_CODE_ERROR_INVALID_INPUT
+            }
+        };
+        (code, error.message)
+    })
+}
+
+fn into_c_string(value: String) -> *mut c_char {
+    // Answers and error messages never contain NUL bytes, but avoid panicking across the FFI boundary:
+    CString::new(value)
+        .unwrap_or_else(|_| c"Internal error: NUL byte in result".to_owned())
+        .into_raw()
+}
+
 /// Returns the answer for the specified problem and input.
 ///
 /// Arguments:
@@ -10,9 +78,11 @@ use std::ffi::CString;
 /// day: The day of the problem, from 1 to 25.
 /// part: The part of the problem, either 1 or 2.
 /// input: The input to the problem.
+/// ok: Set to whether the problem was solved, if not NULL.
 ///
 /// Returns:
-/// The computed answer as text.
+/// The computed answer as text, or an error message if not ok. The returned string
+/// must be released with advent_of_code_free_string().
 #[unsafe(no_mangle)]
 pub extern "C" fn advent_of_code_solve(
     year: u16,
@@ -21,42 +91,148 @@ pub extern "C" fn advent_of_code_solve(
     input: *const c_char,
     ok: *mut bool,
 ) -> *mut c_char {
-    #![allow(clippy::unwrap_used, clippy::not_unsafe_ptr_arg_deref)]
+    #![allow(clippy::not_unsafe_ptr_arg_deref)]
 
-    use advent_of_code::solve;
+    let (success, text) = match solve_checked(year, day, part, input) {
+        Ok(answer) => (true, answer),
+        Err((_, message)) => (false, message),
+    };
+    if !ok.is_null() {
+        // SAFETY: Responsibility of the caller.
+        unsafe { *ok = success };
+    }
+    into_c_string(text)
+}
 
-    if input.is_null() {
+/// Releases a string returned by advent_of_code_solve(). Passing NULL does nothing.
+#[unsafe(no_mangle)]
+pub extern "C" fn advent_of_code_free_string(string: *mut c_char) {
+    #![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+    if !string.is_null() {
+        // SAFETY: The string was created by CString::into_raw() in this library,
+        // as required of the caller.
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// Solves the specified problem, writing the answer into a caller-provided buffer.
+///
+/// Arguments:
+/// year: The year of the problem, as in 2018 or 2019.
+/// day: The day of the problem, from 1 to 25.
+/// part: The part of the problem, either 1 or 2.
+/// input: The input to the problem.
+/// buffer: Where to write the NUL-terminated answer, or error message on failure.
+/// buffer_size: The size of the buffer in bytes.
+/// required_size: Set to the buffer size needed for the text, including the NUL byte, if not NULL.
+///
+/// Returns:
+/// ADVENT_OF_CODE_OK on success, otherwise an error code. If the text does not fit in the
+/// buffer ADVENT_OF_CODE_ERROR_BUFFER_TOO_SMALL is returned and nothing is written.
+#[unsafe(no_mangle)]
+pub extern "C" fn advent_of_code_solve_into(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: *const c_char,
+    buffer: *mut c_char,
+    buffer_size: usize,
+    required_size: *mut usize,
+) -> i32 {
+    #![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+    let (code, text) = match solve_checked(year, day, part, input) {
+        Ok(answer) => (ADVENT_OF_CODE_OK, answer),
+        Err(error) => error,
+    };
+
+    let needed_size = text.len() + 1;
+    if !required_size.is_null() {
         // SAFETY: Responsibility of the caller.
-        unsafe { *ok = false };
-        let c_str_result = CString::new("Input is NULL").unwrap();
-        return c_str_result.into_raw();
+        unsafe { *required_size = needed_size };
+    }
+    if buffer.is_null() {
+        return ADVENT_OF_CODE_ERROR_NULL_ARGUMENT;
+    } else if buffer_size < needed_size {
+        return ADVENT_OF_CODE_ERROR_BUFFER_TOO_SMALL;
     }
 
-    // SAFETY: Responsibility of the caller.
-    let c_str = unsafe { CStr::from_ptr(input) };
-    let input_string = match c_str.to_str() {
-        Ok(value) => value,
-        Err(error) => {
-            // SAFETY: Responsibility of the caller.
-            unsafe { *ok = false };
-            let c_str_result = CString::new(format!("Invalid UTF-8 input: {error}")).unwrap();
-            return c_str_result.into_raw();
-        }
+    // SAFETY: The buffer has been checked to hold buffer_size >= text.len() + 1 bytes,
+    // and is valid for writes as required of the caller.
+    unsafe {
+        std::ptr::copy_nonoverlapping(text.as_ptr(), buffer.cast::<u8>(), text.len());
+        *buffer.add(text.len()) = 0;
+    }
+    code
+}
+
+/// Returns a static description of an error code. The returned string must not be freed.
+#[unsafe(no_mangle)]
+pub const extern "C" fn advent_of_code_error_message(code: i32) -> *const c_char {
+    match code {
+        ADVENT_OF_CODE_OK => c"No error",
+        ADVENT_OF_CODE_ERROR_NULL_ARGUMENT => c"Required argument is NULL",
+        ADVENT_OF_CODE_ERROR_INVALID_UTF8 => c"Input is not valid UTF-8",
+        ADVENT_OF_CODE_ERROR_UNSUPPORTED_PUZZLE => c"Unsupported puzzle",
+        ADVENT_OF_CODE_ERROR_INVALID_INPUT => c"Invalid input",
+        ADVENT_OF_CODE_ERROR_BUFFER_TOO_SMALL => c"Buffer too small",
+        ADVENT_OF_CODE_ERROR_INVALID_INDEX => c"Invalid puzzle index",
+        _ => c"Unknown error code",
+    }
+    .as_ptr()
+}
+
+/// Returns the version of this library, as in "2025.6.0". The returned string must not be freed.
+#[unsafe(no_mangle)]
+pub const extern "C" fn advent_of_code_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0")
+        .as_ptr()
+        .cast::<c_char>()
+}
+
+/// Returns the number of puzzles in the catalog enumerated by advent_of_code_puzzle().
+#[unsafe(no_mangle)]
+pub extern "C" fn advent_of_code_puzzle_count() -> usize {
+    advent_of_code::puzzles().count()
+}
+
+/// Looks up a puzzle in the catalog, which is ordered by year and day.
+///
+/// Arguments:
+/// index: The index of the puzzle, from 0 to advent_of_code_puzzle_count() - 1.
+/// puzzle: Where to write the puzzle.
+///
+/// Returns:
+/// ADVENT_OF_CODE_OK on success, otherwise an error code.
+#[unsafe(no_mangle)]
+pub extern "C" fn advent_of_code_puzzle(index: usize, puzzle: *mut AdventOfCodePuzzle) -> i32 {
+    #![allow(clippy::not_unsafe_ptr_arg_deref)]
+
+    if puzzle.is_null() {
+        return ADVENT_OF_CODE_ERROR_NULL_ARGUMENT;
+    }
+    let Some(found) = advent_of_code::puzzles().nth(index) else {
+        return ADVENT_OF_CODE_ERROR_INVALID_INDEX;
     };
+    // SAFETY: Responsibility of the caller.
+    unsafe {
+        *puzzle = AdventOfCodePuzzle {
+            year: found.year,
+            day: found.day,
+            parts: found.parts,
+        };
+    }
+    ADVENT_OF_CODE_OK
+}
 
-    let result = solve(year, day, part, input_string);
-    match result {
-        Ok(value) => {
-            // SAFETY: Responsibility of the caller.
-            unsafe { *ok = true };
-            let c_str_result = CString::new(value).unwrap();
-            c_str_result.into_raw()
-        }
-        Err(value) => {
-            // SAFETY: Responsibility of the caller.
-            unsafe { *ok = false };
-            let c_str_result = CString::new(value).unwrap();
-            c_str_result.into_raw()
-        }
+#[test]
+fn header() {
+    // Run with UPDATE_HEADER=1 to update the checked in header after changing the API.
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/include/advent-of-code.h");
+    let generated = include_str!(concat!(env!("OUT_DIR"), "/advent-of-code.h"));
+    if std::env::var_os("UPDATE_HEADER").is_some() {
+        std::fs::write(path, generated).unwrap();
     }
+    assert_eq!(std::fs::read_to_string(path).unwrap(), generated);
 }
@@ -1,6 +1,8 @@
 #!/bin/sh
 set -e -u
 
+# The checked in header must match the one generated from the sources:
+cargo -q test
 cargo -q build
 cd example/
 
@@ -11,10 +13,14 @@ if [ `uname` = Linux ]; then
   LIBS="$LIBS -lm -lpthread -ldl"
 fi
 
-$CC -I ../target/ -L ../../../target/debug/ main.c $LIBS -o ../target/example
+$CC -I ../include/ -L ../../../target/debug/ main.c $LIBS -o ../target/example
 ../target/example > generated-output.txt
 diff -u expected-output.txt generated-output.txt
 
 if [ `uname` = Linux ]; then
-    valgrind  ../target/example
+    valgrind --error-exitcode=1 --leak-check=full ../target/example > /dev/null
 fi
+
+# Also check with AddressSanitizer, which includes leak detection on Linux:
+$CC -fsanitize=address -fno-omit-frame-pointer -I ../include/ -L ../../../target/debug/ main.c $LIBS -o ../target/example-asan
+../target/example-asan > /dev/null
@@ -1,22 +1,19 @@
-use advent_of_code::solve;
+use advent_of_code::{puzzles, solve};
 use criterion::{Criterion, criterion_group, criterion_main};
 use std::fs::read_to_string;
 
 pub fn criterion_benchmark(c: &mut Criterion) {
     #![allow(clippy::unwrap_used)]
-    for year in 2015..=2025 {
-        let start_day = 1;
-        let end_day = if year >= 2025 { 12 } else { 25 };
-        for day in start_day..=end_day {
-            let input_path = format!("src/year{year}/day{day:02}_input.txt");
-            let input = read_to_string(input_path).unwrap();
+    for puzzle in puzzles() {
+        let (year, day) = (puzzle.year, puzzle.day);
+        let input_path = format!("src/year{year}/day{day:02}_input.txt");
+        let input = read_to_string(input_path).unwrap();
 
-            for part in 1..=(if day == 25 { 1 } else { 2 }) {
-                let benchmark_name = format!("{year}_{day:02}_{part}");
-                c.bench_function(&benchmark_name, |b| {
-                    b.iter(|| solve(year, day, part, &input));
-                });
-            }
+        for part in 1..=puzzle.parts {
+            let benchmark_name = format!("{year}_{day:02}_{part}");
+            c.bench_function(&benchmark_name, |b| {
+                b.iter(|| solve(year, day, part, &input));
+            });
         }
     }
 }
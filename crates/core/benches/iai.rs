@@ -1,348 +0,0 @@
-use advent_of_code::solve;
-use std::fs::read_to_string;
-
-fn problem_2023_1_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day01_input.txt").unwrap();
-    solve(2023, 1, 1, &input).unwrap();
-}
-
-fn problem_2023_1_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day01_input.txt").unwrap();
-    solve(2023, 1, 2, &input).unwrap();
-}
-
-fn problem_2023_2_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day02_input.txt").unwrap();
-    solve(2023, 2, 1, &input).unwrap();
-}
-
-fn problem_2023_2_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day02_input.txt").unwrap();
-    solve(2023, 2, 2, &input).unwrap();
-}
-
-fn problem_2023_3_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day03_input.txt").unwrap();
-    solve(2023, 3, 1, &input).unwrap();
-}
-
-fn problem_2023_3_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day03_input.txt").unwrap();
-    solve(2023, 3, 2, &input).unwrap();
-}
-
-fn problem_2023_4_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day04_input.txt").unwrap();
-    solve(2023, 4, 1, &input).unwrap();
-}
-
-fn problem_2023_4_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day04_input.txt").unwrap();
-    solve(2023, 4, 2, &input).unwrap();
-}
-
-fn problem_2023_5_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day05_input.txt").unwrap();
-    solve(2023, 5, 1, &input).unwrap();
-}
-
-fn problem_2023_5_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day05_input.txt").unwrap();
-    solve(2023, 5, 2, &input).unwrap();
-}
-
-fn problem_2023_6_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day06_input.txt").unwrap();
-    solve(2023, 6, 1, &input).unwrap();
-}
-
-fn problem_2023_6_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day06_input.txt").unwrap();
-    solve(2023, 6, 2, &input).unwrap();
-}
-
-fn problem_2023_7_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day07_input.txt").unwrap();
-    solve(2023, 7, 1, &input).unwrap();
-}
-
-fn problem_2023_7_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day07_input.txt").unwrap();
-    solve(2023, 7, 2, &input).unwrap();
-}
-
-fn problem_2023_8_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day08_input.txt").unwrap();
-    solve(2023, 8, 1, &input).unwrap();
-}
-
-fn problem_2023_8_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day08_input.txt").unwrap();
-    solve(2023, 8, 2, &input).unwrap();
-}
-
-fn problem_2023_9_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day09_input.txt").unwrap();
-    solve(2023, 9, 1, &input).unwrap();
-}
-
-fn problem_2023_9_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day09_input.txt").unwrap();
-    solve(2023, 9, 2, &input).unwrap();
-}
-
-fn problem_2023_10_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day10_input.txt").unwrap();
-    solve(2023, 10, 1, &input).unwrap();
-}
-
-fn problem_2023_10_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day10_input.txt").unwrap();
-    solve(2023, 10, 2, &input).unwrap();
-}
-
-fn problem_2023_11_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day11_input.txt").unwrap();
-    solve(2023, 11, 1, &input).unwrap();
-}
-
-fn problem_2023_11_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day11_input.txt").unwrap();
-    solve(2023, 11, 2, &input).unwrap();
-}
-
-fn problem_2023_12_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day12_input.txt").unwrap();
-    solve(2023, 12, 1, &input).unwrap();
-}
-
-fn problem_2023_12_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day12_input.txt").unwrap();
-    solve(2023, 12, 2, &input).unwrap();
-}
-
-fn problem_2023_13_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day13_input.txt").unwrap();
-    solve(2023, 13, 1, &input).unwrap();
-}
-
-fn problem_2023_13_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day13_input.txt").unwrap();
-    solve(2023, 13, 2, &input).unwrap();
-}
-
-fn problem_2023_14_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day14_input.txt").unwrap();
-    solve(2023, 14, 1, &input).unwrap();
-}
-
-fn problem_2023_14_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day14_input.txt").unwrap();
-    solve(2023, 14, 2, &input).unwrap();
-}
-
-fn problem_2023_15_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day15_input.txt").unwrap();
-    solve(2023, 15, 1, &input).unwrap();
-}
-
-fn problem_2023_15_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day15_input.txt").unwrap();
-    solve(2023, 15, 2, &input).unwrap();
-}
-
-fn problem_2023_16_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day16_input.txt").unwrap();
-    solve(2023, 16, 1, &input).unwrap();
-}
-
-fn problem_2023_16_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day16_input.txt").unwrap();
-    solve(2023, 16, 2, &input).unwrap();
-}
-
-fn problem_2023_17_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day17_input.txt").unwrap();
-    solve(2023, 17, 1, &input).unwrap();
-}
-
-fn problem_2023_17_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day17_input.txt").unwrap();
-    solve(2023, 17, 2, &input).unwrap();
-}
-
-fn problem_2023_18_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day18_input.txt").unwrap();
-    solve(2023, 18, 1, &input).unwrap();
-}
-
-fn problem_2023_18_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day18_input.txt").unwrap();
-    solve(2023, 18, 2, &input).unwrap();
-}
-
-fn problem_2023_19_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day19_input.txt").unwrap();
-    solve(2023, 19, 1, &input).unwrap();
-}
-
-fn problem_2023_19_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day19_input.txt").unwrap();
-    solve(2023, 19, 2, &input).unwrap();
-}
-
-fn problem_2023_20_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day20_input.txt").unwrap();
-    solve(2023, 20, 1, &input).unwrap();
-}
-
-fn problem_2023_20_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day20_input.txt").unwrap();
-    solve(2023, 20, 2, &input).unwrap();
-}
-
-fn problem_2023_21_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day21_input.txt").unwrap();
-    solve(2023, 21, 1, &input).unwrap();
-}
-
-fn problem_2023_21_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day21_input.txt").unwrap();
-    solve(2023, 21, 2, &input).unwrap();
-}
-
-fn problem_2023_22_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day22_input.txt").unwrap();
-    solve(2023, 22, 1, &input).unwrap();
-}
-
-fn problem_2023_22_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day22_input.txt").unwrap();
-    solve(2023, 22, 2, &input).unwrap();
-}
-
-fn problem_2023_23_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day23_input.txt").unwrap();
-    solve(2023, 23, 1, &input).unwrap();
-}
-
-fn problem_2023_23_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day23_input.txt").unwrap();
-    solve(2023, 23, 2, &input).unwrap();
-}
-
-fn problem_2023_24_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day24_input.txt").unwrap();
-    solve(2023, 24, 1, &input).unwrap();
-}
-
-fn problem_2023_24_2() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day24_input.txt").unwrap();
-    solve(2023, 24, 2, &input).unwrap();
-}
-
-fn problem_2023_25_1() {
-    #![allow(clippy::unwrap_used)]
-    let input = read_to_string("src/year2023/day25_input.txt").unwrap();
-    solve(2023, 25, 1, &input).unwrap();
-}
-
-iai::main!(
-    problem_2023_1_1,
-    problem_2023_1_2,
-    problem_2023_2_1,
-    problem_2023_2_2,
-    problem_2023_3_1,
-    problem_2023_3_2,
-    problem_2023_4_1,
-    problem_2023_4_2,
-    problem_2023_5_1,
-    problem_2023_5_2,
-    problem_2023_6_1,
-    problem_2023_6_2,
-    problem_2023_7_1,
-    problem_2023_7_2,
-    problem_2023_8_1,
-    problem_2023_8_2,
-    problem_2023_9_1,
-    problem_2023_9_2,
-    problem_2023_10_1,
-    problem_2023_10_2,
-    problem_2023_11_1,
-    problem_2023_11_2,
-    problem_2023_12_1,
-    problem_2023_12_2,
-    problem_2023_13_1,
-    problem_2023_13_2,
-    problem_2023_14_1,
-    problem_2023_14_2,
-    problem_2023_15_1,
-    problem_2023_15_2,
-    problem_2023_16_1,
-    problem_2023_16_2,
-    problem_2023_17_1,
-    problem_2023_17_2,
-    problem_2023_18_1,
-    problem_2023_18_2,
-    problem_2023_19_1,
-    problem_2023_19_2,
-    problem_2023_20_1,
-    problem_2023_20_2,
-    problem_2023_21_1,
-    problem_2023_21_2,
-    problem_2023_22_1,
-    problem_2023_22_2,
-    problem_2023_23_1,
-    problem_2023_23_2,
-    problem_2023_24_1,
-    problem_2023_24_2,
-    problem_2023_25_1
-);
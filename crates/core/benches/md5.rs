@@ -0,0 +1,36 @@
+//! Compares the scalar and multi-lane MD5 implementations of the puzzles hashing with MD5.
+//!
+//! Run with `--features simd` on nightly for eight lanes instead of four, and with
+//! `--features parallel` to also search nonces on all available threads.
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::fs::read_to_string;
+
+pub fn md5_benchmark(c: &mut Criterion) {
+    #![allow(clippy::unwrap_used)]
+    for (year, day) in [(2015, 4), (2016, 5), (2016, 14), (2016, 17)] {
+        let input_path = format!("src/year{year}/day{day:02}_input.txt");
+        let input = read_to_string(input_path).unwrap();
+
+        for part in 1..=2 {
+            let mut group = c.benchmark_group(format!("md5_{year}_{day:02}_{part}"));
+            for implementation in advent_of_code::implementations(year, day) {
+                group.bench_function(implementation.name, |b| {
+                    b.iter(|| implementation.solve(part, &input));
+                });
+            }
+            group.finish();
+        }
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default()
+        .sample_size(10)
+        .warm_up_time(std::time::Duration::new(1, 0))
+        .nresamples(10_000)
+        .measurement_time(std::time::Duration::new(5, 0));
+    targets = md5_benchmark
+}
+
+criterion_main!(benches);
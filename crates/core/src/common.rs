@@ -1,20 +1,24 @@
 pub mod array_deque;
 pub mod array_stack;
+pub mod bitset;
 pub mod character_recognition;
 pub mod chunk_iterator;
+pub mod cycle;
 #[cfg(feature = "webgpu-compute")]
 pub mod gpu;
 pub mod highest_values;
 pub mod id_assigner;
 pub mod id_assigner_copy;
 pub mod int_to_ascii;
-#[cfg(not(feature = "webgpu-compute"))]
-#[cfg(not(feature = "visualization"))]
 pub mod map_windows;
 pub mod md5;
+pub mod number_theory;
+pub mod parallel;
 pub mod parser;
 pub mod permutation;
 pub mod priority_queueu;
+pub mod progress;
+pub mod storage;
 pub mod triple_window_iterator;
 pub mod tuple_window_iterator;
-pub mod u256;
+pub mod vm;
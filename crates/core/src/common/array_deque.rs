@@ -1,3 +1,6 @@
+use crate::common::storage::capacity_exceeded;
+use alloc::string::String;
+
 pub struct ArrayDeque<const MAX_SIZE: usize, H: Eq + Copy + Clone + Default> {
     pub elements: [H; MAX_SIZE],
     head: usize,
@@ -16,7 +19,7 @@ impl<const MAX_SIZE: usize, H: Eq + Copy + Clone + Default> ArrayDeque<MAX_SIZE,
     pub fn push_back(&mut self, element: H) -> Result<(), String> {
         let next_tail = (self.tail + 1) % MAX_SIZE;
         if next_tail == self.head {
-            return Err("Too many elements pushed".to_string());
+            return Err(capacity_exceeded());
         }
         self.elements[self.tail] = element;
         self.tail = (self.tail + 1) % MAX_SIZE;
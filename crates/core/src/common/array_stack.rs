@@ -1,3 +1,6 @@
+use crate::common::storage::capacity_exceeded;
+use alloc::string::String;
+
 #[derive(Clone)]
 pub struct ArrayStack<const MAX_SIZE: usize, H: Copy + Clone + Default> {
     pub elements: [H; MAX_SIZE],
@@ -28,7 +31,7 @@ impl<const MAX_SIZE: usize, H: Copy + Clone + Default> ArrayStack<MAX_SIZE, H> {
 
     pub fn push(&mut self, element: H) -> Result<(), String> {
         if self.len == MAX_SIZE {
-            return Err("Too many elements pushed".to_string());
+            return Err(capacity_exceeded());
         }
         self.elements[self.len] = element;
         self.len += 1;
@@ -86,6 +89,17 @@ impl<const MAX_SIZE: usize, H: Eq + Copy + Clone + Default> ArrayStack<MAX_SIZE,
     }
 }
 
+/// Stacks are equal if their elements are, ignoring what is left beyond them after popping.
+impl<const MAX_SIZE: usize, H: PartialEq + Copy + Clone + Default> PartialEq
+    for ArrayStack<MAX_SIZE, H>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.slice() == other.slice()
+    }
+}
+
+impl<const MAX_SIZE: usize, H: Eq + Copy + Clone + Default> Eq for ArrayStack<MAX_SIZE, H> {}
+
 #[allow(clippy::unwrap_used)]
 #[test]
 fn retain() {
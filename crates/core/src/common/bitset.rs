@@ -0,0 +1,360 @@
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, Shr};
+
+/// A set of `64 * WORDS` bits, such as a row in a grid, with bit 0 being the lowest
+/// bit of the first word.
+///
+/// Shifting left moves bits to higher indices, as when shifting an integer.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BitSet<const WORDS: usize> {
+    words: [u64; WORDS],
+}
+
+impl<const WORDS: usize> Default for BitSet<WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const WORDS: usize> BitSet<WORDS> {
+    /// The number of bits in the set.
+    pub const CAPACITY: usize = 64 * WORDS;
+
+    pub const fn new() -> Self {
+        Self { words: [0; WORDS] }
+    }
+
+    pub const fn set_bit(&mut self, index: usize) {
+        self.words[index / 64] |= 1 << (index % 64);
+    }
+
+    pub const fn clear_bit(&mut self, index: usize) {
+        self.words[index / 64] &= !(1 << (index % 64));
+    }
+
+    pub const fn is_bit_set(&self, index: usize) -> bool {
+        self.words[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    pub const fn non_zero(&self) -> bool {
+        let mut word_idx = 0;
+        while word_idx < WORDS {
+            if self.words[word_idx] != 0 {
+                return true;
+            }
+            word_idx += 1;
+        }
+        false
+    }
+
+    pub const fn count_ones(&self) -> u32 {
+        self.rank(Self::CAPACITY)
+    }
+
+    /// The number of set bits below `index`.
+    pub const fn rank(&self, index: usize) -> u32 {
+        let mut count = 0;
+        let mut word_idx = 0;
+        while word_idx < index / 64 {
+            count += self.words[word_idx].count_ones();
+            word_idx += 1;
+        }
+        if !index.is_multiple_of(64) {
+            count += (self.words[index / 64] & ((1 << (index % 64)) - 1)).count_ones();
+        }
+        count
+    }
+
+    /// The index of the set bit having `rank` set bits below it, if any.
+    pub const fn select(&self, rank: u32) -> Option<usize> {
+        let mut remaining = rank;
+        let mut word_idx = 0;
+        while word_idx < WORDS {
+            let mut word = self.words[word_idx];
+            let ones = word.count_ones();
+            if remaining < ones {
+                while remaining > 0 {
+                    // Clear the lowest set bit:
+                    word &= word - 1;
+                    remaining -= 1;
+                }
+                return Some(word_idx * 64 + word.trailing_zeros() as usize);
+            }
+            remaining -= ones;
+            word_idx += 1;
+        }
+        None
+    }
+
+    /// The indices of the set bits, in increasing order.
+    pub fn ones(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(word_idx, &word)| {
+            let mut remaining = word;
+            core::iter::from_fn(move || {
+                (remaining != 0).then(|| {
+                    let bit = remaining.trailing_zeros() as usize;
+                    remaining &= remaining - 1;
+                    word_idx * 64 + bit
+                })
+            })
+        })
+    }
+
+    /// Shift all bits one step left, with `carry` shifted in as bit 0. Returns the shifted
+    /// set and the highest bit, which was shifted out - to shift in to a following set.
+    pub const fn shift_left_with_carry(self, carry: bool) -> (Self, bool) {
+        let mut result = Self::new();
+        let mut carry = carry as u64;
+        let mut word_idx = 0;
+        while word_idx < WORDS {
+            let word = self.words[word_idx];
+            result.words[word_idx] = (word << 1) | carry;
+            carry = word >> 63;
+            word_idx += 1;
+        }
+        (result, carry != 0)
+    }
+
+    /// Shift all bits one step right, with `carry` shifted in as the highest bit. Returns
+    /// the shifted set and bit 0, which was shifted out - to shift in to a preceding set.
+    pub const fn shift_right_with_carry(self, carry: bool) -> (Self, bool) {
+        let mut result = Self::new();
+        let mut carry = carry as u64;
+        let mut word_idx = WORDS;
+        while word_idx > 0 {
+            word_idx -= 1;
+            let word = self.words[word_idx];
+            result.words[word_idx] = (word >> 1) | (carry << 63);
+            carry = word & 1;
+        }
+        (result, carry != 0)
+    }
+
+    /// Rotate the lowest `width` bits one step left, so that bit `width - 1` becomes bit 0.
+    /// Bits at `width` and above should be clear, and stay so.
+    pub const fn rotate_left_within(self, width: usize) -> Self {
+        let (mut result, carry) = self.shift_left_with_carry(false);
+        if width == Self::CAPACITY {
+            if carry {
+                result.set_bit(0);
+            }
+        } else if result.is_bit_set(width) {
+            result.clear_bit(width);
+            result.set_bit(0);
+        }
+        result
+    }
+
+    /// Rotate the lowest `width` bits one step right, so that bit 0 becomes bit `width - 1`.
+    /// Bits at `width` and above should be clear, and stay so.
+    pub const fn rotate_right_within(self, width: usize) -> Self {
+        let (mut result, carry) = self.shift_right_with_carry(false);
+        if carry {
+            result.set_bit(width - 1);
+        }
+        result
+    }
+}
+
+/// Word-wise operators, on four words at a time with the `simd` feature.
+macro_rules! bit_operator {
+    ($trait:ident, $function:ident, $assign_trait:ident, $assign_function:ident, $operator:tt, $assign_operator:tt) => {
+        impl<const WORDS: usize> $assign_trait for BitSet<WORDS> {
+            fn $assign_function(&mut self, other: Self) {
+                #[cfg(feature = "simd")]
+                {
+                    use core::simd::u64x4;
+                    let mut chunks = self.words.chunks_exact_mut(4);
+                    let other_chunks = other.words.chunks_exact(4);
+                    let other_remainder = other_chunks.remainder();
+                    for (chunk, other_chunk) in (&mut chunks).zip(other_chunks) {
+                        (u64x4::from_slice(chunk) $operator u64x4::from_slice(other_chunk))
+                            .copy_to_slice(chunk);
+                    }
+                    for (word, other_word) in chunks.into_remainder().iter_mut().zip(other_remainder) {
+                        *word $assign_operator *other_word;
+                    }
+                }
+                #[cfg(not(feature = "simd"))]
+                for (word, other_word) in self.words.iter_mut().zip(other.words) {
+                    *word $assign_operator other_word;
+                }
+            }
+        }
+
+        impl<const WORDS: usize> $trait for BitSet<WORDS> {
+            type Output = Self;
+
+            fn $function(mut self, other: Self) -> Self {
+                self $assign_operator other;
+                self
+            }
+        }
+    };
+}
+
+bit_operator!(BitAnd, bitand, BitAndAssign, bitand_assign, &, &=);
+bit_operator!(BitOr, bitor, BitOrAssign, bitor_assign, |, |=);
+bit_operator!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^, ^=);
+
+impl<const WORDS: usize> Not for BitSet<WORDS> {
+    type Output = Self;
+
+    fn not(mut self) -> Self {
+        #[cfg(feature = "simd")]
+        {
+            let mut chunks = self.words.chunks_exact_mut(4);
+            for chunk in &mut chunks {
+                (!core::simd::u64x4::from_slice(chunk)).copy_to_slice(chunk);
+            }
+            for word in chunks.into_remainder() {
+                *word = !*word;
+            }
+        }
+        #[cfg(not(feature = "simd"))]
+        for word in &mut self.words {
+            *word = !*word;
+        }
+        self
+    }
+}
+
+impl<const WORDS: usize> Shl<usize> for BitSet<WORDS> {
+    type Output = Self;
+
+    /// Shift bits to higher indices, dropping the ones shifted past the capacity.
+    fn shl(self, amount: usize) -> Self {
+        let (word_shift, bit_shift) = (amount / 64, amount % 64);
+        let mut result = Self::new();
+        for word_idx in word_shift..WORDS {
+            let source_idx = word_idx - word_shift;
+            result.words[word_idx] = self.words[source_idx] << bit_shift;
+            if bit_shift != 0 && source_idx > 0 {
+                result.words[word_idx] |= self.words[source_idx - 1] >> (64 - bit_shift);
+            }
+        }
+        result
+    }
+}
+
+impl<const WORDS: usize> Shr<usize> for BitSet<WORDS> {
+    type Output = Self;
+
+    /// Shift bits to lower indices, dropping the ones shifted below zero.
+    fn shr(self, amount: usize) -> Self {
+        let (word_shift, bit_shift) = (amount / 64, amount % 64);
+        let mut result = Self::new();
+        for word_idx in 0..WORDS.saturating_sub(word_shift) {
+            let source_idx = word_idx + word_shift;
+            result.words[word_idx] = self.words[source_idx] >> bit_shift;
+            if bit_shift != 0 && source_idx + 1 < WORDS {
+                result.words[word_idx] |= self.words[source_idx + 1] << (64 - bit_shift);
+            }
+        }
+        result
+    }
+}
+
+impl<const WORDS: usize> FromIterator<usize> for BitSet<WORDS> {
+    fn from_iter<I: IntoIterator<Item = usize>>(indices: I) -> Self {
+        let mut result = Self::new();
+        for index in indices {
+            result.set_bit(index);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+fn bits<const WORDS: usize>(set: &BitSet<WORDS>) -> alloc::vec::Vec<usize> {
+    set.ones().collect()
+}
+
+#[test]
+fn basics() {
+    let mut set = BitSet::<4>::new();
+    for index in [0, 63, 64, 127, 128, 255] {
+        assert!(!set.is_bit_set(index));
+        set.set_bit(index);
+        assert!(set.is_bit_set(index));
+    }
+    assert_eq!(bits(&set), [0, 63, 64, 127, 128, 255]);
+    assert_eq!(set.count_ones(), 6);
+    assert!(set.non_zero());
+    set.clear_bit(63);
+    assert!(!set.is_bit_set(63));
+    assert_eq!(set, [0, 64, 127, 128, 255].into_iter().collect());
+    assert!(!BitSet::<4>::default().non_zero());
+    assert_eq!(BitSet::<3>::CAPACITY, 192);
+}
+
+#[test]
+fn rank_and_select() {
+    let set: BitSet<3> = [1, 5, 64, 100, 191].into_iter().collect();
+    assert_eq!(set.rank(0), 0);
+    assert_eq!(set.rank(2), 1);
+    assert_eq!(set.rank(64), 2);
+    assert_eq!(set.rank(65), 3);
+    assert_eq!(set.rank(192), 5);
+    for (rank, index) in set.ones().enumerate() {
+        assert_eq!(set.select(rank as u32), Some(index));
+        assert_eq!(set.rank(index), rank as u32);
+    }
+    assert_eq!(set.select(5), None);
+}
+
+#[test]
+fn operators() {
+    let a: BitSet<5> = [0, 70, 200, 319].into_iter().collect();
+    let b: BitSet<5> = [0, 71, 200, 300].into_iter().collect();
+    assert_eq!(bits(&(a & b)), [0, 200]);
+    assert_eq!(bits(&(a | b)), [0, 70, 71, 200, 300, 319]);
+    assert_eq!(bits(&(a ^ b)), [70, 71, 300, 319]);
+    assert_eq!((!a).count_ones(), 320 - 4);
+    assert_eq!(!!a, a);
+
+    assert_eq!(bits(&(a << 1)), [1, 71, 201]);
+    assert_eq!(bits(&(a << 64)), [64, 134, 264]);
+    assert_eq!(bits(&(a << 130)), [130, 200]);
+    assert_eq!(bits(&(a >> 1)), [69, 199, 318]);
+    assert_eq!(bits(&(a >> 70)), [0, 130, 249]);
+    assert_eq!(bits(&(a >> 320)), []);
+}
+
+#[test]
+fn grid_rows() {
+    let row: BitSet<2> = [0, 63, 127].into_iter().collect();
+    let (shifted, carry) = row.shift_left_with_carry(true);
+    assert_eq!((bits(&shifted), carry), (alloc::vec![0, 1, 64], true));
+    let (shifted, carry) = row.shift_right_with_carry(false);
+    assert_eq!((bits(&shifted), carry), (alloc::vec![62, 126], true));
+
+    let row: BitSet<4> = [0, 9].into_iter().collect();
+    assert_eq!(bits(&row.rotate_left_within(10)), [0, 1]);
+    assert_eq!(bits(&row.rotate_right_within(10)), [8, 9]);
+    let row: BitSet<4> = [127, 254].into_iter().collect();
+    assert_eq!(bits(&row.rotate_left_within(255)), [0, 128]);
+    assert_eq!(bits(&row.rotate_left_within(256)), [128, 255]);
+    let row: BitSet<4> = [0, 255].into_iter().collect();
+    assert_eq!(bits(&row.rotate_left_within(256)), [0, 1]);
+    assert_eq!(bits(&row.rotate_right_within(256)), [254, 255]);
+}
+
+#[test]
+fn rotate_within_width() {
+    let mut row = BitSet::<4>::new();
+    row.set_bit(0);
+    row = row.rotate_left_within(10);
+    assert_eq!(bits(&row), [1]);
+    row = row.rotate_left_within(4).rotate_left_within(4);
+    assert_eq!(bits(&row), [3]);
+    row = row.rotate_left_within(4);
+    assert_eq!(bits(&row), [0]);
+    row = row.rotate_right_within(129);
+    assert_eq!(bits(&row), [128]);
+    row = row.rotate_left_within(129);
+    assert_eq!(bits(&row), [0]);
+    row = row.rotate_right_within(128);
+    assert_eq!(bits(&row), [127]);
+    row = row.rotate_left_within(128);
+    assert_eq!(bits(&row), [0]);
+}
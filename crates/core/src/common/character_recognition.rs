@@ -1,3 +1,9 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::fmt::{self, Write};
+
+use crate::input::Input;
+
 pub const CHAR_WIDTH: usize = 5;
 pub const CHAR_HEIGHT: usize = 6;
 
@@ -178,7 +184,8 @@ fn recognize_letter(
     })
 }
 
-pub fn recognize(bytes: &[bool]) -> Result<String, String> {
+/// Recognizes letters from a screen of lit pixels, given row by row.
+pub fn recognize(input: &Input, bytes: &[bool]) -> Result<String, String> {
     if !bytes.len().is_multiple_of(CHAR_WIDTH * CHAR_HEIGHT) {
         return Err(format!(
             "Input length is not a multiple of {}",
@@ -187,6 +194,20 @@ pub fn recognize(bytes: &[bool]) -> Result<String, String> {
     }
     let num_letters = bytes.len() / (CHAR_WIDTH * CHAR_HEIGHT);
     let all_width = CHAR_WIDTH * num_letters;
+    input.trace(
+        "screen",
+        fmt::from_fn(|f| {
+            for (row_idx, row) in bytes.chunks(all_width).enumerate() {
+                if row_idx > 0 {
+                    f.write_char('\n')?;
+                }
+                for &lit in row {
+                    f.write_char(if lit { '#' } else { '.' })?;
+                }
+            }
+            Ok(())
+        }),
+    );
     let mut result = String::with_capacity(num_letters);
     for letter_idx in 0..num_letters {
         result.push(recognize_letter(
@@ -0,0 +1,291 @@
+use crate::common::number_theory::lcm;
+use core::hash::Hash;
+use hashbrown::HashMap;
+use hashbrown::hash_map::Entry;
+
+/// A cycle in a sequence of states: The state at each step from `start` on is the same
+/// as the state `period` steps later.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The first step in the cycle.
+    pub start: u64,
+    /// The number of steps after which the states repeat.
+    pub period: u64,
+}
+
+impl Cycle {
+    /// Returns the step at or before `start + period` with the same state as `step`.
+    pub const fn equivalent_step(&self, step: u64) -> u64 {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.period
+        }
+    }
+
+    /// Returns the value at `step` of a projection of the state, given its values up to
+    /// `start + period` by `value_at`, assuming that it grows by the same amount each
+    /// period - which is zero for a projection of only the state itself.
+    ///
+    /// The steps given to `value_at` have all been reached, so they can index a history.
+    pub fn extrapolate(&self, step: u64, mut value_at: impl FnMut(usize) -> i64) -> i64 {
+        let equivalent_step = self.equivalent_step(step) as usize;
+        if step < self.start {
+            return value_at(equivalent_step);
+        }
+        let skipped_periods = ((step - self.start) / self.period) as i64;
+        let growth_per_period =
+            value_at((self.start + self.period) as usize) - value_at(self.start as usize);
+        value_at(equivalent_step) + skipped_periods * growth_per_period
+    }
+
+    /// Returns the cycle of two independent sequences progressing together, such as
+    /// separate parts of a simulation, or `None` if its period overflows.
+    pub const fn combine(&self, other: &Self) -> Option<Self> {
+        let Some(period) = lcm(self.period, other.period) else {
+            return None;
+        };
+        Some(Self {
+            start: if self.start > other.start {
+                self.start
+            } else {
+                other.start
+            },
+            period,
+        })
+    }
+}
+
+/// Finds the cycle in the sequence of states from `initial`, advanced by `step`, using
+/// Floyd's tortoise and hare algorithm, which only keeps two states at a time.
+///
+/// Returns `None` if no state repeats within `max_steps` steps.
+pub fn floyd<T: Clone + PartialEq>(
+    initial: &T,
+    mut step: impl FnMut(&mut T),
+    max_steps: u64,
+) -> Option<Cycle> {
+    // Find a state in the cycle, at a step which is a multiple of the period:
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    let mut steps = 0;
+    loop {
+        step(&mut tortoise);
+        step(&mut hare);
+        step(&mut hare);
+        steps += 1;
+        if tortoise == hare {
+            break;
+        } else if steps >= max_steps {
+            return None;
+        }
+    }
+
+    // Moving from there and from the initial state in lockstep, they meet at the start:
+    tortoise.clone_from(initial);
+    let mut start = 0;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    let mut period = 1;
+    hare.clone_from(&tortoise);
+    step(&mut hare);
+    while tortoise != hare {
+        step(&mut hare);
+        period += 1;
+    }
+    Some(Cycle { start, period })
+}
+
+/// Finds the cycle in the sequence of states from `initial`, advanced by `step`, using
+/// Brent's algorithm, which only keeps two states at a time and needs fewer steps than
+/// [floyd].
+///
+/// Returns `None` if no state repeats within `max_steps` steps.
+pub fn brent<T: Clone + PartialEq>(
+    initial: &T,
+    mut step: impl FnMut(&mut T),
+    max_steps: u64,
+) -> Option<Cycle> {
+    // Find the period, by comparing with states at increasing powers of two:
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    step(&mut hare);
+    let (mut power, mut period, mut steps) = (1, 1, 1);
+    while tortoise != hare {
+        if steps >= max_steps {
+            return None;
+        } else if power == period {
+            tortoise.clone_from(&hare);
+            power *= 2;
+            period = 0;
+        }
+        step(&mut hare);
+        period += 1;
+        steps += 1;
+    }
+
+    // With the hare a period ahead of the tortoise, they meet at the start:
+    tortoise.clone_from(initial);
+    hare.clone_from(initial);
+    for _ in 0..period {
+        step(&mut hare);
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+    Some(Cycle { start, period })
+}
+
+/// Finds a cycle by remembering the first step at which each state was seen, for when
+/// states are given one at a time or are expensive to recompute.
+///
+/// The states can be given as keys identifying them, such as hashes of larger states.
+pub struct CycleDetector<K> {
+    first_seen: HashMap<K, u64>,
+    steps: u64,
+}
+
+impl<K: Eq + Hash> CycleDetector<K> {
+    pub fn new() -> Self {
+        Self {
+            first_seen: HashMap::new(),
+            steps: 0,
+        }
+    }
+
+    /// Records the state at the next step, starting with step 0, returning the cycle if the
+    /// same state was recorded at an earlier step.
+    pub fn record(&mut self, key: K) -> Option<Cycle> {
+        let step = self.steps;
+        self.steps += 1;
+        match self.first_seen.entry(key) {
+            Entry::Occupied(entry) => Some(Cycle {
+                start: *entry.get(),
+                period: step - entry.get(),
+            }),
+            Entry::Vacant(entry) => {
+                entry.insert(step);
+                None
+            }
+        }
+    }
+}
+
+impl<K: Eq + Hash> Default for CycleDetector<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[test]
+fn detectors() {
+    // The sequence 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ... has the cycle 3, 4, 5, 6, 7:
+    let advance = |n: &mut u32| *n = if *n == 7 { 3 } else { *n + 1 };
+    let expected = Cycle {
+        start: 3,
+        period: 5,
+    };
+    assert_eq!(floyd(&0, advance, 100), Some(expected));
+    assert_eq!(brent(&0, advance, 100), Some(expected));
+    assert_eq!(
+        floyd(&5, advance, 100),
+        Some(Cycle {
+            start: 0,
+            period: 5
+        })
+    );
+    assert_eq!(
+        brent(&5, advance, 100),
+        Some(Cycle {
+            start: 0,
+            period: 5
+        })
+    );
+    assert_eq!(floyd(&0, |n: &mut u32| *n += 1, 100), None);
+    assert_eq!(brent(&0, |n: &mut u32| *n += 1, 100), None);
+
+    let mut detector = CycleDetector::new();
+    let mut state = 0;
+    let mut found = None;
+    for _ in 0..100 {
+        found = detector.record(state);
+        if found.is_some() {
+            break;
+        }
+        advance(&mut state);
+    }
+    assert_eq!(found, Some(expected));
+
+    // A state which repeats after a single step:
+    assert_eq!(
+        brent(&7, |_: &mut u32| {}, 100),
+        Some(Cycle {
+            start: 0,
+            period: 1
+        })
+    );
+    assert_eq!(
+        floyd(&7, |_: &mut u32| {}, 100),
+        Some(Cycle {
+            start: 0,
+            period: 1
+        })
+    );
+}
+
+#[test]
+fn extrapolation() {
+    let cycle = Cycle {
+        start: 3,
+        period: 5,
+    };
+    assert_eq!(cycle.equivalent_step(2), 2);
+    assert_eq!(cycle.equivalent_step(3), 3);
+    assert_eq!(cycle.equivalent_step(8), 3);
+    assert_eq!(cycle.equivalent_step(1_000_000_000_004), 4);
+
+    // A value growing by 10 each period, as the height of a pile repeating its pattern:
+    let values = [0, 1, 2, 10, 12, 14, 16, 18, 20];
+    let value_at = |step: usize| values[step];
+    assert_eq!(cycle.extrapolate(2, value_at), 2);
+    assert_eq!(cycle.extrapolate(8, value_at), 20);
+    assert_eq!(cycle.extrapolate(9, value_at), 22);
+    assert_eq!(cycle.extrapolate(13, value_at), 30);
+    assert_eq!(
+        cycle.extrapolate(1_000_000_000_003, value_at),
+        2_000_000_000_010
+    );
+
+    let combined = cycle.combine(&Cycle {
+        start: 4,
+        period: 15,
+    });
+    assert_eq!(
+        combined,
+        Some(Cycle {
+            start: 4,
+            period: 15
+        })
+    );
+    assert_eq!(
+        Cycle {
+            start: 0,
+            period: 4
+        }
+        .combine(&Cycle {
+            start: 1,
+            period: 6
+        }),
+        Some(Cycle {
+            start: 1,
+            period: 12
+        })
+    );
+}
@@ -1,3 +1,6 @@
+use alloc::format;
+use alloc::string::String;
+
 pub struct Gpu {
     pub instance: wgpu::Instance,
     pub device: wgpu::Device,
@@ -1,3 +1,6 @@
+use crate::common::storage::capacity_exceeded;
+use alloc::string::String;
+
 pub struct IdAssigner<'a, const MAX_SIZE: usize, H: Ord + Eq + ?Sized> {
     id_map: [&'a H; MAX_SIZE],
     ids: [u16; MAX_SIZE],
@@ -18,6 +21,9 @@ impl<'a, const MAX_SIZE: usize, H: Ord + Eq + ?Sized> IdAssigner<'a, MAX_SIZE, H
             match self.id_map[0..(self.assigned_count as usize)].binary_search(&name) {
                 Ok(idx) => self.ids[idx],
                 Err(idx) => {
+                    if self.assigned_count as usize == MAX_SIZE {
+                        return Err(capacity_exceeded());
+                    }
                     self.id_map
                         .copy_within(idx..self.assigned_count as usize, idx + 1);
                     self.ids
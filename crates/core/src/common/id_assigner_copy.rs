@@ -1,3 +1,5 @@
+use alloc::string::{String, ToString};
+
 pub struct IdAssigner<const MAX_SIZE: usize, H: Ord + Eq + Copy + Clone> {
     id_map: [H; MAX_SIZE],
     ids: [u16; MAX_SIZE],
@@ -1,5 +1,5 @@
-use std::array;
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
+use core::array;
 
 pub struct MapWindows<I: Iterator, F, T, const N: usize>
 where
@@ -52,6 +52,7 @@ impl<I: Iterator> MapWindowsIterator for I {}
 #[test]
 #[allow(unstable_name_collisions)]
 fn test_iterator() {
+    use alloc::{vec, vec::Vec};
     let v = [1, 2, 3, 4]
         .iter()
         .map_windows_stable(|[a, b]| (**a, **b))
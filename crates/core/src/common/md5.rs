@@ -22,6 +22,23 @@
 // https://people.csail.mit.edu/rivest/Md5.c
 // https://tools.ietf.org/html/rfc1321
 
+use crate::common::int_to_ascii::IntToAsciiContext;
+use crate::common::{parallel, progress};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::ops::{BitAnd, BitOr, BitXor, Not, Range};
+
+/// The number of messages hashed at once by [`compute_lanes`]: eight with the `simd`
+/// feature, and four otherwise.
+pub const LANES: usize = if cfg!(feature = "simd") { 8 } else { 4 };
+
+/// The number of nonces searched between reporting progress, and by each thread
+/// with the `parallel` feature.
+const NONCES_PER_CHUNK: u32 = 1 << 16;
+
+#[allow(clippy::unreadable_literal)]
+const INITIAL_STATE: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
 /// A context.
 #[derive(Clone)]
 pub struct Context {
@@ -41,11 +58,10 @@ impl Context {
     /// Create a context for computing a digest.
     #[inline]
     pub const fn new() -> Self {
-        #![allow(clippy::unreadable_literal)]
         Self {
             buffer: [0; 64],
             count: [0, 0],
-            state: [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476],
+            state: INITIAL_STATE,
         }
     }
 
@@ -97,19 +113,288 @@ impl Context {
             j += 4;
         }
         transform(&mut self.state, &input);
-        let mut digest = [0_u8; 16];
-        let mut j = 0;
-        for i in 0..4 {
-            digest[j] = ((self.state[i]) & 0xff) as u8;
-            digest[j + 1] = ((self.state[i] >> 8) & 0xff) as u8;
-            digest[j + 2] = ((self.state[i] >> 16) & 0xff) as u8;
-            digest[j + 3] = ((self.state[i] >> 24) & 0xff) as u8;
-            j += 4;
+        to_digest(self.state)
+    }
+}
+
+fn to_digest(state: [u32; 4]) -> [u8; 16] {
+    let mut digest = [0_u8; 16];
+    for (bytes, word) in digest.chunks_exact_mut(4).zip(state) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    digest
+}
+
+/// A 32-bit word as operated on by [`transform`] - either a single word, or one word
+/// for each of the [`LANES`] messages hashed at once.
+trait Word:
+    Copy + BitAnd<Output = Self> + BitOr<Output = Self> + BitXor<Output = Self> + Not<Output = Self>
+{
+    fn splat(value: u32) -> Self;
+    fn add(self, other: Self) -> Self;
+    fn rotate(self, n: u32) -> Self;
+}
+
+impl Word for u32 {
+    fn splat(value: u32) -> Self {
+        value
+    }
+    fn add(self, other: Self) -> Self {
+        self.wrapping_add(other)
+    }
+    fn rotate(self, n: u32) -> Self {
+        self.rotate_left(n)
+    }
+}
+
+/// A word for each lane, converted to and from plain arrays when loading message
+/// blocks and extracting digests.
+trait LaneWord: Word {
+    fn from_lanes(lanes: [u32; LANES]) -> Self;
+    fn to_lanes(self) -> [u32; LANES];
+}
+
+#[cfg(feature = "simd")]
+type Lanes = core::simd::u32x8;
+
+#[cfg(feature = "simd")]
+impl Word for Lanes {
+    fn splat(value: u32) -> Self {
+        Self::from_array([value; LANES])
+    }
+    fn add(self, other: Self) -> Self {
+        // Lane-wise addition wraps on overflow:
+        self + other
+    }
+    fn rotate(self, n: u32) -> Self {
+        (self << Self::splat(n)) | (self >> Self::splat(32 - n))
+    }
+}
+
+#[cfg(feature = "simd")]
+impl LaneWord for Lanes {
+    fn from_lanes(lanes: [u32; LANES]) -> Self {
+        Self::from_array(lanes)
+    }
+    fn to_lanes(self) -> [u32; LANES] {
+        self.to_array()
+    }
+}
+
+/// The SWAR fallback without the `simd` feature, applying each operation to all lanes
+/// of an array in a loop which the compiler turns into vector instructions when the
+/// target has them.
+#[cfg(not(feature = "simd"))]
+#[derive(Clone, Copy)]
+struct Lanes([u32; LANES]);
+
+#[cfg(not(feature = "simd"))]
+impl Lanes {
+    fn zip_with(self, other: Self, operation: impl Fn(u32, u32) -> u32) -> Self {
+        Self(core::array::from_fn(|lane| {
+            operation(self.0[lane], other.0[lane])
+        }))
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+macro_rules! lanes_operator {
+    ($trait:ident, $function:ident, $operator:tt) => {
+        impl $trait for Lanes {
+            type Output = Self;
+            fn $function(self, other: Self) -> Self {
+                self.zip_with(other, |a, b| a $operator b)
+            }
         }
-        digest
+    };
+}
+
+#[cfg(not(feature = "simd"))]
+lanes_operator!(BitAnd, bitand, &);
+#[cfg(not(feature = "simd"))]
+lanes_operator!(BitOr, bitor, |);
+#[cfg(not(feature = "simd"))]
+lanes_operator!(BitXor, bitxor, ^);
+
+#[cfg(not(feature = "simd"))]
+impl Not for Lanes {
+    type Output = Self;
+    fn not(self) -> Self {
+        Self(self.0.map(|value| !value))
     }
 }
 
+#[cfg(not(feature = "simd"))]
+impl Word for Lanes {
+    fn splat(value: u32) -> Self {
+        Self([value; LANES])
+    }
+    fn add(self, other: Self) -> Self {
+        self.zip_with(other, u32::wrapping_add)
+    }
+    fn rotate(self, n: u32) -> Self {
+        Self(self.0.map(|value| value.rotate_left(n)))
+    }
+}
+
+#[cfg(not(feature = "simd"))]
+impl LaneWord for Lanes {
+    fn from_lanes(lanes: [u32; LANES]) -> Self {
+        Self(lanes)
+    }
+    fn to_lanes(self) -> [u32; LANES] {
+        self.0
+    }
+}
+
+/// The number of 64 byte blocks of a message after padding it with a one bit,
+/// zeros and its length.
+const fn num_padded_blocks(message: &[u8]) -> usize {
+    (message.len() + 8) / 64 + 1
+}
+
+/// The words of a block of a padded message.
+fn padded_block(message: &[u8], block: usize) -> [u32; 16] {
+    let mut bytes = [0_u8; 64];
+    let start = block * 64;
+    if start < message.len() {
+        let end = message.len().min(start + 64);
+        bytes[..end - start].copy_from_slice(&message[start..end]);
+    }
+    if (start..start + 64).contains(&message.len()) {
+        bytes[message.len() - start] = 0x80;
+    }
+    if block + 1 == num_padded_blocks(message) {
+        let bit_length = (message.len() as u64).wrapping_mul(8);
+        bytes[56..].copy_from_slice(&bit_length.to_le_bytes());
+    }
+    let mut words = [0_u32; 16];
+    for (word, bytes) in words.iter_mut().zip(bytes.chunks_exact(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    words
+}
+
+/// Compute the digests of [`LANES`] messages at once, which is several times faster than
+/// computing them one after another with a [`Context`].
+///
+/// The messages may have different lengths, but hashing is fastest when they need the
+/// same number of blocks - such as when all are shorter than 56 bytes.
+pub fn compute_lanes(messages: [&[u8]; LANES]) -> [[u8; 16]; LANES] {
+    let num_blocks = messages.map(num_padded_blocks);
+    let max_blocks = num_blocks.iter().copied().max().unwrap_or_default();
+
+    let mut state = INITIAL_STATE.map(Lanes::splat);
+    for block in 0..max_blocks {
+        let mut words = [[0_u32; LANES]; 16];
+        for (lane, message) in messages.iter().enumerate() {
+            if block < num_blocks[lane] {
+                for (word, value) in words.iter_mut().zip(padded_block(message, block)) {
+                    word[lane] = value;
+                }
+            }
+        }
+        let mut new_state = state;
+        transform(&mut new_state, &words.map(Lanes::from_lanes));
+        if num_blocks.iter().all(|&blocks| block < blocks) {
+            state = new_state;
+        } else {
+            // Keep the state of lanes whose message has already been hashed:
+            for (word, new_word) in state.iter_mut().zip(new_state) {
+                let (mut lanes, new_lanes) = (word.to_lanes(), new_word.to_lanes());
+                for lane in 0..LANES {
+                    if block < num_blocks[lane] {
+                        lanes[lane] = new_lanes[lane];
+                    }
+                }
+                *word = Lanes::from_lanes(lanes);
+            }
+        }
+    }
+
+    let lanes = state.map(LaneWord::to_lanes);
+    core::array::from_fn(|lane| to_digest(lanes.map(|word| word[lane])))
+}
+
+/// Search the nonces in increasing order for digests of `prefix` followed by the nonce
+/// in decimal which satisfy `is_match`, calling `on_match` with each match until it
+/// returns true, in which case the nonce is returned.
+///
+/// Digests are computed [`LANES`] at a time, with chunks of nonces searched in
+/// [parallel](crate::common::parallel) and matches given to `on_match` in order.
+pub fn search_nonces(
+    prefix: &[u8],
+    nonces: Range<u32>,
+    is_match: impl Fn(&[u8; 16]) -> bool + Sync,
+    mut on_match: impl FnMut(u32, [u8; 16]) -> bool,
+) -> Result<Option<u32>, String> {
+    let nonces_per_round = NONCES_PER_CHUNK.saturating_mul(parallel::num_threads() as u32);
+    let mut round_start = nonces.start;
+    while round_start < nonces.end {
+        progress::report(round_start.into(), nonces.end.into())?;
+        let round_end = round_start.saturating_add(nonces_per_round).min(nonces.end);
+        for (nonce, digest) in search_round(prefix, round_start..round_end, &is_match) {
+            if on_match(nonce, digest) {
+                return Ok(Some(nonce));
+            }
+        }
+        round_start = round_end;
+    }
+    Ok(None)
+}
+
+fn search_round(
+    prefix: &[u8],
+    nonces: Range<u32>,
+    is_match: &(impl Fn(&[u8; 16]) -> bool + Sync),
+) -> Vec<(u32, [u8; 16])> {
+    let num_chunks = nonces.len().div_ceil(NONCES_PER_CHUNK as usize);
+    parallel::map_reduce(
+        num_chunks,
+        |chunk| {
+            let chunk_start = nonces.start + chunk as u32 * NONCES_PER_CHUNK;
+            let chunk_end = chunk_start.saturating_add(NONCES_PER_CHUNK).min(nonces.end);
+            search_chunk(prefix, chunk_start..chunk_end, is_match)
+        },
+        |mut matches, more_matches| {
+            matches.extend(more_matches);
+            matches
+        },
+    )
+    .unwrap_or_default()
+}
+
+fn search_chunk(
+    prefix: &[u8],
+    nonces: Range<u32>,
+    is_match: &impl Fn(&[u8; 16]) -> bool,
+) -> Vec<(u32, [u8; 16])> {
+    let mut matches = Vec::new();
+    let mut messages: [Vec<u8>; LANES] = core::array::from_fn(|_| prefix.to_vec());
+    let mut ascii_bytes_contexts: [IntToAsciiContext; LANES] =
+        core::array::from_fn(|_| IntToAsciiContext::new());
+    for batch_start in nonces.clone().step_by(LANES) {
+        for (lane, (message, ascii_bytes_context)) in messages
+            .iter_mut()
+            .zip(ascii_bytes_contexts.iter_mut())
+            .enumerate()
+        {
+            // The last batch may extend past the end, which is hashed but ignored:
+            let nonce = batch_start.saturating_add(lane as u32);
+            message.truncate(prefix.len());
+            message.extend_from_slice(ascii_bytes_context.ascii_bytes(nonce));
+        }
+        let digests = compute_lanes(messages.each_ref().map(Vec::as_slice));
+        for (lane, digest) in digests.iter().enumerate() {
+            let nonce = batch_start.saturating_add(lane as u32);
+            if nonces.contains(&nonce) && is_match(digest) {
+                matches.push((nonce, *digest));
+            }
+        }
+    }
+    matches
+}
+
 /// Compute the digest of data.
 #[inline]
 #[cfg(test)]
@@ -121,7 +406,7 @@ pub fn compute<T: AsRef<[u8]>>(data: T) -> [u8; 16] {
 
 #[cfg(test)]
 fn lower_hex(data: &[u8]) -> String {
-    use std::fmt::Write;
+    use core::fmt::Write;
     let mut buf = String::new();
     for value in data {
         write!(buf, "{value:02x}").unwrap();
@@ -130,16 +415,16 @@ fn lower_hex(data: &[u8]) -> String {
     buf
 }
 
-const fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
+fn transform<W: Word>(state: &mut [W; 4], input: &[W; 16]) {
     #![allow(clippy::unreadable_literal)]
     #![allow(clippy::tuple_array_conversions)]
     let (mut a, mut b, mut c, mut d) = (state[0], state[1], state[2], state[3]);
     macro_rules! add(
-        ($a:expr, $b:expr) => ($a.wrapping_add($b));
+        ($a:expr, $b:expr) => ($a.add($b));
     );
     macro_rules! rotate(
         //($x:expr, $n:expr) => (($x << $n) | ($x >> (32 - $n)));
-        ($x:expr, $n:expr) => ($x.rotate_left($n));
+        ($x:expr, $n:expr) => ($x.rotate($n));
     );
     {
         macro_rules! F(
@@ -147,7 +432,7 @@ const fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
         );
         macro_rules! T(
             ($a:expr, $b:expr, $c:expr, $d:expr, $x:expr, $s:expr, $ac:expr) => ({
-                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), $ac);
+                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), W::splat($ac));
                 $a = rotate!($a, $s);
                 $a = add!($a, $b);
             });
@@ -179,7 +464,7 @@ const fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
         );
         macro_rules! T(
             ($a:expr, $b:expr, $c:expr, $d:expr, $x:expr, $s:expr, $ac:expr) => ({
-                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), $ac);
+                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), W::splat($ac));
                 $a = rotate!($a, $s);
                 $a = add!($a, $b);
             });
@@ -211,7 +496,7 @@ const fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
         );
         macro_rules! T(
             ($a:expr, $b:expr, $c:expr, $d:expr, $x:expr, $s:expr, $ac:expr) => ({
-                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), $ac);
+                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), W::splat($ac));
                 $a = rotate!($a, $s);
                 $a = add!($a, $b);
             });
@@ -243,7 +528,7 @@ const fn transform(state: &mut [u32; 4], input: &[u32; 16]) {
         );
         macro_rules! T(
             ($a:expr, $b:expr, $c:expr, $d:expr, $x:expr, $s:expr, $ac:expr) => ({
-                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), $ac);
+                $a = add!(add!(add!($a, F!($b, $c, $d)), $x), W::splat($ac));
                 $a = rotate!($a, $s);
                 $a = add!($a, $b);
             });
@@ -314,6 +599,7 @@ mod tests {
 
     #[test]
     fn overflow_count() {
+        use alloc::vec;
         let data = vec![0; 8 * 1024 * 1024];
         let mut context = super::Context::new();
         for _ in 0..64 {
@@ -324,4 +610,48 @@ mod tests {
             "aa559b4e3523a6c931f08f4df52d58f2"
         );
     }
+
+    #[test]
+    fn compute_lanes() {
+        use alloc::vec::Vec;
+        let data = (0..200_u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect::<Vec<_>>();
+        // Messages of all lengths up to three blocks, with lengths differing between lanes:
+        for length in 0..data.len() - super::LANES * 17 {
+            let messages: [&[u8]; super::LANES] =
+                core::array::from_fn(|lane| &data[lane..=length + lane * 17]);
+            let digests = super::compute_lanes(messages);
+            for (message, digest) in messages.iter().zip(digests) {
+                assert_eq!(digest, super::compute(message));
+            }
+        }
+    }
+
+    #[test]
+    fn search_nonces() {
+        use alloc::vec::Vec;
+        let is_match = |digest: &[u8; 16]| digest[0] == 0;
+        let mut matches = Vec::new();
+        let stopped_at = super::search_nonces(b"abc", 0..300_000, is_match, |nonce, digest| {
+            assert_eq!(digest, super::compute(alloc::format!("abc{nonce}")));
+            matches.push(nonce);
+            matches.len() == 1000
+        });
+        let expected = (0..300_000)
+            .filter(|nonce| is_match(&super::compute(alloc::format!("abc{nonce}"))))
+            .take(1000)
+            .collect::<Vec<_>>();
+        assert_eq!(matches, expected);
+        assert_eq!(stopped_at, Ok(expected.last().copied()));
+
+        assert_eq!(
+            super::search_nonces(b"abc", 10..10, is_match, |_, _| true),
+            Ok(None)
+        );
+        assert_eq!(
+            super::search_nonces(b"abc", u32::MAX - 3..u32::MAX, |_| true, |_, _| true),
+            Ok(Some(u32::MAX - 3))
+        );
+    }
 }
@@ -0,0 +1,362 @@
+use alloc::string::{String, ToString};
+
+/// Returns the greatest common divisor of `a` and `b`, which is zero only if both are.
+pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns the least common multiple of `a` and `b`, or `None` on overflow.
+pub const fn lcm(a: u64, b: u64) -> Option<u64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Returns the sum of all positive divisors of `n`, including `n` itself, or `None` on
+/// overflow.
+///
+/// Takes `sqrt(n)` steps, so `n` should be bounded by the caller.
+pub const fn divisor_sum(n: u64) -> Option<u64> {
+    let mut sum: u64 = 0;
+    let mut i = 1;
+    // Comparing with `n / i` instead of `i * i` with `n`, which may overflow:
+    while i <= n / i {
+        if n.is_multiple_of(i) {
+            let Some(new_sum) = sum.checked_add(i) else {
+                return None;
+            };
+            sum = new_sum;
+            if i != n / i {
+                let Some(new_sum) = sum.checked_add(n / i) else {
+                    return None;
+                };
+                sum = new_sum;
+            }
+        }
+        i += 1;
+    }
+    Some(sum)
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ExtendedEuclidResult {
+    pub gcd: i128,
+    pub x: i128,
+    pub y: i128,
+}
+
+/// Given the integers `a` and `b`, compute:
+///
+/// - `gcd`, the greatest common divisor of `a` and `b`.
+/// - Two integers `x` and `y` such that `gcd = a * x + b * y`, called the "Bézout coefficients".
+///
+/// See <https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm/>
+/// and <https://cp-algorithms.com/algebra/extended-euclid-algorithm.html#toc-tgt-0/>
+pub const fn extended_euclid(a: i128, b: i128) -> ExtendedEuclidResult {
+    // Each step replaces (a, b) with (b, a % b), keeping coefficients for both:
+    //     a = a0 * x + b0 * y
+    //     b = a0 * next_x + b0 * next_y
+    // Since `a % b` can be written as `a - (a / b) * b`, its coefficients are
+    // `x - (a / b) * next_x` and `y - (a / b) * next_y`.
+    let (mut a, mut b) = (a, b);
+    let (mut x, mut next_x) = (1, 0);
+    let (mut y, mut next_y) = (0, 1);
+    while b != 0 {
+        let quotient = a / b;
+        (a, b) = (b, a - quotient * b);
+        (x, next_x) = (next_x, x - quotient * next_x);
+        (y, next_y) = (next_y, y - quotient * next_y);
+    }
+    ExtendedEuclidResult { gcd: a, x, y }
+}
+
+/// Find the modular multiplicative inverse of the integer `a` with respect to modulo `m`
+/// if and only if `a` and `m` are coprime (the only positive integer dividing both are 1).
+///
+/// That is, the value `x` returned, from `0` to `m - 1`, makes `(a * x) % m` equal `1`.
+pub const fn modular_inverse(a: i128, m: i128) -> Option<i128> {
+    // See https://en.wikipedia.org/wiki/Modular_multiplicative_inverse#Extended_Euclidean_algorithm
+    // The extended Euclidean algorithm gives us `x` and `y` such that:
+    //     a * x + m * y = gcd(a, m)
+    // Since gcd(a, m) is 1 when a and m are coprime, this can be rewritten as:
+    //     a * x + m * y = 1
+    // Which means that
+    //     (a * x) % m = 1
+    // So that x is the searched after modular multiplicative inverse, which
+    // we finally need to make positive if necessary.
+    if m <= 0 {
+        return None;
+    }
+    let result = extended_euclid(a.rem_euclid(m), m);
+    if result.gcd == 1 {
+        Some(result.x.rem_euclid(m))
+    } else {
+        None
+    }
+}
+
+/// Returns `(a * b) % modulus` without overflowing.
+pub const fn mul_mod(a: u64, b: u64, modulus: u64) -> u64 {
+    ((a as u128 * b as u128) % modulus as u128) as u64
+}
+
+/// Returns `(a * b)` modulo `modulus`, from `0` to `modulus - 1`, without overflowing for
+/// any `a` and `b` and positive `modulus`.
+pub const fn mul_mod_i128(a: i128, b: i128, modulus: i128) -> i128 {
+    let modulus_unsigned = modulus as u128;
+    let mut a = a.rem_euclid(modulus) as u128;
+    let mut b = b.rem_euclid(modulus) as u128;
+    if let Some(product) = a.checked_mul(b) {
+        return (product % modulus_unsigned) as i128;
+    }
+    // Double and add, which does not overflow since both terms are below 2^127:
+    let mut result = 0;
+    while b > 0 {
+        if b & 1 == 1 {
+            result = (result + a) % modulus_unsigned;
+        }
+        a = (a + a) % modulus_unsigned;
+        b >>= 1;
+    }
+    result as i128
+}
+
+/// Returns `base` raised to `exponent` modulo `modulus`, from `0` to `modulus - 1`.
+pub fn mod_exp(base: i128, exponent: i128, modulus: i128) -> Result<i128, String> {
+    if modulus <= 0 {
+        return Err("Modular exponentiation with a non-positive modulus".to_string());
+    } else if exponent < 0 {
+        return Err("Modular exponentiation with a negative exponent".to_string());
+    }
+
+    let mut result = 1 % modulus;
+    let mut base = base.rem_euclid(modulus);
+    let mut exponent = exponent;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod_i128(result, base, modulus);
+        }
+        exponent >>= 1;
+        base = mul_mod_i128(base, base, modulus);
+    }
+    Ok(result)
+}
+
+/// The integers `x` with `x % modulus == remainder`, that is, `x ≡ remainder (mod modulus)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Congruence {
+    pub remainder: i128,
+    pub modulus: i128,
+}
+
+/// Combines congruences into one satisfied by exactly the integers satisfying all of them,
+/// with a remainder from `0` to the least common multiple of the moduli.
+///
+/// The moduli need not be coprime. Returns `None` if the congruences contradict each other,
+/// a modulus is not positive or the combined modulus overflows.
+///
+/// See <https://en.wikipedia.org/wiki/Chinese_remainder_theorem/>
+/// and <https://cp-algorithms.com/algebra/chinese-remainder-theorem.html>.
+pub fn chinese_remainder(congruences: impl IntoIterator<Item = Congruence>) -> Option<Congruence> {
+    let mut combined = Congruence {
+        remainder: 0,
+        modulus: 1,
+    };
+    for congruence in congruences {
+        if congruence.modulus <= 0 {
+            return None;
+        }
+        // Searching for `x = combined.remainder + combined.modulus * k` such that
+        //     combined.modulus * k ≡ congruence.remainder - combined.remainder (mod congruence.modulus)
+        // which is solvable if the gcd of the moduli divides the difference, and then
+        // dividing everything with the gcd gives an equation with an invertible factor:
+        let ExtendedEuclidResult { gcd, .. } =
+            extended_euclid(combined.modulus, congruence.modulus);
+        let difference = congruence.remainder - combined.remainder;
+        if difference % gcd != 0 {
+            return None;
+        }
+        let reduced_modulus = congruence.modulus / gcd;
+        let k = mul_mod_i128(
+            difference / gcd,
+            modular_inverse(combined.modulus / gcd, reduced_modulus)?,
+            reduced_modulus,
+        );
+        let modulus = combined.modulus.checked_mul(reduced_modulus)?;
+        combined = Congruence {
+            remainder: (combined.remainder + mul_mod_i128(combined.modulus, k, modulus))
+                .rem_euclid(modulus),
+            modulus,
+        };
+    }
+    Some(combined)
+}
+
+/// The function `x -> (multiplier * x + increment) % modulus`, as used by linear congruential
+/// generators, which stays of this form when composed with itself or similar functions.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct LinearFunction {
+    pub multiplier: i128,
+    pub increment: i128,
+    pub modulus: i128,
+}
+
+impl LinearFunction {
+    pub const fn identity(modulus: i128) -> Self {
+        Self {
+            multiplier: 1,
+            increment: 0,
+            modulus,
+        }
+    }
+
+    /// Returns the function value, from `0` to `modulus - 1`.
+    pub const fn apply(&self, x: i128) -> i128 {
+        (mul_mod_i128(self.multiplier, x, self.modulus) + self.increment.rem_euclid(self.modulus))
+            .rem_euclid(self.modulus)
+    }
+
+    /// Returns the function applying this function and then `next`, which is assumed to have
+    /// the same modulus.
+    pub const fn then(&self, next: &Self) -> Self {
+        // next(self(x)) = next.multiplier * (self.multiplier * x + self.increment) + next.increment
+        Self {
+            multiplier: mul_mod_i128(next.multiplier, self.multiplier, self.modulus),
+            increment: next.apply(self.increment),
+            modulus: self.modulus,
+        }
+    }
+
+    /// Returns the function applying this function `exponent` times.
+    pub const fn pow(&self, mut exponent: u64) -> Self {
+        let mut result = Self::identity(self.modulus);
+        let mut power = *self;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.then(&power);
+            }
+            power = power.then(&power);
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// Returns the function undoing this function, if the multiplier is invertible.
+    pub const fn inverse(&self) -> Option<Self> {
+        // y = a * x + b means that x = a⁻¹ * y - a⁻¹ * b:
+        let Some(multiplier) = modular_inverse(self.multiplier, self.modulus) else {
+            return None;
+        };
+        Some(Self {
+            multiplier,
+            increment: mul_mod_i128(-multiplier, self.increment, self.modulus),
+            modulus: self.modulus,
+        })
+    }
+}
+
+#[test]
+fn divisors() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(gcd(0, 0), 0);
+    assert_eq!(lcm(4, 6), Some(12));
+    assert_eq!(lcm(8, 9), Some(72));
+    assert_eq!(lcm(0, 9), Some(0));
+    assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    assert_eq!(divisor_sum(0), Some(0));
+    assert_eq!(divisor_sum(1), Some(1));
+    assert_eq!(divisor_sum(12), Some(1 + 2 + 3 + 4 + 6 + 12));
+    assert_eq!(divisor_sum(49), Some(1 + 7 + 49));
+}
+
+#[test]
+fn modular_arithmetic() {
+    assert_eq!(
+        extended_euclid(240, 46),
+        ExtendedEuclidResult {
+            gcd: 2,
+            x: -9,
+            y: 47
+        }
+    );
+    assert_eq!(modular_inverse(3, 11), Some(4));
+    assert_eq!(modular_inverse(-3, 11), Some(7));
+    assert_eq!(modular_inverse(6, 9), None);
+
+    assert_eq!(mul_mod(u64::MAX, u64::MAX, 1_000_000_007), 114_944_269);
+    assert_eq!(mul_mod_i128(-2, 3, 7), 1);
+    // Products which would overflow i128 and u128:
+    let large_prime = i128::MAX;
+    assert_eq!(mul_mod_i128(1 << 100, 1 << 27, large_prime), 1);
+    assert_eq!(
+        mul_mod_i128(large_prime - 1, large_prime - 1, large_prime),
+        1
+    );
+
+    assert_eq!(mod_exp(4, 13, 497), Ok(445));
+    assert_eq!(mod_exp(-4, 3, 10), Ok(6));
+    assert_eq!(mod_exp(5, 0, 1), Ok(0));
+    // Fermat's little theorem, with a modulus whose square does not fit in i128:
+    assert_eq!(mod_exp(3, large_prime - 1, large_prime), Ok(1));
+    assert!(mod_exp(2, 3, 0).is_err());
+    assert!(mod_exp(2, -3, 5).is_err());
+}
+
+#[test]
+fn chinese_remainders() {
+    let congruence = |remainder, modulus| Congruence { remainder, modulus };
+    assert_eq!(
+        chinese_remainder([congruence(2, 3), congruence(3, 5), congruence(2, 7)]),
+        Some(congruence(23, 105))
+    );
+    // Moduli which are not coprime:
+    assert_eq!(
+        chinese_remainder([congruence(2, 4), congruence(4, 6)]),
+        Some(congruence(10, 12))
+    );
+    assert_eq!(
+        chinese_remainder([congruence(1, 4), congruence(2, 6)]),
+        None
+    );
+    assert_eq!(
+        chinese_remainder([congruence(-1, 7), congruence(-20, 13)]),
+        Some(congruence(6, 91))
+    );
+    assert_eq!(chinese_remainder([]), Some(congruence(0, 1)));
+    assert_eq!(chinese_remainder([congruence(1, 0)]), None);
+}
+
+#[test]
+fn linear_functions() {
+    let f = LinearFunction {
+        multiplier: 3,
+        increment: 4,
+        modulus: 11,
+    };
+    assert_eq!(f.apply(5), 8);
+    assert_eq!(f.then(&f).apply(5), f.apply(f.apply(5)));
+    assert_eq!(f.pow(0), LinearFunction::identity(11));
+    let mut x = 5;
+    for _ in 0..1000 {
+        x = f.apply(x);
+    }
+    assert_eq!(f.pow(1000).apply(5), x);
+    assert_eq!(
+        f.inverse().map(|inverse| inverse.apply(f.apply(5))),
+        Some(5)
+    );
+    assert_eq!(
+        LinearFunction {
+            multiplier: 2,
+            increment: 1,
+            modulus: 4
+        }
+        .inverse(),
+        None
+    );
+}
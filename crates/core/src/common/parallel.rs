@@ -0,0 +1,115 @@
+//! Spreading independent work over multiple threads with the `parallel` feature.
+//!
+//! Without the feature - or where threads cannot be started, such as in wasm without
+//! thread support - all work runs on the calling thread. Results are combined in the
+//! same order either way, so they do not depend on the number of threads.
+#[cfg(any(feature = "parallel", test))]
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The number of threads to spread work over.
+#[cfg(feature = "parallel")]
+pub fn num_threads() -> usize {
+    std::thread::available_parallelism().map_or(1, core::num::NonZeroUsize::get)
+}
+
+/// The number of threads to spread work over.
+#[cfg(not(feature = "parallel"))]
+pub const fn num_threads() -> usize {
+    1
+}
+
+/// Compute `work` for each index in `0..count`, and combine the results in index order
+/// with the associative `combine`. Returns `None` if `count` is zero.
+pub fn map_reduce<R: Send>(
+    count: usize,
+    work: impl Fn(usize) -> R + Sync,
+    combine: impl Fn(R, R) -> R + Sync,
+) -> Option<R> {
+    #[cfg(feature = "parallel")]
+    {
+        let threads = num_threads().min(count);
+        if threads > 1 {
+            return map_reduce_on_threads(threads, count, &work, &combine);
+        }
+    }
+    (0..count).map(work).reduce(combine)
+}
+
+/// Map each item with `work`, returning the results in the order of the items.
+pub fn map<T: Sync, R: Send>(items: &[T], work: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    #[cfg(feature = "parallel")]
+    if num_threads().min(items.len()) > 1 {
+        return map_reduce(
+            items.len(),
+            |index| vec![work(&items[index])],
+            |mut results, more_results| {
+                results.extend(more_results);
+                results
+            },
+        )
+        .unwrap_or_default();
+    }
+    items.iter().map(work).collect()
+}
+
+#[cfg(feature = "parallel")]
+fn map_reduce_on_threads<R: Send>(
+    threads: usize,
+    count: usize,
+    work: &(impl Fn(usize) -> R + Sync),
+    combine: &(impl Fn(R, R) -> R + Sync),
+) -> Option<R> {
+    let reduce_range = |range: core::ops::Range<usize>| range.map(work).reduce(combine);
+    std::thread::scope(|scope| {
+        // Start all threads before joining them in order:
+        #[allow(clippy::needless_collect)]
+        let spawned = (0..threads)
+            .map(|thread| {
+                let range = (thread * count / threads)..((thread + 1) * count / threads);
+                let handle = std::thread::Builder::new()
+                    .spawn_scoped(scope, {
+                        let range = range.clone();
+                        move || reduce_range(range)
+                    })
+                    .ok();
+                (range, handle)
+            })
+            .collect::<Vec<_>>();
+        spawned
+            .into_iter()
+            .filter_map(|(range, handle)| {
+                handle.map_or_else(
+                    || reduce_range(range),
+                    |handle| {
+                        handle
+                            .join()
+                            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                    },
+                )
+            })
+            .reduce(combine)
+    })
+}
+
+#[test]
+fn ordered_results() {
+    use alloc::string::{String, ToString};
+
+    assert_eq!(map_reduce(0, |i| i, |a, b| a + b), None);
+    assert_eq!(
+        map_reduce(1000, |i| i * i, |a, b| a + b),
+        Some((0..1000).map(|i| i * i).sum())
+    );
+    // The results are combined in order, so a non-commutative combination works:
+    assert_eq!(
+        map_reduce(20, |i| i.to_string(), |a, b| a + "," + &b),
+        Some(
+            (0..20)
+                .map(|i| i.to_string())
+                .collect::<Vec<String>>()
+                .join(",")
+        )
+    );
+    assert_eq!(map(&[3, 1, 2], |&value| value * 10), vec![30, 10, 20]);
+}
@@ -1,4 +1,7 @@
-use std::str::FromStr;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::str::FromStr;
 
 pub fn parse_lines<T: FromStr>(input: &str) -> Result<Vec<T>, String> {
     input
@@ -1,3 +1,5 @@
+use alloc::string::String;
+
 /// Generate all permutations of a sequence using Heap's algorithm.
 pub fn all_permutations<F, T>(sequence: &mut [T], on_permutation: &mut F) -> Result<(), String>
 where
@@ -1,3 +1,6 @@
+use crate::common::storage::capacity_exceeded;
+use alloc::string::String;
+
 /// A binary heap implementation of a priority queue.
 pub struct PriorityQueue<const MAX_SIZE: usize, H: Copy + Clone + Default + PartialOrd> {
     pub elements: [H; MAX_SIZE],
@@ -14,7 +17,7 @@ impl<const MAX_SIZE: usize, H: Copy + Clone + Default + PartialOrd> PriorityQueu
 
     pub fn push(&mut self, new_element: H) -> Result<(), String> {
         if self.len == MAX_SIZE {
-            return Err("Too many elements pushed".to_string());
+            return Err(capacity_exceeded());
         }
         let mut current_index = self.len;
         self.len += 1;
@@ -0,0 +1,120 @@
+//! Progress reporting from long-running solvers, which also serves as the point
+//! where they may be cancelled.
+//!
+//! Listeners are kept per thread, so without the `std` feature progress is not reported.
+use alloc::format;
+use alloc::string::String;
+#[cfg(feature = "std")]
+use alloc::{boxed::Box, string::ToString};
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+
+/// How far a solver has come, as `completed` out of at most `total` units of work.
+///
+/// The total is an upper bound, such as an iteration limit, so a solver may finish
+/// long before `completed` reaches `total`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub completed: u64,
+    pub total: u64,
+}
+
+/// The error returned by solvers being cancelled.
+pub const CANCELLED_MESSAGE: &str = "Cancelled";
+
+/// The start of the error returned by solvers giving up after exceeding their budget.
+pub const BUDGET_EXCEEDED_PREFIX: &str = "Aborted after ";
+
+/// Returns the error for a solver giving up after `work`, as in "1000 iterations".
+pub fn budget_exceeded(work: impl core::fmt::Display) -> String {
+    format!("{BUDGET_EXCEEDED_PREFIX}{work}")
+}
+
+#[cfg(feature = "std")]
+type Listener = Box<dyn FnMut(Progress) -> bool>;
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static LISTENER: RefCell<Option<Listener>> = const { RefCell::new(None) };
+}
+
+/// Removes the listener when dropped, also when the solver panics.
+#[cfg(feature = "std")]
+pub struct ListenerGuard {
+    previous: Option<Listener>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for ListenerGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        LISTENER.with(|listener| *listener.borrow_mut() = previous);
+    }
+}
+
+/// Installs a listener for progress reported on this thread while the returned guard is alive.
+///
+/// The listener returns whether the solver should continue.
+#[cfg(feature = "std")]
+pub fn listen(listener: impl FnMut(Progress) -> bool + 'static) -> ListenerGuard {
+    let previous = LISTENER.with(|current| current.borrow_mut().replace(Box::new(listener)));
+    ListenerGuard { previous }
+}
+
+/// Reports progress to the installed listener, if any, returning an error if cancelled.
+///
+/// Invoking the listener has a cost, so solvers should report at coarse intervals.
+#[cfg(feature = "std")]
+pub fn report(completed: u64, total: u64) -> Result<(), String> {
+    let keep_going = LISTENER.with(|listener| {
+        // A listener which itself solves problems, and so reports progress, is not notified again:
+        listener.try_borrow_mut().map_or(true, |mut listener| {
+            listener
+                .as_mut()
+                .is_none_or(|listener| listener(Progress { completed, total }))
+        })
+    });
+    if keep_going {
+        Ok(())
+    } else {
+        Err(CANCELLED_MESSAGE.to_string())
+    }
+}
+
+/// Reports progress, which without listeners never cancels the solver.
+#[cfg(not(feature = "std"))]
+#[allow(clippy::unnecessary_wraps)]
+pub const fn report(_completed: u64, _total: u64) -> Result<(), String> {
+    Ok(())
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn progress() {
+    use alloc::rc::Rc;
+    use core::cell::Cell;
+
+    assert_eq!(report(1, 10), Ok(()));
+
+    let reported = Rc::new(Cell::new(None));
+    {
+        let listener_reported = Rc::clone(&reported);
+        let _guard = listen(move |progress| {
+            listener_reported.set(Some(progress));
+            progress.completed < 5
+        });
+        assert_eq!(report(2, 10), Ok(()));
+        assert_eq!(
+            reported.get(),
+            Some(Progress {
+                completed: 2,
+                total: 10
+            })
+        );
+        assert_eq!(report(5, 10), Err(CANCELLED_MESSAGE.to_string()));
+    }
+
+    reported.set(None);
+    assert_eq!(report(7, 10), Ok(()));
+    assert_eq!(reported.get(), None);
+}
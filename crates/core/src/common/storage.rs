@@ -0,0 +1,189 @@
+//! Storage for solvers which avoid allocations by using arrays sized for official inputs.
+//!
+//! Such a solver is written against the traits below, and solves an input with fixed-capacity
+//! storage first. If that fails with [CAPACITY_EXCEEDED], as for community made stress test
+//! inputs, it is solved again with heap-allocated storage by [with_heap_fallback].
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+
+use crate::common::array_deque::ArrayDeque;
+use crate::common::array_stack::ArrayStack;
+use crate::common::bitset::BitSet;
+use crate::common::priority_queueu::PriorityQueue;
+use crate::input::Input;
+
+/// The error returned when an input does not fit into fixed-capacity storage.
+pub const CAPACITY_EXCEEDED: &str = "Too many elements pushed";
+
+pub fn capacity_exceeded() -> String {
+    CAPACITY_EXCEEDED.to_string()
+}
+
+/// Solves the input with `fixed`, or with `heap` if the input does not fit into the
+/// fixed-capacity storage used by `fixed`.
+pub fn with_heap_fallback<T>(
+    input: &Input,
+    fixed: fn(&Input) -> Result<T, String>,
+    heap: fn(&Input) -> Result<T, String>,
+) -> Result<T, String> {
+    match fixed(input) {
+        Err(error) if error == CAPACITY_EXCEEDED => heap(input),
+        result => result,
+    }
+}
+
+/// A stack, such as an [ArrayStack] or a [Vec].
+pub trait Stack<H> {
+    fn push(&mut self, element: H) -> Result<(), String>;
+    fn slice(&self) -> &[H];
+}
+
+impl<const MAX_SIZE: usize, H: Copy + Clone + Default> Stack<H> for ArrayStack<MAX_SIZE, H> {
+    fn push(&mut self, element: H) -> Result<(), String> {
+        Self::push(self, element)
+    }
+
+    fn slice(&self) -> &[H] {
+        Self::slice(self)
+    }
+}
+
+impl<H> Stack<H> for Vec<H> {
+    fn push(&mut self, element: H) -> Result<(), String> {
+        Self::push(self, element);
+        Ok(())
+    }
+
+    fn slice(&self) -> &[H] {
+        self
+    }
+}
+
+/// A first-in, first-out queue, such as an [ArrayDeque] or a [VecDeque].
+pub trait Queue<H> {
+    fn push_back(&mut self, element: H) -> Result<(), String>;
+    fn pop_front(&mut self) -> Option<H>;
+}
+
+impl<const MAX_SIZE: usize, H: Eq + Copy + Clone + Default> Queue<H> for ArrayDeque<MAX_SIZE, H> {
+    fn push_back(&mut self, element: H) -> Result<(), String> {
+        Self::push_back(self, element)
+    }
+
+    fn pop_front(&mut self) -> Option<H> {
+        Self::pop_front(self)
+    }
+}
+
+impl<H> Queue<H> for VecDeque<H> {
+    fn push_back(&mut self, element: H) -> Result<(), String> {
+        Self::push_back(self, element);
+        Ok(())
+    }
+
+    fn pop_front(&mut self) -> Option<H> {
+        Self::pop_front(self)
+    }
+}
+
+/// A priority queue popping the minimum element, such as a [PriorityQueue] or a [BinaryHeap]
+/// of [Reverse] elements.
+pub trait MinQueue<H> {
+    fn push(&mut self, element: H) -> Result<(), String>;
+    fn pop(&mut self) -> Option<H>;
+}
+
+impl<const MAX_SIZE: usize, H: Copy + Clone + Default + PartialOrd> MinQueue<H>
+    for PriorityQueue<MAX_SIZE, H>
+{
+    fn push(&mut self, element: H) -> Result<(), String> {
+        Self::push(self, element)
+    }
+
+    fn pop(&mut self) -> Option<H> {
+        Self::pop(self)
+    }
+}
+
+impl<H: Ord> MinQueue<H> for BinaryHeap<Reverse<H>> {
+    fn push(&mut self, element: H) -> Result<(), String> {
+        Self::push(self, Reverse(element));
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<H> {
+        Self::pop(self).map(|Reverse(element)| element)
+    }
+}
+
+/// A grid of bits, such as an array of [BitSet] rows or a [HeapBitGrid].
+pub trait BitGrid {
+    fn is_bit_set(&self, x: usize, y: usize) -> bool;
+    fn set_bit(&mut self, x: usize, y: usize);
+    fn count_ones(&self) -> u32;
+}
+
+impl<const WORDS: usize, const NUM_ROWS: usize> BitGrid for [BitSet<WORDS>; NUM_ROWS] {
+    fn is_bit_set(&self, x: usize, y: usize) -> bool {
+        self[y].is_bit_set(x)
+    }
+
+    fn set_bit(&mut self, x: usize, y: usize) {
+        self[y].set_bit(x);
+    }
+
+    fn count_ones(&self) -> u32 {
+        self.iter().map(BitSet::count_ones).sum()
+    }
+}
+
+/// A heap-allocated grid of bits without any size limit.
+#[derive(Clone)]
+pub struct HeapBitGrid {
+    words_per_row: usize,
+    words: Vec<u64>,
+}
+
+impl HeapBitGrid {
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(64);
+        Self {
+            words_per_row,
+            words: vec![0; words_per_row * height],
+        }
+    }
+}
+
+impl BitGrid for HeapBitGrid {
+    fn is_bit_set(&self, x: usize, y: usize) -> bool {
+        self.words[y * self.words_per_row + x / 64] & (1 << (x % 64)) != 0
+    }
+
+    fn set_bit(&mut self, x: usize, y: usize) {
+        self.words[y * self.words_per_row + x / 64] |= 1 << (x % 64);
+    }
+
+    fn count_ones(&self) -> u32 {
+        self.words.iter().map(|word| word.count_ones()).sum()
+    }
+}
+
+#[test]
+fn bit_grids() {
+    fn check(grid: &mut impl BitGrid) {
+        for (x, y) in [(0, 0), (63, 1), (64, 1), (199, 2)] {
+            assert!(!grid.is_bit_set(x, y));
+            grid.set_bit(x, y);
+            assert!(grid.is_bit_set(x, y));
+        }
+        grid.set_bit(0, 0);
+        assert_eq!(grid.count_ones(), 4);
+        assert!(!grid.is_bit_set(1, 0));
+        assert!(!grid.is_bit_set(64, 0));
+    }
+    check(&mut [BitSet::<4>::new(); 3]);
+    check(&mut HeapBitGrid::new(200, 3));
+}
@@ -0,0 +1,234 @@
+//! Shared building blocks for the register machines appearing in several years:
+//! assembunny (2016), duet (2017), elfcode (2018) and the 3-bit computer (2024 day 17).
+use alloc::format;
+use alloc::string::String;
+use core::hash::Hash;
+
+use crate::common::progress;
+
+/// An instruction operand, either an immediate value or a register.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Operand<W> {
+    Value(W),
+    Register(u8),
+}
+
+impl<W: core::str::FromStr> Operand<W> {
+    /// Parses a single letter register name in `registers`, or else an immediate value.
+    pub fn parse(input: &str, registers: core::ops::RangeInclusive<u8>) -> Result<Self, String> {
+        parse_register(input, registers).map_or_else(
+            || {
+                input
+                    .parse::<W>()
+                    .map(Self::Value)
+                    .map_err(|_| format!("Invalid operand: '{input}'"))
+            },
+            |register| Ok(Self::Register(register)),
+        )
+    }
+}
+
+impl<W: Copy> Operand<W> {
+    pub fn value(self, registers: &[W]) -> W {
+        match self {
+            Self::Value(value) => value,
+            Self::Register(register) => registers[register as usize],
+        }
+    }
+}
+
+/// Parses a single letter register name in `registers`, as in `b'a'..=b'd'`, into an index from 0.
+pub fn parse_register(input: &str, registers: core::ops::RangeInclusive<u8>) -> Option<u8> {
+    match input.as_bytes() {
+        &[c] if registers.contains(&c) => Some(c - registers.start()),
+        _ => None,
+    }
+}
+
+/// The result of executing a single instruction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Step<O> {
+    /// An instruction without any externally visible effect was executed.
+    Executed,
+    /// An instruction producing output was executed.
+    Output(O),
+    /// No instruction was executed, as execution waits for input.
+    Blocked,
+    /// No instruction was executed, as the instruction pointer is outside the program.
+    Halted,
+}
+
+/// A limit on the number of instructions to execute.
+///
+/// Progress is reported periodically, allowing long-running programs to be cancelled.
+pub struct Budget {
+    limit: u64,
+    used: u64,
+}
+
+impl Budget {
+    const PROGRESS_INTERVAL: u64 = 1 << 16;
+
+    pub const fn new(limit: u64) -> Self {
+        Self { limit, used: 0 }
+    }
+
+    pub fn consume(&mut self) -> Result<(), String> {
+        if self.used >= self.limit {
+            return Err(progress::budget_exceeded(format_args!(
+                "{} instructions",
+                self.limit
+            )));
+        }
+        self.used += 1;
+        if self.used.is_multiple_of(Self::PROGRESS_INTERVAL) {
+            progress::report(self.used, self.limit)?;
+        }
+        Ok(())
+    }
+
+    /// Consumes the budget of `count` instructions at once, such as for a block of
+    /// instructions executed together.
+    pub fn consume_many(&mut self, count: u64) -> Result<(), String> {
+        if self.limit - self.used < count {
+            return Err(progress::budget_exceeded(format_args!(
+                "{} instructions",
+                self.limit
+            )));
+        }
+        let previous_intervals = self.used / Self::PROGRESS_INTERVAL;
+        self.used += count;
+        if self.used / Self::PROGRESS_INTERVAL != previous_intervals {
+            progress::report(self.used, self.limit)?;
+        }
+        Ok(())
+    }
+}
+
+pub trait Machine {
+    type Output;
+    /// A snapshot of everything affecting future execution, used for cycle detection.
+    type State: Eq + Hash;
+
+    /// Executes a single instruction.
+    fn step(&mut self) -> Result<Step<Self::Output>, String>;
+
+    fn state(&self) -> Self::State;
+
+    /// Executes instructions until blocking, halting or `stop` returning true.
+    ///
+    /// The `stop` function is called after each executed instruction, and so also serves
+    /// as a hook for tracing execution.
+    fn run_until<F>(
+        &mut self,
+        budget: &mut Budget,
+        mut stop: F,
+    ) -> Result<Step<Self::Output>, String>
+    where
+        F: FnMut(&Self, &Step<Self::Output>) -> bool,
+    {
+        loop {
+            budget.consume()?;
+            let step = self.step()?;
+            if matches!(step, Step::Blocked | Step::Halted) || stop(self, &step) {
+                return Ok(step);
+            }
+        }
+    }
+
+    /// Executes instructions until blocking or halting.
+    fn run(&mut self, budget: &mut Budget) -> Result<Step<Self::Output>, String> {
+        self.run_until(budget, |_, _| false)
+    }
+
+    /// Executes instructions until output is produced, returning None on blocking or halting.
+    fn run_until_output(&mut self, budget: &mut Budget) -> Result<Option<Self::Output>, String> {
+        Ok(
+            match self.run_until(budget, |_, step| matches!(step, Step::Output(_)))? {
+                Step::Output(output) => Some(output),
+                _ => None,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+struct Countdown {
+    ip: usize,
+    counter: u8,
+}
+
+#[cfg(test)]
+impl Machine for Countdown {
+    type Output = u8;
+    type State = (usize, u8);
+
+    fn step(&mut self) -> Result<Step<u8>, String> {
+        // 0: out counter, 1: counter = (counter + 1) % 4, 2: jump to 0
+        Ok(match self.ip {
+            0 => {
+                self.ip += 1;
+                Step::Output(self.counter)
+            }
+            1 => {
+                self.counter = (self.counter + 1) % 4;
+                self.ip += 1;
+                Step::Executed
+            }
+            2 => {
+                self.ip = 0;
+                Step::Executed
+            }
+            _ => Step::Halted,
+        })
+    }
+
+    fn state(&self) -> (usize, u8) {
+        (self.ip, self.counter)
+    }
+}
+
+#[test]
+fn machine() {
+    use alloc::{string::ToString, vec::Vec};
+    assert_eq!(
+        Operand::<i32>::parse("c", b'a'..=b'd'),
+        Ok(Operand::Register(2))
+    );
+    assert_eq!(
+        Operand::<i32>::parse("-12", b'a'..=b'd'),
+        Ok(Operand::Value(-12))
+    );
+    assert!(Operand::<i32>::parse("e", b'a'..=b'd').is_err());
+    assert_eq!(Operand::Register(1).value(&[4, 5]), 5);
+
+    let mut machine = Countdown { ip: 0, counter: 2 };
+    let mut budget = Budget::new(100);
+    assert_eq!(machine.run_until_output(&mut budget), Ok(Some(2)));
+    assert_eq!(machine.run_until_output(&mut budget), Ok(Some(3)));
+
+    let mut ips = Vec::new();
+    let mut budget = Budget::new(100);
+    let _ = machine.run_until(&mut budget, |machine, _| {
+        ips.push(machine.ip);
+        ips.len() == 3
+    });
+    assert_eq!(ips, [2, 0, 1]);
+
+    assert_eq!(
+        machine.run(&mut Budget::new(5)),
+        Err("Aborted after 5 instructions".to_string())
+    );
+
+    let mut budget = Budget::new(10);
+    assert_eq!(budget.consume_many(6), Ok(()));
+    assert_eq!(
+        budget.consume_many(5),
+        Err("Aborted after 10 instructions".to_string())
+    );
+    assert_eq!(budget.consume_many(4), Ok(()));
+    assert!(budget.consume().is_err());
+
+    let mut machine = Countdown { ip: 3, counter: 0 };
+    assert_eq!(machine.run(&mut Budget::new(100)), Ok(Step::Halted));
+}
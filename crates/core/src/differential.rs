@@ -0,0 +1,196 @@
+//! Differential checking of puzzles having alternative implementations, such as ones
+//! using SIMD or WebGPU, which should all give the same answers.
+use crate::input::{Input, Part};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// Adds the fixed-capacity and heap-allocated storage implementations of a puzzle, as
+/// chosen between by [with_heap_fallback](crate::common::storage::with_heap_fallback).
+macro_rules! push_fixed_and_heap {
+    ($result:ident, $($module:ident)::+) => {{
+        $result.push(Implementation {
+            name: "fixed",
+            function: |input| $($module)::+::solve_fixed(input).map(|answer| answer.to_string()),
+        });
+        $result.push(Implementation {
+            name: "heap",
+            function: |input| $($module)::+::solve_heap(input).map(|answer| answer.to_string()),
+        });
+    }};
+}
+
+/// Adds the scalar implementation of a puzzle using MD5, together with the one hashing
+/// several messages at once using [compute_lanes](crate::common::md5::compute_lanes).
+macro_rules! push_scalar_and_lanes {
+    ($result:ident, $($module:ident)::+) => {{
+        $result.push(Implementation {
+            name: "scalar",
+            function: |input| $($module)::+::solve_scalar(input).map(|answer| answer.to_string()),
+        });
+        $result.push(Implementation {
+            name: "lanes",
+            function: |input| $($module)::+::solve(input).map(|answer| answer.to_string()),
+        });
+    }};
+}
+
+/// An implementation of a puzzle solution.
+#[derive(Copy, Clone)]
+pub struct Implementation {
+    /// A short name, as in `simd`.
+    pub name: &'static str,
+    function: fn(&Input) -> Result<String, String>,
+}
+
+impl Implementation {
+    /// Solves the specified part of the puzzle with this implementation.
+    pub fn solve(&self, part: u8, input: &str) -> Result<String, String> {
+        let input = Input {
+            part: if part == 1 { Part::One } else { Part::Two },
+            text: input.trim_end(),
+            params: &[],
+            #[cfg(feature = "visualization")]
+            visualization: core::cell::RefCell::new(String::new()),
+            #[cfg(feature = "trace")]
+            trace_entries: core::cell::RefCell::new(Vec::new()),
+        };
+        (self.function)(&input)
+    }
+}
+
+/// Returns the implementations of a puzzle available with the enabled features, for
+/// puzzles registered for differential checking. Other puzzles have none.
+///
+/// # Example
+/// ```
+/// use advent_of_code::implementations;
+/// assert!(implementations(2022, 23).iter().any(|implementation| implementation.name == "scalar"));
+/// assert!(implementations(2023, 10).iter().any(|implementation| implementation.name == "heap"));
+/// assert!(implementations(2016, 5).iter().any(|implementation| implementation.name == "lanes"));
+/// assert!(implementations(2019, 1).is_empty());
+/// ```
+pub fn implementations(year: u16, day: u8) -> Vec<Implementation> {
+    let mut result = Vec::new();
+    match (year, day) {
+        (2015, 4) => push_scalar_and_lanes!(result, crate::year2015::day04),
+        (2016, 5) => push_scalar_and_lanes!(result, crate::year2016::day05),
+        (2016, 14) => push_scalar_and_lanes!(result, crate::year2016::day14),
+        (2016, 17) => push_scalar_and_lanes!(result, crate::year2016::day17),
+        (2022, 23) => {
+            use crate::year2022::*;
+            result.push(Implementation {
+                name: "scalar",
+                function: |input| day23::solve_scalar(input).map(|answer| answer.to_string()),
+            });
+            result.push(Implementation {
+                name: "heap",
+                function: |input| day23::solve_heap(input).map(|answer| answer.to_string()),
+            });
+            #[cfg(feature = "simd")]
+            result.push(Implementation {
+                name: "simd",
+                function: |input| day23_simd::solve(input).map(|answer| answer.to_string()),
+            });
+            #[cfg(feature = "webgpu-compute")]
+            result.push(Implementation {
+                name: "webgpu",
+                function: |input| day23_webgpu::solve(input).map(|answer| answer.to_string()),
+            });
+            #[cfg(feature = "visualization")]
+            result.push(Implementation {
+                name: "renderer",
+                function: |input| day23_renderer::solve(input).map(|answer| answer.to_string()),
+            });
+        }
+        (2023, 10) => push_fixed_and_heap!(result, crate::year2023::day10),
+        (2023, 17) => push_fixed_and_heap!(result, crate::year2023::day17),
+        (2023, 20) => push_fixed_and_heap!(result, crate::year2023::day20),
+        (2023, 21) => push_fixed_and_heap!(result, crate::year2023::day21),
+        (2024, 16) => push_fixed_and_heap!(result, crate::year2024::day16),
+        (2024, 20) => push_fixed_and_heap!(result, crate::year2024::day20),
+        (2025, 8) => push_fixed_and_heap!(result, crate::year2025::day08),
+        (2025, 9) => push_fixed_and_heap!(result, crate::year2025::day09),
+        _ => {}
+    }
+    result
+}
+
+/// Solves a puzzle with all its [implementations](fn.implementations.html), returning the
+/// answer they agree on - or `None` if all of them failed - or a description of how they
+/// disagree.
+///
+/// An implementation failing, such as by exceeding a fixed capacity, while another one
+/// answers is a disagreement.
+///
+/// # Example
+/// ```
+/// use advent_of_code::check_implementations;
+/// let input = "....#..\n..###.#\n#...#.#\n.#...##\n#.###..\n##.#.##\n.#..#..";
+/// assert_eq!(check_implementations(2022, 23, 1, input), Ok(Some("110".to_string())));
+/// ```
+pub fn check_implementations(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+) -> Result<Option<String>, String> {
+    let mut answered: Option<(&str, String)> = None;
+    let mut failed: Option<(&str, String)> = None;
+    for implementation in implementations(year, day) {
+        match implementation.solve(part, input) {
+            Ok(answer) => match &answered {
+                Some((name, agreed_answer)) if *agreed_answer != answer => {
+                    return Err(format!(
+                        "{year}/{day} part {part}: {name} answered {agreed_answer}, but {} answered {answer}",
+                        implementation.name
+                    ));
+                }
+                Some(_) => {}
+                None => answered = Some((implementation.name, answer)),
+            },
+            Err(error) => {
+                if failed.is_none() {
+                    failed = Some((implementation.name, error));
+                }
+            }
+        }
+    }
+    match (answered, failed) {
+        (Some((name, answer)), Some((failed_name, error))) => Err(format!(
+            "{year}/{day} part {part}: {name} answered {answer}, but {failed_name} failed: {error}"
+        )),
+        (answered, _) => Ok(answered.map(|(_, answer)| answer)),
+    }
+}
+
+#[test]
+fn random_elf_layouts() {
+    let mut state = 0x2022_0023_u32;
+    let mut next_random = move || {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state
+    };
+
+    for _ in 0..20 {
+        let width = 1 + next_random() % 24;
+        let height = 1 + next_random() % 24;
+        let input = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| if next_random() % 3 == 0 { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        for part in [1, 2] {
+            match check_implementations(2022, 23, part, &input) {
+                Ok(Some(_)) => {}
+                Ok(None) => panic!("No implementation answered part {part}\n{input}"),
+                Err(disagreement) => panic!("{disagreement}\n{input}"),
+            }
+        }
+    }
+}
@@ -1,7 +1,12 @@
 #![allow(clippy::redundant_pub_crate)]
 #![allow(unused)]
 
-use std::cell::RefCell;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::fmt::Display;
+
+use crate::params::Param;
 
 pub type ResultType = String;
 
@@ -14,8 +19,22 @@ pub enum Part {
 pub struct Input<'a> {
     pub part: Part,
     pub text: &'a str,
+    /// Values given for parameters of the puzzle, validated to be within range.
+    pub params: &'a [(&'a str, i64)],
     #[cfg(feature = "visualization")]
     pub visualization: RefCell<String>,
+    /// Intermediate values recorded with [Input::trace].
+    #[cfg(feature = "trace")]
+    pub trace_entries: RefCell<Vec<TraceEntry>>,
+}
+
+/// A named intermediate value recorded by a solver, such as the constants parsed
+/// from the input or the length of a detected cycle.
+#[cfg(feature = "trace")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceEntry {
+    pub name: &'static str,
+    pub value: String,
 }
 
 #[allow(single_use_lifetimes)]
@@ -38,14 +57,39 @@ impl<'a> Input<'a> {
         }
     }
 
+    /// Records a named intermediate value of the solution with the `trace` feature.
+    ///
+    /// The value is only formatted with the feature enabled, so costly formatting
+    /// can be deferred by passing something like [core::fmt::from_fn].
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn trace(&self, name: &'static str, value: impl Display) {
+        #![allow(clippy::missing_const_for_fn)]
+        #[cfg(feature = "trace")]
+        self.trace_entries.borrow_mut().push(TraceEntry {
+            name,
+            value: value.to_string(),
+        });
+    }
+
+    /// Returns the value given for a parameter, or its default.
+    pub fn param(&self, param: &Param) -> i64 {
+        self.params
+            .iter()
+            .find(|(name, _)| *name == param.name)
+            .map_or(param.default, |&(_, value)| value)
+    }
+
     #[cfg(test)]
     #[allow(clippy::missing_const_for_fn)]
     pub fn part_one(text: &'a str) -> Self {
         Self {
             part: Part::One,
             text,
+            params: &[],
             #[cfg(feature = "visualization")]
             visualization: RefCell::new("".to_string()),
+            #[cfg(feature = "trace")]
+            trace_entries: RefCell::new(Vec::new()),
         }
     }
 
@@ -55,10 +99,19 @@ impl<'a> Input<'a> {
         Self {
             part: Part::Two,
             text,
+            params: &[],
             #[cfg(feature = "visualization")]
             visualization: RefCell::new("".to_string()),
+            #[cfg(feature = "trace")]
+            trace_entries: RefCell::new(Vec::new()),
         }
     }
+
+    #[cfg(test)]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn with_params(self, params: &'a [(&'a str, i64)]) -> Self {
+        Self { params, ..self }
+    }
 }
 
 #[cfg(test)]
@@ -66,6 +119,12 @@ macro_rules! test_part_one {
     ($input:tt => $expected:expr) => {
         assert_eq!(solve(&Input::part_one($input)), Ok($expected));
     };
+    ($input:tt, $params:expr => $expected:expr) => {
+        assert_eq!(
+            solve(&Input::part_one($input).with_params(&$params)),
+            Ok($expected)
+        );
+    };
 }
 #[cfg(test)]
 pub(crate) use test_part_one;
@@ -73,14 +132,27 @@ pub(crate) use test_part_one;
 #[cfg(test)]
 macro_rules! test_part_one_no_allocations {
     ($input:tt => $expected:expr) => {
-        #[cfg(feature = "count-allocations")]
+        // Spawning threads and tracing allocates, so allocations are not counted
+        // with the `parallel` or `trace` features:
+        #[cfg(all(
+            feature = "count-allocations",
+            not(any(feature = "parallel", feature = "trace"))
+        ))]
         {
             let info = allocation_counter::measure(|| {
                 assert_eq!(solve(&Input::part_one($input)), Ok($expected));
             });
-            assert_eq!(0, info.count_total);
+            assert_eq!(
+                0, info.count_total,
+                "{} allocations of {} bytes in total, peaking at {} bytes",
+                info.count_total, info.bytes_total, info.bytes_max
+            );
         }
-        #[cfg(not(feature = "count-allocations"))]
+        #[cfg(any(
+            not(feature = "count-allocations"),
+            feature = "parallel",
+            feature = "trace"
+        ))]
         {
             assert_eq!(solve(&Input::part_one($input)), Ok($expected));
         }
@@ -94,6 +166,12 @@ macro_rules! test_part_two {
     ($input:tt => $expected:expr) => {
         assert_eq!(solve(&Input::part_two($input)), Ok($expected));
     };
+    ($input:tt, $params:expr => $expected:expr) => {
+        assert_eq!(
+            solve(&Input::part_two($input).with_params(&$params)),
+            Ok($expected)
+        );
+    };
 }
 #[cfg(test)]
 pub(crate) use test_part_two;
@@ -101,14 +179,27 @@ pub(crate) use test_part_two;
 #[cfg(test)]
 macro_rules! test_part_two_no_allocations {
     ($input:tt => $expected:expr) => {
-        #[cfg(feature = "count-allocations")]
+        // Spawning threads and tracing allocates, so allocations are not counted
+        // with the `parallel` or `trace` features:
+        #[cfg(all(
+            feature = "count-allocations",
+            not(any(feature = "parallel", feature = "trace"))
+        ))]
         {
             let info = allocation_counter::measure(|| {
                 assert_eq!(solve(&Input::part_two($input)), Ok($expected));
             });
-            assert_eq!(0, info.count_total);
+            assert_eq!(
+                0, info.count_total,
+                "{} allocations of {} bytes in total, peaking at {} bytes",
+                info.count_total, info.bytes_total, info.bytes_max
+            );
         }
-        #[cfg(not(feature = "count-allocations"))]
+        #[cfg(any(
+            not(feature = "count-allocations"),
+            feature = "parallel",
+            feature = "trace"
+        ))]
         {
             assert_eq!(solve(&Input::part_two($input)), Ok($expected));
         }
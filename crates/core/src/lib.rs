@@ -1,5 +1,6 @@
 #![cfg_attr(feature = "simd", feature(portable_simd))]
 #![forbid(unsafe_code)]
+#![no_std]
 /*!
 This crate provides solutions for [Advent of Code](https://adventofcode.com/) problems.
 
@@ -22,13 +23,34 @@ use advent_of_code::solve;
 let solution = solve(2019, 1, 1, "14");
 assert_eq!(solution, Ok("2".to_string()));
 ```
+
+# Without the standard library
+The solvers only need the [alloc](https://doc.rust-lang.org/alloc/) crate, so disabling the
+default `std` feature builds the crate for targets without the standard library:
+
+```toml
+[dependencies]
+advent_of_code = { version = "*", default-features = false }
+```
+
+Progress reporting by [solve_with_progress](fn.solve_with_progress.html) and the
+`parallel`, `visualization`, `webgpu-compute` and `debug-output` features require `std`.
 */
 #![crate_name = "advent_of_code"]
 
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
 mod common;
+mod differential;
 #[cfg_attr(test, macro_use)]
 mod input;
-mod mod_exp;
+mod params;
 mod year2015;
 mod year2016;
 mod year2017;
@@ -72,7 +94,145 @@ fn to_stringer_input<T: ToString>(
 /// assert_eq!(solution, Ok("2".to_string()));
 /// ```
 pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<ResultType, String> {
-    #![allow(clippy::let_and_return)]
+    solve_with_params(year, day, part, input, &[])
+}
+
+#[cfg(feature = "trace")]
+pub use input::TraceEntry;
+pub use params::{Param, SolveOptions};
+
+/// Returns the solution like [solve](fn.solve.html), with [parameters](struct.Param.html)
+/// of the puzzle given by `options`.
+///
+/// # Example
+/// ```
+/// use advent_of_code::{solve_with_options, SolveOptions};
+/// let input = "...#......\n.......#..\n#.........\n..........\n......#...
+/// .#........\n.........#\n..........\n.......#..\n#...#.....";
+/// let options = SolveOptions::default().param("expansion_factor", 10);
+/// let solution = solve_with_options(2023, 11, 2, input, &options);
+/// assert_eq!(solution, Ok("1030".to_string()));
+/// ```
+pub fn solve_with_options(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    options: &SolveOptions,
+) -> Result<ResultType, String> {
+    let params = validated_params(year, day, options)?;
+    solve_with_params(year, day, part, input, &params)
+}
+
+/// Returns the solution like [solve_with_options](fn.solve_with_options.html), together
+/// with the named intermediate values recorded by the solver with the `trace` feature.
+///
+/// The trace is returned also if solving fails, to help explain why.
+///
+/// # Example
+/// ```
+/// use advent_of_code::{solve_with_trace, SolveOptions};
+/// let input = "1163751742\n1381373672\n2136511328\n3694931569\n7463417111
+/// 1319128137\n1359912421\n3125421639\n1293138521\n2311944581";
+/// let (solution, trace) = solve_with_trace(2021, 15, 1, input, &SolveOptions::default());
+/// assert_eq!(solution, Ok("40".to_string()));
+/// assert!(trace.iter().any(|entry| entry.name == "max frontier size"));
+/// ```
+#[cfg(feature = "trace")]
+pub fn solve_with_trace(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    options: &SolveOptions,
+) -> (Result<ResultType, String>, Vec<TraceEntry>) {
+    let params = match validated_params(year, day, options) {
+        Ok(params) => params,
+        Err(error) => return (Err(error), Vec::new()),
+    };
+    match new_input(day, part, input, &params) {
+        Ok(input) => {
+            let result = solve_input(year, day, part, &input);
+            (result, input.trace_entries.take())
+        }
+        Err(error) => (Err(error), Vec::new()),
+    }
+}
+
+/// Statistics about the heap allocations made while solving a problem, as returned by
+/// [solve_with_allocation_stats](fn.solve_with_allocation_stats.html).
+#[cfg(feature = "count-allocations")]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    /// The number of heap allocations.
+    pub allocations: u64,
+    /// The total number of bytes allocated.
+    pub allocated_bytes: u64,
+    /// The highest number of bytes allocated at the same time.
+    pub peak_heap_bytes: u64,
+}
+
+/// Returns the solution like [solve](fn.solve.html), together with statistics about the
+/// heap allocations made while solving, with the `count-allocations` feature.
+///
+/// Allocations made by other threads, as with the `parallel` feature, are not counted.
+///
+/// # Example
+/// ```
+/// use advent_of_code::solve_with_allocation_stats;
+/// let (solution, stats) = solve_with_allocation_stats(2019, 1, 1, "14");
+/// assert_eq!(solution, Ok("2".to_string()));
+/// assert_eq!(stats.allocations, 1);
+/// ```
+#[cfg(feature = "count-allocations")]
+pub fn solve_with_allocation_stats(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+) -> (Result<ResultType, String>, AllocationStats) {
+    let mut result = Err(String::new());
+    let info = allocation_counter::measure(|| {
+        result = solve(year, day, part, input);
+    });
+    let stats = AllocationStats {
+        allocations: info.count_total,
+        allocated_bytes: info.bytes_total,
+        peak_heap_bytes: info.bytes_max,
+    };
+    (result, stats)
+}
+
+fn validated_params(
+    year: u16,
+    day: u8,
+    options: &SolveOptions,
+) -> Result<Vec<(&str, i64)>, String> {
+    options.validate(puzzle_params(year, day))?;
+    Ok(options
+        .params
+        .iter()
+        .map(|(name, value)| (name.as_str(), *value))
+        .collect())
+}
+
+fn solve_with_params(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    params: &[(&str, i64)],
+) -> Result<ResultType, String> {
+    let input = new_input(day, part, input, params)?;
+    solve_input(year, day, part, &input)
+}
+
+fn new_input<'a>(
+    day: u8,
+    part: u8,
+    input: &'a str,
+    params: &'a [(&'a str, i64)],
+) -> Result<input::Input<'a>, String> {
     use crate::input::{Input, Part};
     let input = input.trim_end();
 
@@ -88,279 +248,282 @@ pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<ResultType, St
         return Err(format!("Invalid part {part} - must be 1-2"));
     }
 
-    #[cfg(feature = "visualization")]
-    let visualization = std::cell::RefCell::new("".to_string());
-
-    let input = Input {
+    Ok(Input {
         part: if part == 1 { Part::One } else { Part::Two },
         text: input,
+        params,
         #[cfg(feature = "visualization")]
-        visualization,
-    };
+        visualization: core::cell::RefCell::new("".to_string()),
+        #[cfg(feature = "trace")]
+        trace_entries: core::cell::RefCell::new(Vec::new()),
+    })
+}
 
+fn solve_input(year: u16, day: u8, part: u8, input: &input::Input) -> Result<ResultType, String> {
+    #![allow(clippy::let_and_return)]
     let result = match (year, day) {
-        (2015, 1) => to_stringer_input(year2015::day01::solve, &input),
-        (2015, 2) => to_stringer_input(year2015::day02::solve, &input),
-        (2015, 3) => to_stringer_input(year2015::day03::solve, &input),
-        (2015, 4) => to_stringer_input(year2015::day04::solve, &input),
-        (2015, 5) => to_stringer_input(year2015::day05::solve, &input),
-        (2015, 6) => to_stringer_input(year2015::day06::solve, &input),
-        (2015, 7) => to_stringer_input(year2015::day07::solve, &input),
-        (2015, 8) => to_stringer_input(year2015::day08::solve, &input),
-        (2015, 9) => to_stringer_input(year2015::day09::solve, &input),
-        (2015, 10) => to_stringer_input(year2015::day10::solve, &input),
-        (2015, 11) => to_stringer_input(year2015::day11::solve, &input),
-        (2015, 12) => to_stringer_input(year2015::day12::solve, &input),
-        (2015, 13) => to_stringer_input(year2015::day13::solve, &input),
-        (2015, 14) => to_stringer_input(year2015::day14::solve, &input),
-        (2015, 15) => to_stringer_input(year2015::day15::solve, &input),
-        (2015, 16) => to_stringer_input(year2015::day16::solve, &input),
-        (2015, 17) => to_stringer_input(year2015::day17::solve, &input),
-        (2015, 18) => to_stringer_input(year2015::day18::solve, &input),
-        (2015, 19) => to_stringer_input(year2015::day19::solve, &input),
-        (2015, 20) => to_stringer_input(year2015::day20::solve, &input),
-        (2015, 21) => to_stringer_input(year2015::day21::solve, &input),
-        (2015, 22) => to_stringer_input(year2015::day22::solve, &input),
-        (2015, 23) => to_stringer_input(year2015::day23::solve, &input),
-        (2015, 24) => to_stringer_input(year2015::day24::solve, &input),
-        (2015, 25) => to_stringer_input(year2015::day25::solve, &input),
-        (2016, 1) => to_stringer_input(year2016::day01::solve, &input),
-        (2016, 2) => to_stringer_input(year2016::day02::solve, &input),
-        (2016, 3) => to_stringer_input(year2016::day03::solve, &input),
-        (2016, 4) => to_stringer_input(year2016::day04::solve, &input),
-        (2016, 5) => to_stringer_input(year2016::day05::solve, &input),
-        (2016, 6) => to_stringer_input(year2016::day06::solve, &input),
-        (2016, 7) => to_stringer_input(year2016::day07::solve, &input),
-        (2016, 8) => to_stringer_input(year2016::day08::solve, &input),
-        (2016, 9) => to_stringer_input(year2016::day09::solve, &input),
-        (2016, 10) => to_stringer_input(year2016::day10::solve, &input),
-        (2016, 11) => to_stringer_input(year2016::day11::solve, &input),
-        (2016, 12) => to_stringer_input(year2016::day12::solve, &input),
-        (2016, 13) => to_stringer_input(year2016::day13::solve, &input),
-        (2016, 14) => to_stringer_input(year2016::day14::solve, &input),
-        (2016, 15) => to_stringer_input(year2016::day15::solve, &input),
-        (2016, 16) => to_stringer_input(year2016::day16::solve, &input),
-        (2016, 17) => to_stringer_input(year2016::day17::solve, &input),
-        (2016, 18) => to_stringer_input(year2016::day18::solve, &input),
-        (2016, 19) => to_stringer_input(year2016::day19::solve, &input),
-        (2016, 20) => to_stringer_input(year2016::day20::solve, &input),
-        (2016, 21) => to_stringer_input(year2016::day21::solve, &input),
-        (2016, 22) => to_stringer_input(year2016::day22::solve, &input),
-        (2016, 23) => to_stringer_input(year2016::day23::solve, &input),
-        (2016, 24) => to_stringer_input(year2016::day24::solve, &input),
-        (2016, 25) => to_stringer_input(year2016::day25::solve, &input),
-        (2017, 1) => to_stringer_input(year2017::day01::solve, &input),
-        (2017, 2) => to_stringer_input(year2017::day02::solve, &input),
-        (2017, 3) => to_stringer_input(year2017::day03::solve, &input),
-        (2017, 4) => to_stringer_input(year2017::day04::solve, &input),
-        (2017, 5) => to_stringer_input(year2017::day05::solve, &input),
-        (2017, 6) => to_stringer_input(year2017::day06::solve, &input),
-        (2017, 7) => to_stringer_input(year2017::day07::solve, &input),
-        (2017, 8) => to_stringer_input(year2017::day08::solve, &input),
-        (2017, 9) => to_stringer_input(year2017::day09::solve, &input),
-        (2017, 10) => to_stringer_input(year2017::day10::solve, &input),
-        (2017, 11) => to_stringer_input(year2017::day11::solve, &input),
-        (2017, 12) => to_stringer_input(year2017::day12::solve, &input),
-        (2017, 13) => to_stringer_input(year2017::day13::solve, &input),
-        (2017, 14) => to_stringer_input(year2017::day14::solve, &input),
-        (2017, 15) => to_stringer_input(year2017::day15::solve, &input),
-        (2017, 16) => to_stringer_input(year2017::day16::solve, &input),
-        (2017, 17) => to_stringer_input(year2017::day17::solve, &input),
-        (2017, 18) => to_stringer_input(year2017::day18::solve, &input),
-        (2017, 19) => to_stringer_input(year2017::day19::solve, &input),
-        (2017, 20) => to_stringer_input(year2017::day20::solve, &input),
-        (2017, 21) => to_stringer_input(year2017::day21::solve, &input),
-        (2017, 22) => to_stringer_input(year2017::day22::solve, &input),
-        (2017, 23) => to_stringer_input(year2017::day23::solve, &input),
-        (2017, 24) => to_stringer_input(year2017::day24::solve, &input),
-        (2017, 25) => to_stringer_input(year2017::day25::solve, &input),
-        (2018, 1) => to_stringer_input(year2018::day01::solve, &input),
-        (2018, 2) => to_stringer_input(year2018::day02::solve, &input),
-        (2018, 3) => to_stringer_input(year2018::day03::solve, &input),
-        (2018, 4) => to_stringer_input(year2018::day04::solve, &input),
-        (2018, 5) => to_stringer_input(year2018::day05::solve, &input),
-        (2018, 6) => to_stringer_input(year2018::day06::solve, &input),
-        (2018, 7) => to_stringer_input(year2018::day07::solve, &input),
-        (2018, 8) => to_stringer_input(year2018::day08::solve, &input),
-        (2018, 9) => to_stringer_input(year2018::day09::solve, &input),
-        (2018, 10) => to_stringer_input(year2018::day10::solve, &input),
-        (2018, 11) => to_stringer_input(year2018::day11::solve, &input),
-        (2018, 12) => to_stringer_input(year2018::day12::solve, &input),
-        (2018, 13) => to_stringer_input(year2018::day13::solve, &input),
-        (2018, 14) => to_stringer_input(year2018::day14::solve, &input),
-        (2018, 15) => to_stringer_input(year2018::day15::solve, &input),
-        (2018, 16) => to_stringer_input(year2018::day16::solve, &input),
-        (2018, 17) => to_stringer_input(year2018::day17::solve, &input),
-        (2018, 18) => to_stringer_input(year2018::day18::solve, &input),
-        (2018, 19) => to_stringer_input(year2018::day19::solve, &input),
-        (2018, 20) => to_stringer_input(year2018::day20::solve, &input),
-        (2018, 21) => to_stringer_input(year2018::day21::solve, &input),
-        (2018, 22) => to_stringer_input(year2018::day22::solve, &input),
-        (2018, 23) => to_stringer_input(year2018::day23::solve, &input),
-        (2018, 24) => to_stringer_input(year2018::day24::solve, &input),
-        (2018, 25) => to_stringer_input(year2018::day25::solve, &input),
-        (2019, 1) => to_stringer_input(year2019::day01::solve, &input),
-        (2019, 2) => to_stringer_input(year2019::day02::solve, &input),
-        (2019, 3) => to_stringer_input(year2019::day03::solve, &input),
-        (2019, 4) => to_stringer_input(year2019::day04::solve, &input),
-        (2019, 5) => to_stringer_input(year2019::day05::solve, &input),
-        (2019, 6) => to_stringer_input(year2019::day06::solve, &input),
-        (2019, 7) => to_stringer_input(year2019::day07::solve, &input),
-        (2019, 8) => to_stringer_input(year2019::day08::solve, &input),
-        (2019, 9) => to_stringer_input(year2019::day09::solve, &input),
-        (2019, 10) => to_stringer_input(year2019::day10::solve, &input),
-        (2019, 11) => to_stringer_input(year2019::day11::solve, &input),
-        (2019, 12) => to_stringer_input(year2019::day12::solve, &input),
-        (2019, 13) => to_stringer_input(year2019::day13::solve, &input),
-        (2019, 14) => to_stringer_input(year2019::day14::solve, &input),
-        (2019, 15) => to_stringer_input(year2019::day15::solve, &input),
-        (2019, 16) => to_stringer_input(year2019::day16::solve, &input),
-        (2019, 17) => to_stringer_input(year2019::day17::solve, &input),
-        (2019, 18) => to_stringer_input(year2019::day18::solve, &input),
-        (2019, 19) => to_stringer_input(year2019::day19::solve, &input),
-        (2019, 20) => to_stringer_input(year2019::day20::solve, &input),
-        (2019, 21) => to_stringer_input(year2019::day21::solve, &input),
-        (2019, 22) => to_stringer_input(year2019::day22::solve, &input),
-        (2019, 23) => to_stringer_input(year2019::day23::solve, &input),
-        (2019, 24) => to_stringer_input(year2019::day24::solve, &input),
-        (2019, 25) => to_stringer_input(year2019::day25::solve, &input),
-        (2020, 1) => to_stringer_input(year2020::day01::solve, &input),
-        (2020, 2) => to_stringer_input(year2020::day02::solve, &input),
-        (2020, 3) => to_stringer_input(year2020::day03::solve, &input),
-        (2020, 4) => to_stringer_input(year2020::day04::solve, &input),
-        (2020, 5) => to_stringer_input(year2020::day05::solve, &input),
-        (2020, 6) => to_stringer_input(year2020::day06::solve, &input),
-        (2020, 7) => to_stringer_input(year2020::day07::solve, &input),
-        (2020, 8) => to_stringer_input(year2020::day08::solve, &input),
-        (2020, 9) => to_stringer_input(year2020::day09::solve, &input),
-        (2020, 10) => to_stringer_input(year2020::day10::solve, &input),
-        (2020, 11) => to_stringer_input(year2020::day11::solve, &input),
-        (2020, 12) => to_stringer_input(year2020::day12::solve, &input),
-        (2020, 13) => to_stringer_input(year2020::day13::solve, &input),
-        (2020, 14) => to_stringer_input(year2020::day14::solve, &input),
-        (2020, 15) => to_stringer_input(year2020::day15::solve, &input),
-        (2020, 16) => to_stringer_input(year2020::day16::solve, &input),
-        (2020, 17) => to_stringer_input(year2020::day17::solve, &input),
-        (2020, 18) => to_stringer_input(year2020::day18::solve, &input),
-        (2020, 19) => to_stringer_input(year2020::day19::solve, &input),
-        (2020, 20) => to_stringer_input(year2020::day20::solve, &input),
-        (2020, 21) => to_stringer_input(year2020::day21::solve, &input),
-        (2020, 22) => to_stringer_input(year2020::day22::solve, &input),
-        (2020, 23) => to_stringer_input(year2020::day23::solve, &input),
-        (2020, 24) => to_stringer_input(year2020::day24::solve, &input),
-        (2020, 25) => to_stringer_input(year2020::day25::solve, &input),
-        (2021, 1) => to_stringer_input(year2021::day01::solve, &input),
-        (2021, 2) => to_stringer_input(year2021::day02::solve, &input),
-        (2021, 3) => to_stringer_input(year2021::day03::solve, &input),
-        (2021, 4) => to_stringer_input(year2021::day04::solve, &input),
-        (2021, 5) => to_stringer_input(year2021::day05::solve, &input),
-        (2021, 6) => to_stringer_input(year2021::day06::solve, &input),
-        (2021, 7) => to_stringer_input(year2021::day07::solve, &input),
-        (2021, 8) => to_stringer_input(year2021::day08::solve, &input),
-        (2021, 9) => to_stringer_input(year2021::day09::solve, &input),
-        (2021, 10) => to_stringer_input(year2021::day10::solve, &input),
-        (2021, 11) => to_stringer_input(year2021::day11::solve, &input),
-        (2021, 12) => to_stringer_input(year2021::day12::solve, &input),
-        (2021, 13) => to_stringer_input(year2021::day13::solve, &input),
-        (2021, 14) => to_stringer_input(year2021::day14::solve, &input),
-        (2021, 15) => to_stringer_input(year2021::day15::solve, &input),
-        (2021, 16) => to_stringer_input(year2021::day16::solve, &input),
-        (2021, 17) => to_stringer_input(year2021::day17::solve, &input),
-        (2021, 18) => to_stringer_input(year2021::day18::solve, &input),
-        (2021, 19) => to_stringer_input(year2021::day19::solve, &input),
-        (2021, 20) => to_stringer_input(year2021::day20::solve, &input),
-        (2021, 21) => to_stringer_input(year2021::day21::solve, &input),
-        (2021, 22) => to_stringer_input(year2021::day22::solve, &input),
-        (2021, 23) => to_stringer_input(year2021::day23::solve, &input),
-        (2021, 24) => to_stringer_input(year2021::day24::solve, &input),
-        (2021, 25) => to_stringer_input(year2021::day25::solve, &input),
-        (2022, 1) => to_stringer_input(year2022::day01::solve, &input),
-        (2022, 2) => to_stringer_input(year2022::day02::solve, &input),
-        (2022, 3) => to_stringer_input(year2022::day03::solve, &input),
-        (2022, 4) => to_stringer_input(year2022::day04::solve, &input),
-        (2022, 5) => to_stringer_input(year2022::day05::solve, &input),
-        (2022, 6) => to_stringer_input(year2022::day06::solve, &input),
-        (2022, 7) => to_stringer_input(year2022::day07::solve, &input),
-        (2022, 8) => to_stringer_input(year2022::day08::solve, &input),
-        (2022, 9) => to_stringer_input(year2022::day09::solve, &input),
-        (2022, 10) => to_stringer_input(year2022::day10::solve, &input),
-        (2022, 11) => to_stringer_input(year2022::day11::solve, &input),
-        (2022, 12) => to_stringer_input(year2022::day12::solve, &input),
-        (2022, 13) => to_stringer_input(year2022::day13::solve, &input),
-        (2022, 14) => to_stringer_input(year2022::day14::solve, &input),
-        (2022, 15) => to_stringer_input(year2022::day15::solve, &input),
-        (2022, 16) => to_stringer_input(year2022::day16::solve, &input),
-        (2022, 17) => to_stringer_input(year2022::day17::solve, &input),
-        (2022, 18) => to_stringer_input(year2022::day18::solve, &input),
-        (2022, 19) => to_stringer_input(year2022::day19::solve, &input),
-        (2022, 20) => to_stringer_input(year2022::day20::solve, &input),
-        (2022, 21) => to_stringer_input(year2022::day21::solve, &input),
-        (2022, 22) => to_stringer_input(year2022::day22::solve, &input),
-        (2022, 23) => to_stringer_input(year2022::day23::solve, &input),
-        (2022, 24) => to_stringer_input(year2022::day24::solve, &input),
-        (2022, 25) => to_stringer_input(year2022::day25::solve, &input),
-        (2023, 1) => to_stringer_input(year2023::day01::solve, &input),
-        (2023, 2) => to_stringer_input(year2023::day02::solve, &input),
-        (2023, 3) => to_stringer_input(year2023::day03::solve, &input),
-        (2023, 4) => to_stringer_input(year2023::day04::solve, &input),
-        (2023, 5) => to_stringer_input(year2023::day05::solve, &input),
-        (2023, 6) => to_stringer_input(year2023::day06::solve, &input),
-        (2023, 7) => to_stringer_input(year2023::day07::solve, &input),
-        (2023, 8) => to_stringer_input(year2023::day08::solve, &input),
-        (2023, 9) => to_stringer_input(year2023::day09::solve, &input),
-        (2023, 10) => to_stringer_input(year2023::day10::solve, &input),
-        (2023, 11) => to_stringer_input(year2023::day11::solve, &input),
-        (2023, 12) => to_stringer_input(year2023::day12::solve, &input),
-        (2023, 13) => to_stringer_input(year2023::day13::solve, &input),
-        (2023, 14) => to_stringer_input(year2023::day14::solve, &input),
-        (2023, 15) => to_stringer_input(year2023::day15::solve, &input),
-        (2023, 16) => to_stringer_input(year2023::day16::solve, &input),
-        (2023, 17) => to_stringer_input(year2023::day17::solve, &input),
-        (2023, 18) => to_stringer_input(year2023::day18::solve, &input),
-        (2023, 19) => to_stringer_input(year2023::day19::solve, &input),
-        (2023, 20) => to_stringer_input(year2023::day20::solve, &input),
-        (2023, 21) => to_stringer_input(year2023::day21::solve, &input),
-        (2023, 22) => to_stringer_input(year2023::day22::solve, &input),
-        (2023, 23) => to_stringer_input(year2023::day23::solve, &input),
-        (2023, 24) => to_stringer_input(year2023::day24::solve, &input),
-        (2023, 25) => to_stringer_input(year2023::day25::solve, &input),
-        (2024, 1) => to_stringer_input(year2024::day01::solve, &input),
-        (2024, 2) => to_stringer_input(year2024::day02::solve, &input),
-        (2024, 3) => to_stringer_input(year2024::day03::solve, &input),
-        (2024, 4) => to_stringer_input(year2024::day04::solve, &input),
-        (2024, 5) => to_stringer_input(year2024::day05::solve, &input),
-        (2024, 6) => to_stringer_input(year2024::day06::solve, &input),
-        (2024, 7) => to_stringer_input(year2024::day07::solve, &input),
-        (2024, 8) => to_stringer_input(year2024::day08::solve, &input),
-        (2024, 9) => to_stringer_input(year2024::day09::solve, &input),
-        (2024, 10) => to_stringer_input(year2024::day10::solve, &input),
-        (2024, 11) => to_stringer_input(year2024::day11::solve, &input),
-        (2024, 12) => to_stringer_input(year2024::day12::solve, &input),
-        (2024, 13) => to_stringer_input(year2024::day13::solve, &input),
-        (2024, 14) => to_stringer_input(year2024::day14::solve, &input),
-        (2024, 15) => to_stringer_input(year2024::day15::solve, &input),
-        (2024, 16) => to_stringer_input(year2024::day16::solve, &input),
-        (2024, 17) => to_stringer_input(year2024::day17::solve, &input),
-        (2024, 18) => to_stringer_input(year2024::day18::solve, &input),
-        (2024, 19) => to_stringer_input(year2024::day19::solve, &input),
-        (2024, 20) => to_stringer_input(year2024::day20::solve, &input),
-        (2024, 21) => to_stringer_input(year2024::day21::solve, &input),
-        (2024, 22) => to_stringer_input(year2024::day22::solve, &input),
-        (2024, 23) => to_stringer_input(year2024::day23::solve, &input),
-        (2024, 24) => to_stringer_input(year2024::day24::solve, &input),
-        (2024, 25) => to_stringer_input(year2024::day25::solve, &input),
-        (2025, 1) => to_stringer_input(year2025::day01::solve, &input),
-        (2025, 2) => to_stringer_input(year2025::day02::solve, &input),
-        (2025, 3) => to_stringer_input(year2025::day03::solve, &input),
-        (2025, 4) => to_stringer_input(year2025::day04::solve, &input),
-        (2025, 5) => to_stringer_input(year2025::day05::solve, &input),
-        (2025, 6) => to_stringer_input(year2025::day06::solve, &input),
-        (2025, 7) => to_stringer_input(year2025::day07::solve, &input),
-        (2025, 8) => to_stringer_input(year2025::day08::solve, &input),
-        (2025, 9) => to_stringer_input(year2025::day09::solve, &input),
-        (2025, 10) => to_stringer_input(year2025::day10::solve, &input),
-        (2025, 11) => to_stringer_input(year2025::day11::solve, &input),
-        (2025, 12) => to_stringer_input(year2025::day12::solve, &input),
+        (2015, 1) => to_stringer_input(year2015::day01::solve, input),
+        (2015, 2) => to_stringer_input(year2015::day02::solve, input),
+        (2015, 3) => to_stringer_input(year2015::day03::solve, input),
+        (2015, 4) => to_stringer_input(year2015::day04::solve, input),
+        (2015, 5) => to_stringer_input(year2015::day05::solve, input),
+        (2015, 6) => to_stringer_input(year2015::day06::solve, input),
+        (2015, 7) => to_stringer_input(year2015::day07::solve, input),
+        (2015, 8) => to_stringer_input(year2015::day08::solve, input),
+        (2015, 9) => to_stringer_input(year2015::day09::solve, input),
+        (2015, 10) => to_stringer_input(year2015::day10::solve, input),
+        (2015, 11) => to_stringer_input(year2015::day11::solve, input),
+        (2015, 12) => to_stringer_input(year2015::day12::solve, input),
+        (2015, 13) => to_stringer_input(year2015::day13::solve, input),
+        (2015, 14) => to_stringer_input(year2015::day14::solve, input),
+        (2015, 15) => to_stringer_input(year2015::day15::solve, input),
+        (2015, 16) => to_stringer_input(year2015::day16::solve, input),
+        (2015, 17) => to_stringer_input(year2015::day17::solve, input),
+        (2015, 18) => to_stringer_input(year2015::day18::solve, input),
+        (2015, 19) => to_stringer_input(year2015::day19::solve, input),
+        (2015, 20) => to_stringer_input(year2015::day20::solve, input),
+        (2015, 21) => to_stringer_input(year2015::day21::solve, input),
+        (2015, 22) => to_stringer_input(year2015::day22::solve, input),
+        (2015, 23) => to_stringer_input(year2015::day23::solve, input),
+        (2015, 24) => to_stringer_input(year2015::day24::solve, input),
+        (2015, 25) => to_stringer_input(year2015::day25::solve, input),
+        (2016, 1) => to_stringer_input(year2016::day01::solve, input),
+        (2016, 2) => to_stringer_input(year2016::day02::solve, input),
+        (2016, 3) => to_stringer_input(year2016::day03::solve, input),
+        (2016, 4) => to_stringer_input(year2016::day04::solve, input),
+        (2016, 5) => to_stringer_input(year2016::day05::solve, input),
+        (2016, 6) => to_stringer_input(year2016::day06::solve, input),
+        (2016, 7) => to_stringer_input(year2016::day07::solve, input),
+        (2016, 8) => to_stringer_input(year2016::day08::solve, input),
+        (2016, 9) => to_stringer_input(year2016::day09::solve, input),
+        (2016, 10) => to_stringer_input(year2016::day10::solve, input),
+        (2016, 11) => to_stringer_input(year2016::day11::solve, input),
+        (2016, 12) => to_stringer_input(year2016::day12::solve, input),
+        (2016, 13) => to_stringer_input(year2016::day13::solve, input),
+        (2016, 14) => to_stringer_input(year2016::day14::solve, input),
+        (2016, 15) => to_stringer_input(year2016::day15::solve, input),
+        (2016, 16) => to_stringer_input(year2016::day16::solve, input),
+        (2016, 17) => to_stringer_input(year2016::day17::solve, input),
+        (2016, 18) => to_stringer_input(year2016::day18::solve, input),
+        (2016, 19) => to_stringer_input(year2016::day19::solve, input),
+        (2016, 20) => to_stringer_input(year2016::day20::solve, input),
+        (2016, 21) => to_stringer_input(year2016::day21::solve, input),
+        (2016, 22) => to_stringer_input(year2016::day22::solve, input),
+        (2016, 23) => to_stringer_input(year2016::day23::solve, input),
+        (2016, 24) => to_stringer_input(year2016::day24::solve, input),
+        (2016, 25) => to_stringer_input(year2016::day25::solve, input),
+        (2017, 1) => to_stringer_input(year2017::day01::solve, input),
+        (2017, 2) => to_stringer_input(year2017::day02::solve, input),
+        (2017, 3) => to_stringer_input(year2017::day03::solve, input),
+        (2017, 4) => to_stringer_input(year2017::day04::solve, input),
+        (2017, 5) => to_stringer_input(year2017::day05::solve, input),
+        (2017, 6) => to_stringer_input(year2017::day06::solve, input),
+        (2017, 7) => to_stringer_input(year2017::day07::solve, input),
+        (2017, 8) => to_stringer_input(year2017::day08::solve, input),
+        (2017, 9) => to_stringer_input(year2017::day09::solve, input),
+        (2017, 10) => to_stringer_input(year2017::day10::solve, input),
+        (2017, 11) => to_stringer_input(year2017::day11::solve, input),
+        (2017, 12) => to_stringer_input(year2017::day12::solve, input),
+        (2017, 13) => to_stringer_input(year2017::day13::solve, input),
+        (2017, 14) => to_stringer_input(year2017::day14::solve, input),
+        (2017, 15) => to_stringer_input(year2017::day15::solve, input),
+        (2017, 16) => to_stringer_input(year2017::day16::solve, input),
+        (2017, 17) => to_stringer_input(year2017::day17::solve, input),
+        (2017, 18) => to_stringer_input(year2017::day18::solve, input),
+        (2017, 19) => to_stringer_input(year2017::day19::solve, input),
+        (2017, 20) => to_stringer_input(year2017::day20::solve, input),
+        (2017, 21) => to_stringer_input(year2017::day21::solve, input),
+        (2017, 22) => to_stringer_input(year2017::day22::solve, input),
+        (2017, 23) => to_stringer_input(year2017::day23::solve, input),
+        (2017, 24) => to_stringer_input(year2017::day24::solve, input),
+        (2017, 25) => to_stringer_input(year2017::day25::solve, input),
+        (2018, 1) => to_stringer_input(year2018::day01::solve, input),
+        (2018, 2) => to_stringer_input(year2018::day02::solve, input),
+        (2018, 3) => to_stringer_input(year2018::day03::solve, input),
+        (2018, 4) => to_stringer_input(year2018::day04::solve, input),
+        (2018, 5) => to_stringer_input(year2018::day05::solve, input),
+        (2018, 6) => to_stringer_input(year2018::day06::solve, input),
+        (2018, 7) => to_stringer_input(year2018::day07::solve, input),
+        (2018, 8) => to_stringer_input(year2018::day08::solve, input),
+        (2018, 9) => to_stringer_input(year2018::day09::solve, input),
+        (2018, 10) => to_stringer_input(year2018::day10::solve, input),
+        (2018, 11) => to_stringer_input(year2018::day11::solve, input),
+        (2018, 12) => to_stringer_input(year2018::day12::solve, input),
+        (2018, 13) => to_stringer_input(year2018::day13::solve, input),
+        (2018, 14) => to_stringer_input(year2018::day14::solve, input),
+        (2018, 15) => to_stringer_input(year2018::day15::solve, input),
+        (2018, 16) => to_stringer_input(year2018::day16::solve, input),
+        (2018, 17) => to_stringer_input(year2018::day17::solve, input),
+        (2018, 18) => to_stringer_input(year2018::day18::solve, input),
+        (2018, 19) => to_stringer_input(year2018::day19::solve, input),
+        (2018, 20) => to_stringer_input(year2018::day20::solve, input),
+        (2018, 21) => to_stringer_input(year2018::day21::solve, input),
+        (2018, 22) => to_stringer_input(year2018::day22::solve, input),
+        (2018, 23) => to_stringer_input(year2018::day23::solve, input),
+        (2018, 24) => to_stringer_input(year2018::day24::solve, input),
+        (2018, 25) => to_stringer_input(year2018::day25::solve, input),
+        (2019, 1) => to_stringer_input(year2019::day01::solve, input),
+        (2019, 2) => to_stringer_input(year2019::day02::solve, input),
+        (2019, 3) => to_stringer_input(year2019::day03::solve, input),
+        (2019, 4) => to_stringer_input(year2019::day04::solve, input),
+        (2019, 5) => to_stringer_input(year2019::day05::solve, input),
+        (2019, 6) => to_stringer_input(year2019::day06::solve, input),
+        (2019, 7) => to_stringer_input(year2019::day07::solve, input),
+        (2019, 8) => to_stringer_input(year2019::day08::solve, input),
+        (2019, 9) => to_stringer_input(year2019::day09::solve, input),
+        (2019, 10) => to_stringer_input(year2019::day10::solve, input),
+        (2019, 11) => to_stringer_input(year2019::day11::solve, input),
+        (2019, 12) => to_stringer_input(year2019::day12::solve, input),
+        (2019, 13) => to_stringer_input(year2019::day13::solve, input),
+        (2019, 14) => to_stringer_input(year2019::day14::solve, input),
+        (2019, 15) => to_stringer_input(year2019::day15::solve, input),
+        (2019, 16) => to_stringer_input(year2019::day16::solve, input),
+        (2019, 17) => to_stringer_input(year2019::day17::solve, input),
+        (2019, 18) => to_stringer_input(year2019::day18::solve, input),
+        (2019, 19) => to_stringer_input(year2019::day19::solve, input),
+        (2019, 20) => to_stringer_input(year2019::day20::solve, input),
+        (2019, 21) => to_stringer_input(year2019::day21::solve, input),
+        (2019, 22) => to_stringer_input(year2019::day22::solve, input),
+        (2019, 23) => to_stringer_input(year2019::day23::solve, input),
+        (2019, 24) => to_stringer_input(year2019::day24::solve, input),
+        (2019, 25) => to_stringer_input(year2019::day25::solve, input),
+        (2020, 1) => to_stringer_input(year2020::day01::solve, input),
+        (2020, 2) => to_stringer_input(year2020::day02::solve, input),
+        (2020, 3) => to_stringer_input(year2020::day03::solve, input),
+        (2020, 4) => to_stringer_input(year2020::day04::solve, input),
+        (2020, 5) => to_stringer_input(year2020::day05::solve, input),
+        (2020, 6) => to_stringer_input(year2020::day06::solve, input),
+        (2020, 7) => to_stringer_input(year2020::day07::solve, input),
+        (2020, 8) => to_stringer_input(year2020::day08::solve, input),
+        (2020, 9) => to_stringer_input(year2020::day09::solve, input),
+        (2020, 10) => to_stringer_input(year2020::day10::solve, input),
+        (2020, 11) => to_stringer_input(year2020::day11::solve, input),
+        (2020, 12) => to_stringer_input(year2020::day12::solve, input),
+        (2020, 13) => to_stringer_input(year2020::day13::solve, input),
+        (2020, 14) => to_stringer_input(year2020::day14::solve, input),
+        (2020, 15) => to_stringer_input(year2020::day15::solve, input),
+        (2020, 16) => to_stringer_input(year2020::day16::solve, input),
+        (2020, 17) => to_stringer_input(year2020::day17::solve, input),
+        (2020, 18) => to_stringer_input(year2020::day18::solve, input),
+        (2020, 19) => to_stringer_input(year2020::day19::solve, input),
+        (2020, 20) => to_stringer_input(year2020::day20::solve, input),
+        (2020, 21) => to_stringer_input(year2020::day21::solve, input),
+        (2020, 22) => to_stringer_input(year2020::day22::solve, input),
+        (2020, 23) => to_stringer_input(year2020::day23::solve, input),
+        (2020, 24) => to_stringer_input(year2020::day24::solve, input),
+        (2020, 25) => to_stringer_input(year2020::day25::solve, input),
+        (2021, 1) => to_stringer_input(year2021::day01::solve, input),
+        (2021, 2) => to_stringer_input(year2021::day02::solve, input),
+        (2021, 3) => to_stringer_input(year2021::day03::solve, input),
+        (2021, 4) => to_stringer_input(year2021::day04::solve, input),
+        (2021, 5) => to_stringer_input(year2021::day05::solve, input),
+        (2021, 6) => to_stringer_input(year2021::day06::solve, input),
+        (2021, 7) => to_stringer_input(year2021::day07::solve, input),
+        (2021, 8) => to_stringer_input(year2021::day08::solve, input),
+        (2021, 9) => to_stringer_input(year2021::day09::solve, input),
+        (2021, 10) => to_stringer_input(year2021::day10::solve, input),
+        (2021, 11) => to_stringer_input(year2021::day11::solve, input),
+        (2021, 12) => to_stringer_input(year2021::day12::solve, input),
+        (2021, 13) => to_stringer_input(year2021::day13::solve, input),
+        (2021, 14) => to_stringer_input(year2021::day14::solve, input),
+        (2021, 15) => to_stringer_input(year2021::day15::solve, input),
+        (2021, 16) => to_stringer_input(year2021::day16::solve, input),
+        (2021, 17) => to_stringer_input(year2021::day17::solve, input),
+        (2021, 18) => to_stringer_input(year2021::day18::solve, input),
+        (2021, 19) => to_stringer_input(year2021::day19::solve, input),
+        (2021, 20) => to_stringer_input(year2021::day20::solve, input),
+        (2021, 21) => to_stringer_input(year2021::day21::solve, input),
+        (2021, 22) => to_stringer_input(year2021::day22::solve, input),
+        (2021, 23) => to_stringer_input(year2021::day23::solve, input),
+        (2021, 24) => to_stringer_input(year2021::day24::solve, input),
+        (2021, 25) => to_stringer_input(year2021::day25::solve, input),
+        (2022, 1) => to_stringer_input(year2022::day01::solve, input),
+        (2022, 2) => to_stringer_input(year2022::day02::solve, input),
+        (2022, 3) => to_stringer_input(year2022::day03::solve, input),
+        (2022, 4) => to_stringer_input(year2022::day04::solve, input),
+        (2022, 5) => to_stringer_input(year2022::day05::solve, input),
+        (2022, 6) => to_stringer_input(year2022::day06::solve, input),
+        (2022, 7) => to_stringer_input(year2022::day07::solve, input),
+        (2022, 8) => to_stringer_input(year2022::day08::solve, input),
+        (2022, 9) => to_stringer_input(year2022::day09::solve, input),
+        (2022, 10) => to_stringer_input(year2022::day10::solve, input),
+        (2022, 11) => to_stringer_input(year2022::day11::solve, input),
+        (2022, 12) => to_stringer_input(year2022::day12::solve, input),
+        (2022, 13) => to_stringer_input(year2022::day13::solve, input),
+        (2022, 14) => to_stringer_input(year2022::day14::solve, input),
+        (2022, 15) => to_stringer_input(year2022::day15::solve, input),
+        (2022, 16) => to_stringer_input(year2022::day16::solve, input),
+        (2022, 17) => to_stringer_input(year2022::day17::solve, input),
+        (2022, 18) => to_stringer_input(year2022::day18::solve, input),
+        (2022, 19) => to_stringer_input(year2022::day19::solve, input),
+        (2022, 20) => to_stringer_input(year2022::day20::solve, input),
+        (2022, 21) => to_stringer_input(year2022::day21::solve, input),
+        (2022, 22) => to_stringer_input(year2022::day22::solve, input),
+        (2022, 23) => to_stringer_input(year2022::day23::solve, input),
+        (2022, 24) => to_stringer_input(year2022::day24::solve, input),
+        (2022, 25) => to_stringer_input(year2022::day25::solve, input),
+        (2023, 1) => to_stringer_input(year2023::day01::solve, input),
+        (2023, 2) => to_stringer_input(year2023::day02::solve, input),
+        (2023, 3) => to_stringer_input(year2023::day03::solve, input),
+        (2023, 4) => to_stringer_input(year2023::day04::solve, input),
+        (2023, 5) => to_stringer_input(year2023::day05::solve, input),
+        (2023, 6) => to_stringer_input(year2023::day06::solve, input),
+        (2023, 7) => to_stringer_input(year2023::day07::solve, input),
+        (2023, 8) => to_stringer_input(year2023::day08::solve, input),
+        (2023, 9) => to_stringer_input(year2023::day09::solve, input),
+        (2023, 10) => to_stringer_input(year2023::day10::solve, input),
+        (2023, 11) => to_stringer_input(year2023::day11::solve, input),
+        (2023, 12) => to_stringer_input(year2023::day12::solve, input),
+        (2023, 13) => to_stringer_input(year2023::day13::solve, input),
+        (2023, 14) => to_stringer_input(year2023::day14::solve, input),
+        (2023, 15) => to_stringer_input(year2023::day15::solve, input),
+        (2023, 16) => to_stringer_input(year2023::day16::solve, input),
+        (2023, 17) => to_stringer_input(year2023::day17::solve, input),
+        (2023, 18) => to_stringer_input(year2023::day18::solve, input),
+        (2023, 19) => to_stringer_input(year2023::day19::solve, input),
+        (2023, 20) => to_stringer_input(year2023::day20::solve, input),
+        (2023, 21) => to_stringer_input(year2023::day21::solve, input),
+        (2023, 22) => to_stringer_input(year2023::day22::solve, input),
+        (2023, 23) => to_stringer_input(year2023::day23::solve, input),
+        (2023, 24) => to_stringer_input(year2023::day24::solve, input),
+        (2023, 25) => to_stringer_input(year2023::day25::solve, input),
+        (2024, 1) => to_stringer_input(year2024::day01::solve, input),
+        (2024, 2) => to_stringer_input(year2024::day02::solve, input),
+        (2024, 3) => to_stringer_input(year2024::day03::solve, input),
+        (2024, 4) => to_stringer_input(year2024::day04::solve, input),
+        (2024, 5) => to_stringer_input(year2024::day05::solve, input),
+        (2024, 6) => to_stringer_input(year2024::day06::solve, input),
+        (2024, 7) => to_stringer_input(year2024::day07::solve, input),
+        (2024, 8) => to_stringer_input(year2024::day08::solve, input),
+        (2024, 9) => to_stringer_input(year2024::day09::solve, input),
+        (2024, 10) => to_stringer_input(year2024::day10::solve, input),
+        (2024, 11) => to_stringer_input(year2024::day11::solve, input),
+        (2024, 12) => to_stringer_input(year2024::day12::solve, input),
+        (2024, 13) => to_stringer_input(year2024::day13::solve, input),
+        (2024, 14) => to_stringer_input(year2024::day14::solve, input),
+        (2024, 15) => to_stringer_input(year2024::day15::solve, input),
+        (2024, 16) => to_stringer_input(year2024::day16::solve, input),
+        (2024, 17) => to_stringer_input(year2024::day17::solve, input),
+        (2024, 18) => to_stringer_input(year2024::day18::solve, input),
+        (2024, 19) => to_stringer_input(year2024::day19::solve, input),
+        (2024, 20) => to_stringer_input(year2024::day20::solve, input),
+        (2024, 21) => to_stringer_input(year2024::day21::solve, input),
+        (2024, 22) => to_stringer_input(year2024::day22::solve, input),
+        (2024, 23) => to_stringer_input(year2024::day23::solve, input),
+        (2024, 24) => to_stringer_input(year2024::day24::solve, input),
+        (2024, 25) => to_stringer_input(year2024::day25::solve, input),
+        (2025, 1) => to_stringer_input(year2025::day01::solve, input),
+        (2025, 2) => to_stringer_input(year2025::day02::solve, input),
+        (2025, 3) => to_stringer_input(year2025::day03::solve, input),
+        (2025, 4) => to_stringer_input(year2025::day04::solve, input),
+        (2025, 5) => to_stringer_input(year2025::day05::solve, input),
+        (2025, 6) => to_stringer_input(year2025::day06::solve, input),
+        (2025, 7) => to_stringer_input(year2025::day07::solve, input),
+        (2025, 8) => to_stringer_input(year2025::day08::solve, input),
+        (2025, 9) => to_stringer_input(year2025::day09::solve, input),
+        (2025, 10) => to_stringer_input(year2025::day10::solve, input),
+        (2025, 11) => to_stringer_input(year2025::day11::solve, input),
+        (2025, 12) => to_stringer_input(year2025::day12::solve, input),
         _ => Err(format!("Unsupported year={year}, day={day}, part={part}")),
     };
 
@@ -378,3 +541,160 @@ pub fn solve_raw(year: &str, day: &str, part: &str, input: &str) -> Result<Resul
     let part = part.parse::<u8>().map_err(|_| "Invalid part")?;
     solve(year, day, part, input)
 }
+
+pub use common::progress::Progress;
+pub use differential::{Implementation, check_implementations, implementations};
+
+/// Returns the solution like [solve](fn.solve.html), while notifying `listener` about the
+/// progress of long-running solvers.
+///
+/// Solving is cancelled, returning an error, if `listener` returns false.
+///
+/// # Example
+/// ```
+/// use advent_of_code::solve_with_progress;
+/// let solution = solve_with_progress(2017, 18, 1, "jgz 1 0", |progress| progress.completed < 1000);
+/// assert_eq!(solution, Err("Cancelled".to_string()));
+/// ```
+#[cfg(feature = "std")]
+pub fn solve_with_progress(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &str,
+    listener: impl FnMut(Progress) -> bool + 'static,
+) -> Result<ResultType, String> {
+    let _guard = common::progress::listen(listener);
+    solve(year, day, part, input)
+}
+
+/// A puzzle which [solve](fn.solve.html) has a solution for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Puzzle {
+    /// The year of the puzzle, as in 2018 or 2019.
+    pub year: u16,
+    /// The day of the puzzle, from 1 to 25 (or to 12 from 2025).
+    pub day: u8,
+    /// The number of parts - 1 for the last day of a year, otherwise 2.
+    pub parts: u8,
+    /// Parameters for constants otherwise taken from the puzzle description, which can be
+    /// given to [solve_with_options](fn.solve_with_options.html) to solve examples.
+    pub params: &'static [Param],
+}
+
+/// Returns the puzzles which [solve](fn.solve.html) has solutions for, ordered by year and day.
+///
+/// # Example
+/// ```
+/// use advent_of_code::puzzles;
+/// assert_eq!(puzzles().count(), 10 * 25 + 12);
+/// ```
+pub fn puzzles() -> impl Iterator<Item = Puzzle> {
+    (2015..=2025).flat_map(|year| {
+        let days = if year >= 2025 { 12 } else { 25 };
+        (1..=days).map(move |day| Puzzle {
+            year,
+            day,
+            parts: if day == days { 1 } else { 2 },
+            params: puzzle_params(year, day),
+        })
+    })
+}
+
+const fn puzzle_params(year: u16, day: u8) -> &'static [Param] {
+    match (year, day) {
+        (2022, 15) => &year2022::day15::PARAMS,
+        (2023, 11) => &year2023::day11::PARAMS,
+        (2023, 21) => &year2023::day21::PARAMS,
+        (2024, 14) => &year2024::day14::PARAMS,
+        _ => &[],
+    }
+}
+
+/// Returns whether [solve](fn.solve.html) has a solution for the specified problem.
+///
+/// # Example
+/// ```
+/// use advent_of_code::is_supported;
+/// assert!(is_supported(2019, 1, 2));
+/// assert!(!is_supported(2019, 26, 1));
+/// ```
+pub fn is_supported(year: u16, day: u8, part: u8) -> bool {
+    matches!(part, 1 | 2) && puzzles().any(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+/// The kind of error returned by [solve](fn.solve.html).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// The input could not be parsed or solved.
+    InvalidInput,
+    /// There is no solution for the specified year, day and part.
+    UnsupportedPuzzle,
+    /// The solver gave up after exceeding its computation budget.
+    Timeout,
+    /// Solving was cancelled by a progress listener.
+    Cancelled,
+}
+
+impl ErrorKind {
+    /// Classifies an error returned by [solve](fn.solve.html) for the specified problem.
+    ///
+    /// # Example
+    /// ```
+    /// use advent_of_code::{solve, ErrorKind};
+    /// let error = solve(2017, 18, 1, "jgz 1 0").unwrap_err();
+    /// assert_eq!(ErrorKind::of(2017, 18, 1, &error), ErrorKind::Timeout);
+    ///
+    /// // A path going around in circles:
+    /// let error = solve(2017, 19, 1, " |\n +-+\n | |\n +-+\n").unwrap_err();
+    /// assert_eq!(ErrorKind::of(2017, 19, 1, &error), ErrorKind::Timeout);
+    /// ```
+    pub fn of(year: u16, day: u8, part: u8, message: &str) -> Self {
+        if !is_supported(year, day, part) {
+            Self::UnsupportedPuzzle
+        } else if message.starts_with(common::progress::BUDGET_EXCEEDED_PREFIX) {
+            Self::Timeout
+        } else if message == common::progress::CANCELLED_MESSAGE {
+            Self::Cancelled
+        } else {
+            Self::InvalidInput
+        }
+    }
+
+    /// The name of the kind in camel case, as in `invalidInput`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::InvalidInput => "invalidInput",
+            Self::UnsupportedPuzzle => "unsupportedPuzzle",
+            Self::Timeout => "timeout",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// Returns the line and column of the input, starting from 1, which an error message returned
+/// by [solve](fn.solve.html) points at, from messages starting as in "Line 3: ..." or
+/// "Line 3, column 7: ...".
+///
+/// # Example
+/// ```
+/// use advent_of_code::error_position;
+/// assert_eq!(error_position("Line 2, column 1: Invalid number"), (Some(2), Some(1)));
+/// assert_eq!(error_position("Line 2: Invalid number"), (Some(2), None));
+/// assert_eq!(error_position("Invalid number"), (None, None));
+/// ```
+pub fn error_position(message: &str) -> (Option<u32>, Option<u32>) {
+    let Some((position, _)) = message
+        .strip_prefix("Line ")
+        .and_then(|rest| rest.split_once(':'))
+    else {
+        return (None, None);
+    };
+    let (line, column) = position
+        .split_once(", column ")
+        .map_or((position, None), |(line, column)| (line, Some(column)));
+    (
+        line.parse().ok(),
+        column.and_then(|column| column.parse().ok()),
+    )
+}
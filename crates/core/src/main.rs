@@ -2,18 +2,282 @@
 
 use std::env;
 use std::io::Read;
+#[cfg(feature = "count-allocations")]
+use std::io::Write;
+#[cfg(feature = "count-allocations")]
+use std::process::{Command, Stdio};
 
-use advent_of_code::solve_raw;
+#[cfg(feature = "trace")]
+use advent_of_code::solve_with_trace;
+#[cfg(feature = "count-allocations")]
+use advent_of_code::{AllocationStats, puzzles, solve, solve_with_allocation_stats};
+use advent_of_code::{ErrorKind, SolveOptions, error_position, solve_raw, solve_with_options};
+
+/// The directory with the inputs used by `stats` without a puzzle, as in
+/// `year2022/day17_input.txt`.
+#[cfg(feature = "count-allocations")]
+const INPUT_DIRECTORY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/src");
+
+/// The largest stack tried when measuring the max stack depth of a solution.
+#[cfg(feature = "count-allocations")]
+const MAX_STACK_SIZE: usize = 64 << 20;
+
+/// The max stack depth of a solution is measured with this precision.
+#[cfg(feature = "count-allocations")]
+const STACK_SIZE_PRECISION: usize = 4 << 10;
+
+/// Returns the text quoted and escaped as a JSON string.
+fn json_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len() + 2);
+    result.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Solves a problem on a thread with a stack of `stack_size` bytes, to be run in a child
+/// process by [max_stack_depth], since overflowing the stack aborts the process.
+#[cfg(feature = "count-allocations")]
+fn solve_with_stack_size(stack_size: usize, year: u16, day: u8, part: u8, input: &str) {
+    std::thread::scope(|scope| {
+        let spawned = std::thread::Builder::new()
+            .stack_size(stack_size)
+            .spawn_scoped(scope, || solve(year, day, part, input));
+        if spawned.is_err() {
+            std::process::exit(1);
+        }
+    });
+}
+
+/// Returns the smallest stack, in bytes, on which a problem can be solved, found by
+/// solving it in child processes with stacks of different sizes.
+#[cfg(feature = "count-allocations")]
+fn max_stack_depth(year: u16, day: u8, part: u8, input: &str) -> Result<usize, String> {
+    let executable = env::current_exe()
+        .map_err(|error| format!("Unable to find the advent-of-code executable: {error}"))?;
+    let fits_in = |stack_size: usize| -> Result<bool, String> {
+        let mut child = Command::new(&executable)
+            .args([
+                "--stack-size".to_string(),
+                stack_size.to_string(),
+                year.to_string(),
+                day.to_string(),
+                part.to_string(),
+            ])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|error| format!("Unable to run the advent-of-code executable: {error}"))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin
+                .write_all(input.as_bytes())
+                .map_err(|error| format!("Unable to write input: {error}"))?;
+        }
+        let status = child
+            .wait()
+            .map_err(|error| format!("Unable to run the advent-of-code executable: {error}"))?;
+        Ok(status.success())
+    };
+
+    if !fits_in(MAX_STACK_SIZE)? {
+        return Err(format!(
+            "Solving needs more than {} MiB of stack",
+            MAX_STACK_SIZE >> 20
+        ));
+    }
+    let (mut too_small, mut enough) = (0, MAX_STACK_SIZE);
+    while enough - too_small > STACK_SIZE_PRECISION {
+        let stack_size = too_small.midpoint(enough);
+        if fits_in(stack_size)? {
+            enough = stack_size;
+        } else {
+            too_small = stack_size;
+        }
+    }
+    Ok(enough)
+}
+
+/// Prints allocation statistics of solving a problem read from stdin, or of all problems
+/// (of a year) in [INPUT_DIRECTORY] if no problem is given, with the max stack depth if
+/// `--stack` is given, since measuring it solves each problem many times.
+#[cfg(feature = "count-allocations")]
+#[allow(clippy::print_stdout)]
+fn print_stats(args: &[String]) -> Result<(), String> {
+    let (measure_stack, args) = match args {
+        [flag, rest @ ..] if flag == "--stack" => (true, rest),
+        _ => (false, args),
+    };
+    let measured_stack_depth = |year: u16, day: u8, part: u8, input: &str| {
+        measure_stack
+            .then(|| max_stack_depth(year, day, part, input))
+            .transpose()
+    };
+    let parse_year = |year: &str| year.parse::<u16>().map_err(|_| "Invalid year");
+    match args {
+        [year, day, part] => {
+            let (year, day, part) = (
+                parse_year(year)?,
+                day.parse::<u8>().map_err(|_| "Invalid day")?,
+                part.parse::<u8>().map_err(|_| "Invalid part")?,
+            );
+            let mut input = String::new();
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|error| format!("Error reading input: {error}"))?;
+            let (result, stats) = solve_with_allocation_stats(year, day, part, &input);
+            result?;
+            let max_stack_depth = measured_stack_depth(year, day, part, &input)?;
+            println!("Allocations:     {}", stats.allocations);
+            println!("Allocated bytes: {}", stats.allocated_bytes);
+            println!("Peak heap bytes: {}", stats.peak_heap_bytes);
+            if let Some(max_stack_depth) = max_stack_depth {
+                println!("Max stack depth: {} KiB", max_stack_depth >> 10);
+            }
+        }
+        [] | [_] => {
+            let year = args.first().map(|year| parse_year(year)).transpose()?;
+            println!(
+                "{:<10} {:>12} {:>15} {:>15}{}",
+                "puzzle",
+                "allocations",
+                "allocated bytes",
+                "peak heap bytes",
+                if measure_stack { "  stack KiB" } else { "" }
+            );
+            let (mut solved, mut allocating, mut total) = (0, 0, AllocationStats::default());
+            for puzzle in puzzles().filter(|puzzle| year.is_none_or(|year| year == puzzle.year)) {
+                let path = format!(
+                    "{INPUT_DIRECTORY}/year{}/day{:02}_input.txt",
+                    puzzle.year, puzzle.day
+                );
+                let input = std::fs::read_to_string(&path)
+                    .map_err(|error| format!("Unable to read {path}: {error}"))?;
+                for part in 1..=puzzle.parts {
+                    let name = format!("{}_{:02}_{part}", puzzle.year, puzzle.day);
+                    let (result, stats) =
+                        solve_with_allocation_stats(puzzle.year, puzzle.day, part, &input);
+                    result.map_err(|error| format!("{name}: {error}"))?;
+                    let max_stack_depth =
+                        measured_stack_depth(puzzle.year, puzzle.day, part, &input)
+                            .map_err(|error| format!("{name}: {error}"))?;
+                    println!(
+                        "{name:<10} {:>12} {:>15} {:>15}{}",
+                        stats.allocations,
+                        stats.allocated_bytes,
+                        stats.peak_heap_bytes,
+                        max_stack_depth
+                            .map(|depth| format!(" {:>10}", depth >> 10))
+                            .unwrap_or_default()
+                    );
+                    solved += 1;
+                    // The answer itself is allocated as a string:
+                    if stats.allocations > 1 {
+                        allocating += 1;
+                    }
+                    total.allocations += stats.allocations;
+                    total.allocated_bytes += stats.allocated_bytes;
+                    total.peak_heap_bytes = total.peak_heap_bytes.max(stats.peak_heap_bytes);
+                }
+            }
+            println!(
+                "{:<10} {:>12} {:>15} {:>15}",
+                "total", total.allocations, total.allocated_bytes, total.peak_heap_bytes
+            );
+            println!("{allocating} of {solved} solutions allocate more than the answer");
+        }
+        _ => {
+            return Err(
+                "usage: advent-of-code stats [--stack] [year [day part < input-file]]".to_string(),
+            );
+        }
+    }
+    Ok(())
+}
 
 #[allow(clippy::print_stdout)]
 #[allow(clippy::print_stderr)]
 fn main() -> Result<(), String> {
     let usage = || -> ! {
-        eprintln!("usage: advent-of-code [year] [day] [part] < [input-file]");
+        eprintln!(
+            "usage: advent-of-code [--format text|json] [--param name=value]... [--explain] [year] [day] [part] < [input-file]\n       advent-of-code stats [--stack] [year [day part < input-file]]"
+        );
         std::process::exit(1);
     };
 
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+
+    if args.get(1).is_some_and(|arg| arg == "stats") {
+        #[cfg(feature = "count-allocations")]
+        return print_stats(&args[2..]);
+        #[cfg(not(feature = "count-allocations"))]
+        return Err("stats requires building with the count-allocations feature".to_string());
+    }
+
+    // Used internally by stats when measuring the max stack depth:
+    #[cfg(feature = "count-allocations")]
+    if let [_, flag, stack_size, year, day, part] = args.as_slice()
+        && flag == "--stack-size"
+    {
+        let (Ok(stack_size), Ok(year), Ok(day), Ok(part)) =
+            (stack_size.parse(), year.parse(), day.parse(), part.parse())
+        else {
+            usage();
+        };
+        let mut input = String::new();
+        std::io::stdin()
+            .read_to_string(&mut input)
+            .map_err(|error| format!("Error reading input: {error}"))?;
+        solve_with_stack_size(stack_size, year, day, part, &input);
+        return Ok(());
+    }
+
+    // The JSON output has the fields of the SolveResponse of the advent-of-code-service crate,
+    // except for the time spent solving, and the trace with --explain:
+    let json = match args.iter().position(|arg| arg == "--format") {
+        Some(index) if index + 1 < args.len() => {
+            let format = args.remove(index + 1);
+            args.remove(index);
+            match format.as_str() {
+                "text" => false,
+                "json" => true,
+                _ => usage(),
+            }
+        }
+        Some(_) => usage(),
+        None => false,
+    };
+
+    let mut options = SolveOptions::default();
+    while let Some(index) = args.iter().position(|arg| arg == "--param") {
+        if index + 1 >= args.len() {
+            usage();
+        }
+        let assignment = args.remove(index + 1);
+        args.remove(index);
+        options = options.parse_param(&assignment)?;
+    }
+
+    // Print intermediate values recorded by the solver, which requires the trace feature:
+    let explain = if let Some(index) = args.iter().position(|arg| arg == "--explain") {
+        args.remove(index);
+        if !cfg!(feature = "trace") {
+            return Err("--explain requires building with the trace feature".to_string());
+        }
+        true
+    } else {
+        false
+    };
 
     if args.iter().any(|s| s == "-v" || s == "--version") {
         println!(env!("CARGO_PKG_VERSION"));
@@ -38,14 +302,76 @@ fn main() -> Result<(), String> {
             .map_err(|error| format!("Error reading input: {error}"))?;
 
         for _ in 0..repeat {
-            match solve_raw(year, day, part, input.as_ref()) {
-                Ok(result) => {
-                    if repeat == 1 {
+            let (result, trace): (_, Vec<(&str, String)>) =
+                match (year.parse(), day.parse(), part.parse()) {
+                    #[cfg(feature = "trace")]
+                    (Ok(year), Ok(day), Ok(part)) if explain => {
+                        let (result, trace) = solve_with_trace(year, day, part, &input, &options);
+                        let trace = trace
+                            .into_iter()
+                            .map(|entry| (entry.name, entry.value))
+                            .collect();
+                        (result, trace)
+                    }
+                    (Ok(year), Ok(day), Ok(part)) => (
+                        solve_with_options(year, day, part, &input, &options),
+                        Vec::new(),
+                    ),
+                    _ => (solve_raw(year, day, part, &input), Vec::new()),
+                };
+            if explain && !json && repeat == 1 {
+                for (name, value) in &trace {
+                    if value.contains('\n') {
+                        eprintln!("{name}:\n{value}");
+                    } else {
+                        eprintln!("{name}: {value}");
+                    }
+                }
+            }
+            // Only included in the JSON output with --explain, to keep it unchanged otherwise:
+            let json_trace = if explain {
+                let entries = trace
+                    .iter()
+                    .map(|(name, value)| {
+                        format!(
+                            "{{\"name\":{},\"value\":{}}}",
+                            json_string(name),
+                            json_string(value)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                format!(",\"trace\":[{}]", entries.join(","))
+            } else {
+                String::new()
+            };
+            match result {
+                Ok(result) if repeat == 1 => {
+                    if json {
+                        println!("{{\"answer\":{}{json_trace}}}", json_string(&result));
+                    } else {
                         println!("{result}");
                     }
                 }
+                Ok(_) => {}
                 Err(error) => {
-                    eprintln!("Error: {error}");
+                    if json {
+                        let kind = match (year.parse(), day.parse(), part.parse()) {
+                            (Ok(year), Ok(day), Ok(part)) => ErrorKind::of(year, day, part, &error),
+                            _ => ErrorKind::UnsupportedPuzzle,
+                        };
+                        let (line, column) = error_position(&error);
+                        let position = [("line", line), ("column", column)]
+                            .iter()
+                            .filter_map(|(name, value)| Some(format!(",\"{name}\":{}", (*value)?)))
+                            .collect::<String>();
+                        println!(
+                            "{{\"error\":{{\"kind\":\"{}\",\"message\":{}{position}}}{json_trace}}}",
+                            kind.name(),
+                            json_string(&error)
+                        );
+                    } else {
+                        eprintln!("Error: {error}");
+                    }
                     std::process::exit(1);
                 }
             }
@@ -0,0 +1,110 @@
+//! Parameters overriding constants which solvers otherwise take from the puzzle description
+//! for the real input, such as the size of a grid, so that the smaller examples can be solved.
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// A parameter of a puzzle, as listed in [Puzzle::params](struct.Puzzle.html#structfield.params).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Param {
+    /// The name of the parameter, as in `row`.
+    pub name: &'static str,
+    /// What the parameter controls, including which part it affects.
+    pub description: &'static str,
+    /// The value used for the real puzzle input.
+    pub default: i64,
+    /// The smallest value supported.
+    pub min: i64,
+    /// The largest value supported.
+    pub max: i64,
+}
+
+/// Options for [solve_with_options](fn.solve_with_options.html).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SolveOptions {
+    /// Values of [parameters](struct.Param.html) of the puzzle, by name.
+    pub params: Vec<(String, i64)>,
+}
+
+impl SolveOptions {
+    /// Returns the options with a value for the named parameter.
+    #[must_use]
+    pub fn param(mut self, name: &str, value: i64) -> Self {
+        self.params.retain(|(existing, _)| existing != name);
+        self.params.push((name.to_string(), value));
+        self
+    }
+
+    /// Returns the options with a parameter assignment, as in `row=10`, parsed and added.
+    ///
+    /// # Example
+    /// ```
+    /// use advent_of_code::SolveOptions;
+    /// let options = SolveOptions::default().parse_param("row=10").unwrap();
+    /// assert_eq!(options, SolveOptions::default().param("row", 10));
+    /// assert!(SolveOptions::default().parse_param("row").is_err());
+    /// ```
+    pub fn parse_param(self, assignment: &str) -> Result<Self, String> {
+        let (name, value) = assignment
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid parameter '{assignment}' - expected name=value"))?;
+        let value = value
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| format!("Invalid value for parameter '{name}' - expected an integer"))?;
+        Ok(self.param(name.trim(), value))
+    }
+
+    /// Checks that all parameters are among the supported ones and within their range.
+    pub(crate) fn validate(&self, supported: &[Param]) -> Result<(), String> {
+        for (name, value) in &self.params {
+            let Some(param) = supported.iter().find(|param| param.name == name) else {
+                let names = supported
+                    .iter()
+                    .map(|param| param.name)
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(if names.is_empty() {
+                    format!("Unknown parameter '{name}' - the puzzle has no parameters")
+                } else {
+                    format!("Unknown parameter '{name}' - supported: {names}")
+                });
+            };
+            if !(param.min..=param.max).contains(value) {
+                return Err(format!(
+                    "Invalid value {value} for parameter '{name}' - must be {}-{}",
+                    param.min, param.max
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn validate() {
+    const PARAMS: [Param; 1] = [Param {
+        name: "steps",
+        description: "",
+        default: 64,
+        min: 1,
+        max: 100,
+    }];
+    assert_eq!(SolveOptions::default().validate(&[]), Ok(()));
+    assert_eq!(
+        SolveOptions::default().param("steps", 6).validate(&PARAMS),
+        Ok(())
+    );
+    assert_eq!(
+        SolveOptions::default().param("steps", 0).validate(&PARAMS),
+        Err("Invalid value 0 for parameter 'steps' - must be 1-100".to_string())
+    );
+    assert_eq!(
+        SolveOptions::default().param("rows", 6).validate(&PARAMS),
+        Err("Unknown parameter 'rows' - supported: steps".to_string())
+    );
+    assert_eq!(
+        SolveOptions::default().param("rows", 6).validate(&[]),
+        Err("Unknown parameter 'rows' - the puzzle has no parameters".to_string())
+    );
+}
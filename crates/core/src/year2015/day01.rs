@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<i32, String> {
     let mut floor = 0;
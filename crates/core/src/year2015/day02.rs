@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut wrapping_paper = 0;
@@ -24,9 +26,9 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             .map_err(|_| on_error())?;
 
         wrapping_paper += 2 * (length * width + width * height + height * length)
-            + std::cmp::min(
+            + core::cmp::min(
                 length * width,
-                std::cmp::min(width * height, height * length),
+                core::cmp::min(width * height, height * length),
             );
 
         let mut dimensions = [length, width, height];
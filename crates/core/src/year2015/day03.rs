@@ -1,5 +1,7 @@
 use crate::input::Input;
-use std::collections::HashSet;
+use alloc::format;
+use alloc::string::String;
+use hashbrown::HashSet;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut visited_houses = HashSet::new();
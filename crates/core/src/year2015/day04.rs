@@ -1,16 +1,33 @@
 use crate::common::int_to_ascii::IntToAsciiContext;
-use crate::common::md5::Context;
+use crate::common::md5::{self, Context};
+use crate::common::progress;
 use crate::input::Input;
+use alloc::string::String;
+
+const MAX_INDEX: u32 = 100_000_000;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
-    const MAX_INDEX: u32 = 100_000_000;
+    // Check if hash starts with five/six zeros without converting it to a string:
+    let max_third_byte = input.part_values(0x0F, 0);
+    md5::search_nonces(
+        input.text.as_bytes(),
+        0..MAX_INDEX,
+        |output| output[..2] == [0, 0] && output[2] <= max_third_byte,
+        |_, _| true,
+    )?
+    .ok_or_else(|| progress::budget_exceeded(format_args!("{MAX_INDEX} iterations")))
+}
 
+pub fn solve_scalar(input: &Input) -> Result<u32, String> {
     let mut ascii_bytes_context = IntToAsciiContext::new();
     let secret_key = input.text.as_bytes();
     let mut hasher = Context::new();
     hasher.consume(secret_key);
 
     for index in 0..MAX_INDEX {
+        if index.is_multiple_of(1 << 16) {
+            progress::report(index.into(), MAX_INDEX.into())?;
+        }
         let mut index_hasher = hasher.clone();
         index_hasher.consume(ascii_bytes_context.ascii_bytes(index));
         let output: [u8; 16] = index_hasher.compute();
@@ -21,12 +38,18 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         }
     }
 
-    Err(format!("Aborting after {MAX_INDEX} iterations"))
+    Err(progress::budget_exceeded(format_args!(
+        "{MAX_INDEX} iterations"
+    )))
 }
 
 #[test]
 pub fn tests() {
+    test_part_one!("abcdef" => 609_043);
+    test_part_one!("pqrstuv" => 1_048_970);
+
     let real_input = include_str!("day04_input.txt");
     test_part_one!(real_input => 117_946);
     test_part_two!(real_input => 3_938_038);
+    assert_eq!(solve_scalar(&Input::part_one(real_input)), Ok(117_946));
 }
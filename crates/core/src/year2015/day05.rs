@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     fn is_nice_part_1(string: &&str) -> bool {
@@ -1,4 +1,7 @@
 use crate::input::{Input, Part};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 #[derive(Clone, Copy)]
 enum Action {
@@ -1,5 +1,7 @@
 use crate::input::Input;
-use std::collections::HashMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashMap;
 
 type SignalValue = u16;
 
@@ -104,6 +106,8 @@ pub fn solve(input: &Input) -> Result<SignalValue, String> {
         Ok(value_of_a)
     } else {
         let value_of_a_str = value_of_a.to_string();
+        // Rebound so that the gates, which will borrow the value, are dropped before it:
+        let mut gates = gates;
         for (_key, value) in gates.iter_mut() {
             value.computed_value = None;
         }
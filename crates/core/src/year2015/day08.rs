@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     let mut result = 0;
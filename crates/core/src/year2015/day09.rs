@@ -2,6 +2,8 @@ use crate::common::id_assigner::IdAssigner;
 use crate::common::permutation::all_permutations;
 use crate::common::tuple_window_iterator::TupleWindowIteratorExt;
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 const MAX_LOCATIONS: u16 = 10;
 
@@ -33,8 +35,8 @@ pub fn solve(input: &Input) -> Result<u32, String> {
 
     let mut best_distance = input.part_values(u32::MAX, u32::MIN);
     let comparator = input.part_values(
-        std::cmp::min as fn(_, _) -> _,
-        std::cmp::max as fn(_, _) -> _,
+        core::cmp::min as fn(_, _) -> _,
+        core::cmp::max as fn(_, _) -> _,
     );
 
     all_permutations(&mut places, &mut |ordering| {
@@ -1,5 +1,7 @@
 use crate::common::int_to_ascii::IntToAsciiContext;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 fn read_string(s: &[u8]) -> Vec<u8> {
     let mut ascii_bytes_context = IntToAsciiContext::new();
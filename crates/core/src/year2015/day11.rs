@@ -1,5 +1,6 @@
 use crate::input::Input;
-use std::collections::HashSet;
+use alloc::string::{String, ToString};
+use hashbrown::HashSet;
 
 fn is_valid(password: &[u8]) -> bool {
     // "Passwords must include one increasing straight of at least three letters,
@@ -39,7 +40,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
     'outer: loop {
         if is_valid(&current_password) {
             if return_next_password {
-                return Ok(std::str::from_utf8(&current_password)
+                return Ok(core::str::from_utf8(&current_password)
                     .map_err(|_| "Invalid utf-8 in password")?
                     .to_string());
             } else {
@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::HashMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashMap;
 
 #[allow(clippy::use_self)]
 #[derive(Eq, PartialEq, Debug)]
@@ -140,6 +143,7 @@ pub fn solve(input: &Input) -> Result<i32, String> {
 
 #[test]
 pub fn test_parse() {
+    use alloc::vec;
     let mut current_idx = 0_usize;
     assert_eq!(
         Ok(JsonValue::Number(1234)),
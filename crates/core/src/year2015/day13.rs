@@ -1,6 +1,9 @@
 use crate::common::id_assigner::IdAssigner;
 use crate::common::permutation::all_permutations;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 const MAX_ATTENDEES: usize = 10;
 
@@ -66,7 +69,7 @@ pub fn solve(input: &Input) -> Result<i32, String> {
             this_happiness_change += happiness_changes[this][next] + happiness_changes[next][this];
         }
 
-        best_happiness = std::cmp::max(best_happiness, this_happiness_change);
+        best_happiness = core::cmp::max(best_happiness, this_happiness_change);
         Ok(())
     })?;
 
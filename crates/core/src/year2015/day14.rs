@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 struct Reindeer {
     speed: i32,
@@ -43,11 +45,11 @@ pub fn solve(input: &Input) -> Result<i32, String> {
             let cycle_distance = deer.speed * deer.duration;
             let distance_from_full_cycles = (second / cycle_duration) * cycle_distance;
             let remaining_seconds = second % cycle_duration;
-            let speed_during_remaining_seconds = std::cmp::min(remaining_seconds, deer.duration);
+            let speed_during_remaining_seconds = core::cmp::min(remaining_seconds, deer.duration);
             let distance_during_remaining_seconds = deer.speed * speed_during_remaining_seconds;
 
             deer.distance = distance_from_full_cycles + distance_during_remaining_seconds;
-            best_distance = std::cmp::max(best_distance, deer.distance);
+            best_distance = core::cmp::max(best_distance, deer.distance);
         }
 
         for deer in reindeers.iter_mut() {
@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 struct Ingredient {
     capacity: i32,
@@ -54,7 +57,7 @@ fn highest_score(
     for i in 0..=(100 - spoons_used_so_far) {
         teaspoons[index] = i;
         let score = highest_score(ingredients, teaspoons, index + 1, part2);
-        max_score = std::cmp::max(max_score, score);
+        max_score = core::cmp::max(max_score, score);
     }
     max_score
 }
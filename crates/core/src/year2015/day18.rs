@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::String;
 
 struct Grid {
     data: [bool; 10_000],
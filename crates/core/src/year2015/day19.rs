@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::{HashMap, HashSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut mappings = HashMap::new();
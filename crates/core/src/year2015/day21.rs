@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::String;
 
 struct Stats {
     hit_points: u16,
@@ -43,10 +44,10 @@ struct Item {
 fn player_wins(player: &Stats, boss: &Stats) -> bool {
     let player_rounds_to_win = boss
         .hit_points
-        .div_ceil(std::cmp::max(i32::from(player.damage) - i32::from(boss.armor), 1) as u16);
+        .div_ceil(core::cmp::max(i32::from(player.damage) - i32::from(boss.armor), 1) as u16);
     let boss_rounds_to_win = player
         .hit_points
-        .div_ceil(std::cmp::max(i32::from(boss.damage) - i32::from(player.armor), 1) as u16);
+        .div_ceil(core::cmp::max(i32::from(boss.damage) - i32::from(player.armor), 1) as u16);
     player_rounds_to_win <= boss_rounds_to_win
 }
 
@@ -173,9 +174,9 @@ pub fn solve(input: &Input) -> Result<u16, String> {
     let mut best_cost = input.part_values(u16::MAX, u16::MIN);
     let want_player_to_win = input.is_part_one();
     let cost_keeper = if input.is_part_one() {
-        std::cmp::min
+        core::cmp::min
     } else {
-        std::cmp::max
+        core::cmp::max
     };
 
     for weapon in weapons.iter() {
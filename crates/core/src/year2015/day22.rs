@@ -1,6 +1,7 @@
 use crate::input::Input;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use alloc::collections::BinaryHeap;
+use alloc::string::{String, ToString};
+use core::cmp::Reverse;
 
 #[derive(Default, Eq, PartialEq, Hash, Clone)]
 struct Effect {
@@ -165,7 +166,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
 
             // Boss turn
             if let Some(effective_armor) = process_effects(&mut state_after_spell, &effects) {
-                let damage_on_player = std::cmp::max(1, boss_damage - effective_armor);
+                let damage_on_player = core::cmp::max(1, boss_damage - effective_armor);
                 if damage_on_player < state_after_spell.player_hit_points {
                     state_after_spell.player_hit_points -= damage_on_player;
                     to_visit.push(Reverse(state_after_spell));
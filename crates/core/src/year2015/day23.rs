@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 #[derive(Copy, Clone)]
 enum Instruction {
@@ -1,5 +1,7 @@
 use crate::common::parser::parse_lines;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec;
 
 // arr[]  ---> Input Array
 // data[] ---> Temporary array to store current combination
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let words = input.text.split(' ').collect::<Vec<_>>();
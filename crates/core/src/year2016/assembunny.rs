@@ -1,31 +1,26 @@
+use crate::common::vm::{self, Budget, Machine, Operand, Step};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
 pub type Word = i32;
 type Register = u8;
 
-#[derive(Copy, Clone)]
-pub enum ValueOrRegister {
-    Value(Word),
-    Register(Register),
-}
+pub type ValueOrRegister = Operand<Word>;
 
-impl ValueOrRegister {
-    fn parse(input: &str) -> Result<Self, String> {
-        Ok(if ["a", "b", "c", "d"].contains(&input) {
-            Self::Register(input.as_bytes()[0] - b'a')
-        } else {
-            Self::Value(input.parse::<Word>().map_err(|_| "Invalid value")?)
-        })
-    }
+const REGISTERS: core::ops::RangeInclusive<u8> = b'a'..=b'd';
+
+const MAX_INSTRUCTIONS: u64 = 100_000_000;
+
+fn overflow() -> String {
+    "Overflow in program".to_string()
 }
 
 fn parse_register(input: &str) -> Result<Register, String> {
-    if ["a", "b", "c", "d"].contains(&input) {
-        Ok(input.as_bytes()[0] - b'a')
-    } else {
-        Err("Invalid register - not a/b/c/d".to_string())
-    }
+    vm::parse_register(input, REGISTERS).ok_or_else(|| "Invalid register - not a/b/c/d".to_string())
 }
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub enum Instruction {
     // cpy x y copies x (either an integer or the value of a register) into register y.
     Copy(ValueOrRegister, Register),
@@ -41,6 +36,18 @@ pub enum Instruction {
     Nop,
     // out x transmits x (either an integer or the value of a register) as the next value for the clock signal.
     Out(ValueOrRegister),
+    // Synthetic instruction created by the peephole optimizer, replacing the add-loop
+    // `inc y / dec x / jnz x -2`: adds register x to register y and sets x to zero.
+    Add(Register, Register),
+    // Synthetic instruction created by the peephole optimizer, replacing the multiply-loop
+    // `cpy v inner / inc target / dec inner / jnz inner -2 / dec outer / jnz outer -5`:
+    // adds v * outer to target and sets inner and outer to zero.
+    Multiply {
+        value: ValueOrRegister,
+        inner: Register,
+        outer: Register,
+        target: Register,
+    },
 }
 
 impl Instruction {
@@ -49,7 +56,7 @@ impl Instruction {
         match words[0] {
             "cpy" => {
                 if words.len() == 3 {
-                    let first_parameter = ValueOrRegister::parse(words[1])?;
+                    let first_parameter = ValueOrRegister::parse(words[1], REGISTERS)?;
                     let second_parameter = parse_register(words[2])?;
                     Ok(Self::Copy(first_parameter, second_parameter))
                 } else {
@@ -81,8 +88,8 @@ impl Instruction {
             }
             "jnz" => {
                 if words.len() == 3 {
-                    let first_parameter = ValueOrRegister::parse(words[1])?;
-                    let second_parameter = ValueOrRegister::parse(words[2])?;
+                    let first_parameter = ValueOrRegister::parse(words[1], REGISTERS)?;
+                    let second_parameter = ValueOrRegister::parse(words[2], REGISTERS)?;
                     Ok(Self::Jump(first_parameter, second_parameter))
                 } else {
                     Err(format!(
@@ -96,7 +103,7 @@ impl Instruction {
                 Ok(Self::Toggle(register))
             }
             "out" => {
-                let parameter = ValueOrRegister::parse(words[1])?;
+                let parameter = ValueOrRegister::parse(words[1], REGISTERS)?;
                 Ok(Self::Out(parameter))
             }
             _ => Err("Invalid instruction not starting with cpy, inc, dec or jnz".to_string()),
@@ -117,14 +124,95 @@ impl Instruction {
                 _ => Self::Nop,
             },
             Self::Nop => Self::Nop,
+            // Synthetic instructions are never toggled, as toggling works on the source instructions.
+            Self::Add(..) | Self::Multiply { .. } => self,
+        }
+    }
+
+    /// The number of source instructions replaced by a synthetic instruction.
+    const fn replaced_length(self) -> usize {
+        match self {
+            Self::Add(..) => 3,
+            Self::Multiply { .. } => 6,
+            _ => 1,
+        }
+    }
+}
+
+/// Matches the add-loop `inc y / dec x / jnz x -2` (or with inc and dec swapped) at the start of `instructions`.
+fn match_add_loop(instructions: &[Instruction]) -> Option<(Register, Register)> {
+    use Operand::{Register, Value};
+    match instructions {
+        [
+            Instruction::Increase(to),
+            Instruction::Decrease(from),
+            Instruction::Jump(Register(counter), Value(-2)),
+            ..,
+        ]
+        | [
+            Instruction::Decrease(from),
+            Instruction::Increase(to),
+            Instruction::Jump(Register(counter), Value(-2)),
+            ..,
+        ] if from == counter && from != to => Some((*from, *to)),
+        _ => None,
+    }
+}
+
+/// Matches the multiply-loop `cpy v inner / <add-loop from inner> / dec outer / jnz outer -5`
+/// at the start of `instructions`.
+fn match_multiply_loop(instructions: &[Instruction]) -> Option<Instruction> {
+    use Operand::{Register, Value};
+    let [Instruction::Copy(value, inner), ..] = *instructions else {
+        return None;
+    };
+    let (from, target) = match_add_loop(instructions.get(1..)?)?;
+    let [
+        Instruction::Decrease(outer),
+        Instruction::Jump(Register(counter), Value(-5)),
+    ] = *instructions.get(4..6)?
+    else {
+        return None;
+    };
+    let value_is_modified = matches!(value, Register(r) if [inner, outer, target].contains(&r));
+    (from == inner && counter == outer && ![inner, target].contains(&outer) && !value_is_modified)
+        .then_some(Instruction::Multiply {
+            value,
+            inner,
+            outer,
+            target,
+        })
+}
+
+/// Peephole optimization pass replacing add- and multiply-loops with synthetic instructions.
+///
+/// The returned instructions have the same length as the source ones, with the synthetic
+/// instruction placed at the start of a loop and the rest kept, so that jumps into the
+/// middle of a replaced loop still work.
+fn optimize(instructions: &[Instruction]) -> Vec<Instruction> {
+    let mut optimized = instructions.to_vec();
+    for (idx, optimized_instruction) in optimized.iter_mut().enumerate() {
+        let remaining = &instructions[idx..];
+        if let Some(multiply) = match_multiply_loop(remaining) {
+            *optimized_instruction = multiply;
+        } else if let Some((from, to)) = match_add_loop(remaining) {
+            *optimized_instruction = Instruction::Add(from, to);
         }
     }
+    optimized
 }
 
+#[derive(Clone)]
 pub struct Computer {
     // The assembunny code you've extracted operates on four registers (a, b, c, and d) that start at 0 and can hold any integer
     pub(crate) registers: [Word; 4],
     pub(crate) instructions: Vec<Instruction>,
+    /// The instructions after peephole optimization, kept in sync with the source ones.
+    optimized: Vec<Instruction>,
+    instruction_pointer: usize,
+    /// The number of toggles executed, which identifies the instructions of a computer
+    /// at any point during execution, since the same toggles are executed in the same order.
+    toggles: u32,
 }
 
 impl Computer {
@@ -135,48 +223,57 @@ impl Computer {
         }
         Ok(Self {
             registers: [0, 0, 0, 0],
+            optimized: optimize(&instructions),
             instructions,
+            instruction_pointer: 0,
+            toggles: 0,
         })
     }
 
-    pub(crate) fn execute(&mut self) -> Word {
-        let mut current_instruction = 0;
-        'outer: while let Some(&instruction) = self.instructions.get(current_instruction) {
-            match instruction {
-                Instruction::Copy(value_or_register, register) => {
-                    let value = self.value_of(value_or_register);
-                    self.registers[register as usize] = value;
-                }
-                Instruction::Increase(register) => {
-                    self.registers[register as usize] += 1;
-                }
-                Instruction::Decrease(register) => {
-                    self.registers[register as usize] -= 1;
-                }
-                Instruction::Jump(first, second) => {
-                    if self.value_of(first) != 0 {
-                        current_instruction =
-                            (current_instruction as Word + self.value_of(second)) as usize;
-                        continue 'outer;
-                    }
+    pub(crate) fn execute(&mut self) -> Result<Word, String> {
+        self.run(&mut Budget::new(MAX_INSTRUCTIONS))?;
+        Ok(self.registers[0])
+    }
+
+    /// Executes a synthetic instruction, returning the number of source instructions to skip.
+    ///
+    /// Returns None if the instruction is not synthetic, or if the loop it replaces would not
+    /// terminate normally - in which case the source instruction should be executed instead.
+    fn execute_synthetic(&mut self, instruction: Instruction) -> Result<Option<usize>, String> {
+        match instruction {
+            Instruction::Add(from, to) => {
+                let count = self.registers[from as usize];
+                if count <= 0 {
+                    return Ok(None);
                 }
-                Instruction::Toggle(register) => {
-                    let value = self.registers[register as usize];
-                    let ptr = current_instruction as Word + value;
-                    if ptr < 0 || ptr >= self.instructions.len() as Word {
-                        // If an attempt is made to toggle an instruction outside the program, nothing happens.
-                    } else {
-                        self.instructions[ptr as usize] = self.instructions[ptr as usize].toggle();
-                    }
+                self.registers[to as usize] = self.registers[to as usize]
+                    .checked_add(count)
+                    .ok_or_else(overflow)?;
+                self.registers[from as usize] = 0;
+            }
+            Instruction::Multiply {
+                value,
+                inner,
+                outer,
+                target,
+            } => {
+                let value = self.value_of(value);
+                let count = self.registers[outer as usize];
+                if value <= 0 || count <= 0 {
+                    return Ok(None);
                 }
-                Instruction::Nop => {}
-                Instruction::Out(_a) => {}
+                self.registers[target as usize] = value
+                    .checked_mul(count)
+                    .and_then(|product| self.registers[target as usize].checked_add(product))
+                    .ok_or_else(overflow)?;
+                self.registers[inner as usize] = 0;
+                self.registers[outer as usize] = 0;
+            }
+            _ => {
+                return Ok(None);
             }
-
-            current_instruction += 1;
         }
-
-        self.registers[0]
+        Ok(Some(instruction.replaced_length()))
     }
 
     const fn value_of(&self, value_or_register: ValueOrRegister) -> Word {
@@ -186,3 +283,118 @@ impl Computer {
         }
     }
 }
+
+impl Machine for Computer {
+    type Output = Word;
+    type State = (usize, [Word; 4], u32);
+
+    fn step(&mut self) -> Result<Step<Word>, String> {
+        let Some(&instruction) = self.optimized.get(self.instruction_pointer) else {
+            return Ok(Step::Halted);
+        };
+        if let Some(replaced_length) = self.execute_synthetic(instruction)? {
+            self.instruction_pointer += replaced_length;
+            return Ok(Step::Executed);
+        }
+
+        let mut step = Step::Executed;
+        match self.instructions[self.instruction_pointer] {
+            Instruction::Copy(value_or_register, register) => {
+                let value = self.value_of(value_or_register);
+                self.registers[register as usize] = value;
+            }
+            Instruction::Increase(register) => {
+                self.registers[register as usize] = self.registers[register as usize]
+                    .checked_add(1)
+                    .ok_or_else(overflow)?;
+            }
+            Instruction::Decrease(register) => {
+                self.registers[register as usize] = self.registers[register as usize]
+                    .checked_sub(1)
+                    .ok_or_else(overflow)?;
+            }
+            Instruction::Jump(first, second) => {
+                if self.value_of(first) != 0 {
+                    self.instruction_pointer =
+                        (self.instruction_pointer as Word + self.value_of(second)) as usize;
+                    return Ok(step);
+                }
+            }
+            Instruction::Toggle(register) => {
+                let value = self.registers[register as usize];
+                let ptr = self.instruction_pointer as Word + value;
+                if ptr < 0 || ptr >= self.instructions.len() as Word {
+                    // If an attempt is made to toggle an instruction outside the program, nothing happens.
+                } else {
+                    let ptr = ptr as usize;
+                    self.instructions[ptr] = self.instructions[ptr].toggle();
+                    self.toggles += 1;
+                    // The toggled instruction may be part of an optimized loop, or create a new one:
+                    self.optimized = optimize(&self.instructions);
+                }
+            }
+            Instruction::Nop => {}
+            Instruction::Out(value_or_register) => {
+                step = Step::Output(self.value_of(value_or_register));
+            }
+            Instruction::Add(..) | Instruction::Multiply { .. } => {
+                // Only present in the optimized instructions.
+            }
+        }
+
+        self.instruction_pointer += 1;
+        Ok(step)
+    }
+
+    fn state(&self) -> Self::State {
+        (self.instruction_pointer, self.registers, self.toggles)
+    }
+}
+
+#[test]
+fn peephole_optimization() {
+    let computer = Computer::parse("cpy 3 b\ninc a\ndec b\njnz b -2").unwrap();
+    assert!(optimize(&computer.instructions)[1] == Instruction::Add(1, 0));
+
+    let mut computer =
+        Computer::parse("cpy 4 d\ncpy 3 c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5").unwrap();
+    let optimized = optimize(&computer.instructions);
+    assert!(
+        optimized[1]
+            == Instruction::Multiply {
+                value: ValueOrRegister::Value(3),
+                inner: 2,
+                outer: 3,
+                target: 0
+            }
+    );
+    assert!(optimized[2] == Instruction::Add(2, 0));
+    assert_eq!(computer.execute(), Ok(12));
+    assert_eq!(computer.registers, [12, 0, 0, 0]);
+
+    // Jumping into the middle of an optimized loop executes the source instructions:
+    let mut computer = Computer::parse("cpy 2 a\njnz 1 2\ninc b\ndec a\njnz a -2").unwrap();
+    assert_eq!(computer.execute(), Ok(0));
+    assert_eq!(computer.registers[1], 1);
+
+    // Toggling the jnz of an add-loop into a cpy must invalidate the optimization:
+    let mut computer = Computer::parse("tgl c\ncpy 5 b\ninc a\ndec b\njnz b -2").unwrap();
+    computer.registers[2] = 4;
+    assert_eq!(computer.execute(), Ok(1));
+    assert_eq!(computer.registers[1], 4);
+
+    // Toggling an instruction into one completing an add-loop must create the optimization:
+    let mut computer = Computer::parse("cpy 5 b\ntgl c\ninc a\ninc b\njnz b -2").unwrap();
+    computer.registers[2] = 2;
+    assert_eq!(computer.execute(), Ok(5));
+
+    // Values overflowing in an optimized loop are reported as an error:
+    let mut computer = Computer::parse(
+        "cpy 100000 b\ncpy 100000 d\ncpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5",
+    )
+    .unwrap();
+    assert_eq!(computer.execute(), Err("Overflow in program".to_string()));
+    let mut computer =
+        Computer::parse("cpy 2147483647 a\ncpy 2 b\ninc a\ndec b\njnz b -2").unwrap();
+    assert_eq!(computer.execute(), Err("Overflow in program".to_string()));
+}
@@ -1,5 +1,6 @@
 use crate::input::Input;
-use std::collections::HashSet;
+use alloc::string::{String, ToString};
+use hashbrown::HashSet;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     const fn turn(direction: (i32, i32), right: bool) -> (i32, i32) {
@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     #![allow(clippy::match_same_arms)]
@@ -88,6 +89,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
 
 #[test]
 pub fn tests() {
+    use alloc::string::ToString;
     let real_input = include_str!("day02_input.txt");
     test_part_one!(real_input => "38961".to_string());
     test_part_two!(real_input => "46C92".to_string());
@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     fn is_triangle_possible(n1: u16, n2: u16, n3: u16) -> bool {
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     const NUM_ASCII_LOWERCASE: usize = 26;
@@ -29,7 +31,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
 
         if input.is_part_one() {
             let mut char_frequency: [(u8, u32); NUM_ASCII_LOWERCASE] =
-                std::array::from_fn(|i| (i as u8, 0));
+                core::array::from_fn(|i| (i as u8, 0));
             for c in room_name.bytes().filter(u8::is_ascii_lowercase) {
                 char_frequency[(c - b'a') as usize].1 += 1;
             }
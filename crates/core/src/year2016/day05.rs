@@ -1,10 +1,28 @@
 use crate::common::int_to_ascii::IntToAsciiContext;
-use crate::common::md5::Context;
+use crate::common::md5::{self, Context};
+use crate::common::progress;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+
+const MAX_INDEX: u32 = 100_000_000;
 
 pub fn solve(input: &Input) -> Result<String, String> {
-    const MAX_INDEX: u32 = 100_000_000;
+    let mut password = input.part_values(Vec::new(), vec![' '; 8]);
+    let door_id = input.text.as_bytes();
+    if door_id.len() > 8 {
+        return Err("Too long door id (max length: 8)".to_string());
+    }
+
+    md5::search_nonces(door_id, 0..MAX_INDEX, is_interesting, |_, output| {
+        update_password(&mut password, &output, input.is_part_one())
+    })?
+    .map(|_| String::from_iter(password))
+    .ok_or_else(|| progress::budget_exceeded(format_args!("{MAX_INDEX} iterations")))
+}
 
+pub fn solve_scalar(input: &Input) -> Result<String, String> {
     let mut ascii_bytes_context = IntToAsciiContext::new();
     let mut password = input.part_values(Vec::new(), vec![' '; 8]);
     let door_id = input.text.as_bytes();
@@ -16,39 +34,50 @@ pub fn solve(input: &Input) -> Result<String, String> {
     hasher.consume(door_id);
 
     for index in 0..MAX_INDEX {
+        if index.is_multiple_of(1 << 16) {
+            progress::report(index.into(), MAX_INDEX.into())?;
+        }
         let mut new_hasher = hasher.clone();
         new_hasher.consume(ascii_bytes_context.ascii_bytes(index));
         let output: [u8; 16] = new_hasher.compute();
 
-        // Check if hash starts with five zeros without converting it to a string:
-        if output[..2] == [0, 0] && output[2] <= 0x0F {
-            if input.is_part_one() {
-                password.push(
-                    format!("{:x?}", (output[2] & 0x0F_u8))
-                        .as_str()
-                        .chars()
-                        .next()
-                        .unwrap_or('_'),
-                );
-                if password.len() == 8 {
-                    return Ok(String::from_iter(password));
-                }
-            } else {
-                let position = output[2] & 0x0F_u8;
-                let character = output[3] >> 4;
-                // "Use only the first result for each position, and ignore invalid positions.":
-                if position < 8 && password[position as usize] == ' ' {
-                    password[position as usize] =
-                        format!("{character:x?}").chars().next().unwrap_or('_');
-                    if !password.contains(&' ') {
-                        return Ok(String::from_iter(password));
-                    }
-                }
-            }
+        if is_interesting(&output) && update_password(&mut password, &output, input.is_part_one()) {
+            return Ok(String::from_iter(password));
         }
     }
 
-    Err(format!("Aborting after {MAX_INDEX} iterations"))
+    Err(progress::budget_exceeded(format_args!(
+        "{MAX_INDEX} iterations"
+    )))
+}
+
+/// Check if hash starts with five zeros without converting it to a string.
+fn is_interesting(output: &[u8; 16]) -> bool {
+    output[..2] == [0, 0] && output[2] <= 0x0F
+}
+
+/// Update the password from an interesting hash, returning whether it is complete.
+fn update_password(password: &mut Vec<char>, output: &[u8; 16], part_one: bool) -> bool {
+    if part_one {
+        password.push(
+            format!("{:x?}", (output[2] & 0x0F_u8))
+                .as_str()
+                .chars()
+                .next()
+                .unwrap_or('_'),
+        );
+        password.len() == 8
+    } else {
+        let position = output[2] & 0x0F_u8;
+        let character = output[3] >> 4;
+        // "Use only the first result for each position, and ignore invalid positions.":
+        if position < 8 && password[position as usize] == ' ' {
+            password[position as usize] = format!("{character:x?}").chars().next().unwrap_or('_');
+            !password.contains(&' ')
+        } else {
+            false
+        }
+    }
 }
 
 #[test]
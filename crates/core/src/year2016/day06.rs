@@ -1,5 +1,6 @@
 use crate::input::Input;
-use std::collections::HashMap;
+use alloc::string::String;
+use hashbrown::HashMap;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let mut counts: [HashMap<u8, u32>; 8] = [
@@ -39,6 +40,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
 
 #[test]
 pub fn tests() {
+    use alloc::string::ToString;
     let real_input = include_str!("day06_input.txt");
     test_part_one!(real_input => "qzedlxso".to_string());
     test_part_two!(real_input => "ucmifjae".to_string());
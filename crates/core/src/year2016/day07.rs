@@ -1,5 +1,6 @@
 use crate::input::Input;
-use std::collections::HashSet;
+use alloc::string::String;
+use hashbrown::HashSet;
 
 fn has_abba(ip: &&str) -> bool {
     let ip_bytes = ip.as_bytes();
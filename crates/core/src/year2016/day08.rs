@@ -1,5 +1,6 @@
 use crate::common::character_recognition::{CHAR_HEIGHT, recognize};
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 struct Screen {
     pixels: [bool; Self::WIDTH * CHAR_HEIGHT],
@@ -84,7 +85,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
     if input.is_part_one() {
         Ok(screen.pixels.iter().filter(|&&p| p).count().to_string())
     } else {
-        recognize(&screen.pixels)
+        recognize(input, &screen.pixels)
     }
 }
 
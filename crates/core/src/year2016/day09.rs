@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 fn uncompressed_size(text: &[u8], recursive: bool) -> Result<u64, String> {
     let error_mapper_uf8 = |_| "Invalid input";
@@ -20,11 +22,11 @@ fn uncompressed_size(text: &[u8], recursive: bool) -> Result<u64, String> {
                 if parts.len() != 2 {
                     return Err("Invalid input".into());
                 }
-                let chars_to_take = std::str::from_utf8(parts[0])
+                let chars_to_take = core::str::from_utf8(parts[0])
                     .map_err(error_mapper_uf8)?
                     .parse::<u64>()
                     .map_err(error_mapper_parse)?;
-                let repetitions = std::str::from_utf8(parts[1])
+                let repetitions = core::str::from_utf8(parts[1])
                     .map_err(error_mapper_uf8)?
                     .parse::<u64>()
                     .map_err(error_mapper_parse)?;
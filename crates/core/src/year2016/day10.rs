@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 type BotId = u8;
 
@@ -35,8 +37,8 @@ fn receive(
     let bot = &mut bots[usize::from(to_bot)];
 
     if let Some(first_microchip) = bot.received_chip {
-        let low_microchip = std::cmp::min(first_microchip, microchip);
-        let high_microchip = std::cmp::max(first_microchip, microchip);
+        let low_microchip = core::cmp::min(first_microchip, microchip);
+        let high_microchip = core::cmp::max(first_microchip, microchip);
 
         if part1 && (low_microchip, high_microchip) == (17, 61) {
             return Some(to_bot);
@@ -1,8 +1,11 @@
 use crate::input::Input;
-use std::cmp::Ordering;
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap, HashSet};
-use std::hash::{Hash, Hasher};
+use alloc::collections::BinaryHeap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::cmp::Reverse;
+use core::hash::{Hash, Hasher};
+use hashbrown::{HashMap, HashSet};
 
 #[derive(Clone, Default, Eq, PartialEq, Hash, Copy)]
 struct Floor {
@@ -1,10 +1,11 @@
 use super::assembunny::{Computer, Word};
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<Word, String> {
     let mut computer = Computer::parse(input.text)?;
     computer.registers[2] = input.part_values(0, 1);
-    Ok(computer.execute())
+    computer.execute()
 }
 
 #[test]
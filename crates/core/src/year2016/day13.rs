@@ -1,6 +1,9 @@
 use crate::input::Input;
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashSet};
+use alloc::collections::BinaryHeap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::cmp::Reverse;
+use hashbrown::HashSet;
 
 const fn is_wall(x: i32, y: i32, magic_number: i32) -> bool {
     let sum = x * x + 3 * x + 2 * x * y + y + y * y + magic_number;
@@ -1,5 +1,9 @@
-use crate::common::md5::Context;
+use crate::common::md5::{self, Context, LANES};
+use crate::common::parallel;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 fn to_hash_chars(hash: &[u8]) -> [u8; 32] {
     let mut hash_chars = [0_u8; 32];
@@ -13,6 +17,11 @@ fn to_hash_chars(hash: &[u8]) -> [u8; 32] {
     hash_chars
 }
 
+/// The lowercase hexadecimal representation of a hash.
+fn to_hex(hash: &[u8]) -> [u8; 32] {
+    to_hash_chars(hash).map(|b| if b <= 9 { b'0' + b } else { b'a' + (b - 10) })
+}
+
 fn first_triplet(hash: &[u8]) -> Option<u8> {
     let hash_chars = to_hash_chars(hash);
     hash_chars
@@ -28,12 +37,73 @@ fn contains_five_in_a_row(hash: &[u8], desired_char: u8) -> bool {
         .any(|w| w[0] == desired_char && w.windows(2).all(|adjacent| adjacent[0] == adjacent[1]))
 }
 
+/// The hashes of [`LANES`] consecutive indices, which with key stretching are
+/// rehashed 2016 times.
+fn hashes_from(salt: &str, first_index: usize, key_stretching: bool) -> [[u8; 16]; LANES] {
+    let messages: [String; LANES] =
+        core::array::from_fn(|lane| format!("{salt}{}", first_index + lane));
+    let mut hashes = md5::compute_lanes(messages.each_ref().map(String::as_bytes));
+    if key_stretching {
+        for _ in 0..2016 {
+            let hash_strs = hashes.map(|hash| to_hex(&hash));
+            hashes = md5::compute_lanes(hash_strs.each_ref().map(<[u8; 32]>::as_slice));
+        }
+    }
+    hashes
+}
+
 pub fn solve(input: &Input) -> Result<u32, String> {
     let salt = input.text;
     if salt.len() > 8 {
         return Err("Too long salt (max length: 8)".to_string());
     }
 
+    let key_stretching = input.is_part_two();
+    let mut hashes = Vec::new();
+    let mut valid_key_count = 0;
+    for index in 0..=100_000 {
+        while hashes.len() <= index + 1000 {
+            let first_index = hashes.len();
+            hashes.extend(
+                parallel::map_reduce(
+                    parallel::num_threads(),
+                    |batch| {
+                        Vec::from(hashes_from(
+                            salt,
+                            first_index + batch * LANES,
+                            key_stretching,
+                        ))
+                    },
+                    |mut batch_hashes, more_hashes| {
+                        batch_hashes.extend(more_hashes);
+                        batch_hashes
+                    },
+                )
+                .unwrap_or_default(),
+            );
+        }
+
+        if let Some(triplet_value) = first_triplet(&hashes[index])
+            && hashes[index + 1..=index + 1000]
+                .iter()
+                .any(|hash| contains_five_in_a_row(hash, triplet_value))
+        {
+            valid_key_count += 1;
+            if valid_key_count == 64 {
+                return Ok(index as u32);
+            }
+        }
+    }
+
+    Err("Time out".to_string())
+}
+
+pub fn solve_scalar(input: &Input) -> Result<u32, String> {
+    let salt = input.text;
+    if salt.len() > 8 {
+        return Err("Too long salt (max length: 8)".to_string());
+    }
+
     let mut hash_cache = Vec::new();
     let mut orig_hasher = Context::new();
     orig_hasher.consume(salt.as_bytes());
@@ -44,12 +114,8 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         if input.is_part_two() {
             for _ in 0..2016 {
                 let hash: [u8; 16] = hasher.compute();
-                let hash_str = to_hash_chars(&hash)
-                    .iter()
-                    .map(|&b| if b <= 9 { b'0' + b } else { b'a' + (b - 10) })
-                    .collect::<Vec<_>>();
                 hasher = Context::new();
-                hasher.consume(&hash_str);
+                hasher.consume(&to_hex(&hash));
             }
         }
         let hash: [u8; 16] = hasher.compute();
@@ -68,11 +134,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
                 for _ in 0..2016 {
                     let hash: [u8; 16] = hasher.compute();
                     hasher = Context::new();
-                    let hash_str = to_hash_chars(&hash)
-                        .iter()
-                        .map(|&b| if b <= 9 { b'0' + b } else { b'a' + (b - 10) })
-                        .collect::<Vec<_>>();
-                    hasher.consume(&hash_str);
+                    hasher.consume(&to_hex(&hash));
                 }
             }
             hasher.compute()
@@ -103,4 +165,5 @@ pub fn tests() {
     let real_input = include_str!("day14_input.txt");
     test_part_one!(real_input => 15168);
     test_part_two!(real_input => 20864);
+    assert_eq!(solve_scalar(&Input::part_one(real_input)), Ok(15168));
 }
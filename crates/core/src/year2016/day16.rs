@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 fn compute_checksum(input: &str) -> String {
     let candidate = input
@@ -1,7 +1,10 @@
-use crate::common::md5::Context;
+use crate::common::md5::{self, Context, LANES};
 use crate::input::Input;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use alloc::collections::BinaryHeap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
 
 #[derive(Hash, Clone, Eq, Ord, PartialOrd, PartialEq)]
 struct State {
@@ -14,7 +17,10 @@ fn check_doors(passcode: &[u8], path_so_far: &[u8]) -> [bool; 4] {
     let mut hasher = Context::new();
     hasher.consume(passcode);
     hasher.consume(path_so_far);
-    let output: [u8; 16] = hasher.compute();
+    open_doors(&hasher.compute())
+}
+
+fn open_doors(output: &[u8; 16]) -> [bool; 4] {
     //"Only the first four characters of the hash are used; they represent, respectively,
     // the doors up, down, left, and right from your current position.
     // Any b, c, d, e, or f means that the corresponding door is open; any other character
@@ -28,9 +34,68 @@ fn check_doors(passcode: &[u8], path_so_far: &[u8]) -> [bool; 4] {
     ]
 }
 
+const DIRECTIONS: [((i32, i32), u8); 4] = [
+    ((0, -1), b'U'),
+    ((0, 1), b'D'),
+    ((-1, 0), b'L'),
+    ((1, 0), b'R'),
+];
+
 pub fn solve(input: &Input) -> Result<String, String> {
     let passcode = input.text.as_bytes();
 
+    // Search breadth first, with the passcode followed by the path so far as message
+    // to hash, so that the doors of all paths of the same length can be hashed in lanes:
+    let mut paths = vec![((0, 0), passcode.to_vec())];
+    let mut path_length = 0;
+    let mut desired_path_length = None;
+
+    while !paths.is_empty() {
+        let (at_vault, in_progress): (Vec<_>, Vec<_>) = paths
+            .into_iter()
+            .partition(|(position, _)| *position == (3, 3));
+        if let Some((_, message)) = at_vault.iter().min() {
+            if input.is_part_one() {
+                return Ok(message[passcode.len()..]
+                    .iter()
+                    .map(|&byte| byte as char)
+                    .collect::<String>());
+            }
+            desired_path_length = Some(path_length);
+        }
+
+        paths = Vec::new();
+        for batch in in_progress.chunks(LANES) {
+            let outputs = md5::compute_lanes(core::array::from_fn(|lane| {
+                batch.get(lane).map_or(&[][..], |(_, message)| message)
+            }));
+            for ((position, message), output) in batch.iter().zip(outputs) {
+                for (&is_open, &(direction, direction_char)) in
+                    open_doors(&output).iter().zip(&DIRECTIONS)
+                {
+                    let new_position = (position.0 + direction.0, position.1 + direction.1);
+                    if is_open
+                        && (0..4).contains(&new_position.0)
+                        && (0..4).contains(&new_position.1)
+                    {
+                        let mut new_message = message.clone();
+                        new_message.push(direction_char);
+                        paths.push((new_position, new_message));
+                    }
+                }
+            }
+        }
+        path_length += 1;
+    }
+
+    desired_path_length
+        .map(|length: u32| length.to_string())
+        .ok_or_else(|| "No path found".to_string())
+}
+
+pub fn solve_scalar(input: &Input) -> Result<String, String> {
+    let passcode = input.text.as_bytes();
+
     let mut to_visit = BinaryHeap::new();
 
     let initial_state = State {
@@ -96,7 +161,18 @@ pub fn solve(input: &Input) -> Result<String, String> {
 
 #[test]
 pub fn tests() {
+    test_part_one!("ihgpwlah" => "DDRRRD".to_string());
+    test_part_two!("ihgpwlah" => "370".to_string());
+    test_part_one!("kglvqrro" => "DDUDRLRRUDRD".to_string());
+    test_part_two!("kglvqrro" => "492".to_string());
+    test_part_one!("ulqzkmiv" => "DRURDRUDDLLDLUURRDULRLDUUDDDRR".to_string());
+    test_part_two!("ulqzkmiv" => "830".to_string());
+
     let real_input = include_str!("day17_input.txt");
     test_part_one!(real_input => "RDRDUDLRDR".to_string());
     test_part_two!(real_input => "386".to_string());
+    assert_eq!(
+        solve_scalar(&Input::part_two(real_input)),
+        Ok("386".to_string())
+    );
 }
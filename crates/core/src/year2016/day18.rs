@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut row = input
@@ -35,7 +38,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             }
         }
 
-        std::mem::swap(&mut row, &mut next_row);
+        core::mem::swap(&mut row, &mut next_row);
     }
 
     Ok(safe_count as u32)
@@ -1,10 +1,12 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let n = u32::from(
         input
             .text
-            .parse::<std::num::NonZeroU32>()
+            .parse::<core::num::NonZeroU32>()
             .map_err(|e| format!("Invalid number of elves: {e}"))?,
     );
 
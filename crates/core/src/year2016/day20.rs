@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut intervals = Vec::new();
@@ -20,7 +22,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             if from > lowest_allowed {
                 lowest_allowed
             } else {
-                std::cmp::max(lowest_allowed, to + 1)
+                core::cmp::max(lowest_allowed, to + 1)
             }
         }))
     } else {
@@ -30,7 +32,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             if highest_blocked != u32::MAX && from > highest_blocked + 1 {
                 in_gaps += from - highest_blocked - 1;
             }
-            highest_blocked = std::cmp::max(highest_blocked, to);
+            highest_blocked = core::cmp::max(highest_blocked, to);
         }
         Ok(u32::MAX - highest_blocked + in_gaps)
     }
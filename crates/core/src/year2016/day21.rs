@@ -1,5 +1,8 @@
 use crate::common::permutation::all_permutations;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let mut password = [b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h'];
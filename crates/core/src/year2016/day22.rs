@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 struct Node {
     position: (u8, u8),
@@ -1,25 +1,11 @@
-use super::assembunny::{Computer, Instruction, ValueOrRegister, Word};
+use super::assembunny::{Computer, Word};
 use crate::input::Input;
-
-fn factorial(num: Word) -> Word {
-    match num {
-        0 | 1 => 1,
-        _ => factorial(num - 1) * num,
-    }
-}
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<Word, String> {
     let mut computer = Computer::parse(input.text)?;
-    let register_a_value = input.part_values(7, 12);
-    if computer.instructions.len() > 20
-        && let Instruction::Copy(ValueOrRegister::Value(a), _) = computer.instructions[19]
-        && let Instruction::Jump(ValueOrRegister::Value(b), _) = computer.instructions[20]
-    {
-        return Ok(a * b + factorial(register_a_value));
-    }
-
-    computer.registers[0] = register_a_value;
-    Ok(computer.execute())
+    computer.registers[0] = input.part_values(7, 12);
+    computer.execute()
 }
 
 #[test]
@@ -1,7 +1,11 @@
 use crate::common::permutation::all_permutations;
 use crate::input::Input;
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use alloc::collections::BinaryHeap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cmp::Reverse;
+use hashbrown::{HashMap, HashSet};
 
 struct Grid {
     cols: usize,
@@ -114,8 +118,8 @@ pub fn solve(input: &Input) -> Result<usize, String> {
             let mut total_distance = 0;
             for &n in order.iter() {
                 let key = (
-                    std::cmp::min(current_location, n),
-                    std::cmp::max(current_location, n),
+                    core::cmp::min(current_location, n),
+                    core::cmp::max(current_location, n),
                 );
                 total_distance += distances.get(&key).unwrap_or(&0);
                 current_location = n;
@@ -123,7 +127,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
             if input.is_part_two() {
                 total_distance += distances.get(&(0, current_location)).unwrap_or(&0);
             }
-            answer = std::cmp::min(answer, total_distance);
+            answer = core::cmp::min(answer, total_distance);
             Ok(())
         },
     )?;
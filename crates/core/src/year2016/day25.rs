@@ -1,25 +1,57 @@
-use super::assembunny::{Computer, Instruction, ValueOrRegister};
+use super::assembunny::{Computer, Word};
+use crate::common::cycle::CycleDetector;
+use crate::common::vm::{Budget, Machine};
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
-pub fn solve(input: &Input) -> Result<u32, String> {
-    let computer = Computer::parse(input.text)?;
-    if let Instruction::Copy(ValueOrRegister::Value(a), _register) = computer.instructions[1]
-        && let Instruction::Copy(ValueOrRegister::Value(b), _register) = computer.instructions[2]
-    {
-        let start_value = a * b;
-        for initial_value in 1..1000 {
-            let value = start_value + initial_value;
-            if format!("{value:b}").replace("10", "").is_empty() {
-                return Ok(initial_value as u32);
-            }
+const MAX_INITIAL_VALUE: Word = 100_000;
+/// The limit on the number of instructions executed in total, over all initial values tried.
+const MAX_INSTRUCTIONS: u64 = 10_000_000;
+
+/// Checks if the program, started with the given initial value in register a, outputs
+/// the clock signal 0, 1, 0, 1, ... forever.
+fn outputs_clock_signal(
+    computer: &Computer,
+    initial_value: Word,
+    budget: &mut Budget,
+) -> Result<bool, String> {
+    let mut computer = computer.clone();
+    computer.registers[0] = initial_value;
+
+    let mut expected_output = 0;
+    let mut detector = CycleDetector::new();
+    while let Some(value) = computer.run_until_output(budget)? {
+        if value != expected_output {
+            return Ok(false);
+        }
+        expected_output = 1 - expected_output;
+        // Seeing the same state expecting the same output means that the signal repeats forever:
+        if detector
+            .record((computer.state(), expected_output))
+            .is_some()
+        {
+            return Ok(true);
         }
     }
+    Ok(false)
+}
 
-    Err("Input does not match expectations".to_string())
+pub fn solve(input: &Input) -> Result<Word, String> {
+    let computer = Computer::parse(input.text)?;
+    let mut budget = Budget::new(MAX_INSTRUCTIONS);
+    for initial_value in 1..MAX_INITIAL_VALUE {
+        if outputs_clock_signal(&computer, initial_value, &mut budget)? {
+            return Ok(initial_value);
+        }
+    }
+    Err("No initial value outputs a clock signal".to_string())
 }
 
 #[test]
 pub fn tests() {
     let real_input = include_str!("day25_input.txt");
     test_part_one!(real_input => 196);
+
+    // A program outputting a wrong first value after a long loop gives up after a few tries:
+    test_part_one_error!("cpy 1000000 b\ndec b\njnz b -1\nout 1" => "Aborted after 10000000 instructions");
 }
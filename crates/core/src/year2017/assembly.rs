@@ -1,32 +1,22 @@
-use std::collections::VecDeque;
+use crate::common::vm::{self, Budget, Machine, Operand, Step};
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 pub type RegisterSpecifier = u8;
 pub type NumberValue = i64;
 
-#[derive(Copy, Clone)]
-pub enum Value {
-    Register(RegisterSpecifier),
-    Number(NumberValue),
-}
+pub type Value = Operand<NumberValue>;
 
-impl Value {
-    fn parse(input: &str) -> Option<Self> {
-        Some(if let Some(register_vaule) = Self::parse_register(input) {
-            Self::Register(register_vaule)
-        } else {
-            Self::Number(input.parse::<NumberValue>().ok()?)
-        })
-    }
+const REGISTERS: core::ops::RangeInclusive<u8> = b'a'..=b'z';
 
-    const fn parse_register(input: &str) -> Option<RegisterSpecifier> {
-        if input.len() == 1 {
-            let c = input.as_bytes()[0];
-            if c.is_ascii_lowercase() {
-                return Some(c - b'a');
-            }
-        }
-        None
-    }
+fn parse_value(input: &str) -> Option<Value> {
+    Value::parse(input, REGISTERS).ok()
+}
+
+fn parse_register(input: &str) -> Option<RegisterSpecifier> {
+    vm::parse_register(input, REGISTERS)
 }
 
 #[derive(Copy, Clone)]
@@ -46,42 +36,42 @@ impl Instruction {
     fn parse(input: &str) -> Option<Self> {
         let mut parts = input.split(' ');
         match parts.next() {
-            Some("snd") => Some(Self::Snd(Value::parse(parts.next()?)?)),
+            Some("snd") => Some(Self::Snd(parse_value(parts.next()?)?)),
             Some("set") => Some(Self::Set(
-                Value::parse_register(parts.next()?)?,
-                Value::parse(parts.next()?)?,
+                parse_register(parts.next()?)?,
+                parse_value(parts.next()?)?,
             )),
             Some("add") => Some(Self::Add(
-                Value::parse_register(parts.next()?)?,
-                Value::parse(parts.next()?)?,
+                parse_register(parts.next()?)?,
+                parse_value(parts.next()?)?,
             )),
             Some("sub") => Some(Self::Sub(
-                Value::parse_register(parts.next()?)?,
-                Value::parse(parts.next()?)?,
+                parse_register(parts.next()?)?,
+                parse_value(parts.next()?)?,
             )),
             Some("mul") => Some(Self::Mul(
-                Value::parse_register(parts.next()?)?,
-                Value::parse(parts.next()?)?,
+                parse_register(parts.next()?)?,
+                parse_value(parts.next()?)?,
             )),
             Some("mod") => Some(Self::Mod(
-                Value::parse_register(parts.next()?)?,
-                Value::parse(parts.next()?)?,
+                parse_register(parts.next()?)?,
+                parse_value(parts.next()?)?,
             )),
             Some("rcv") => {
-                let value = Value::parse(parts.next()?)?;
-                if matches!(value, Value::Number(_)) {
+                let value = parse_value(parts.next()?)?;
+                if matches!(value, Value::Value(_)) {
                     // rcv expects a register.
                     return None;
                 }
                 Some(Self::Rcv(value))
             }
             Some("jgz") => Some(Self::Jgz(
-                Value::parse(parts.next()?)?,
-                Value::parse(parts.next()?)?,
+                parse_value(parts.next()?)?,
+                parse_value(parts.next()?)?,
             )),
             Some("jnz") => Some(Self::Jnz(
-                Value::parse(parts.next()?)?,
-                Value::parse(parts.next()?)?,
+                parse_value(parts.next()?)?,
+                parse_value(parts.next()?)?,
             )),
             _ => None,
         }
@@ -91,19 +81,19 @@ impl Instruction {
 #[derive(Clone)]
 pub struct Program {
     pub(crate) registers: Vec<NumberValue>,
-    instruction_pointer: u8,
+    instruction_pointer: usize,
     pub(crate) terminated: bool,
     pub(crate) last_played_frequency: NumberValue,
     pub(crate) instructions: Vec<Instruction>,
-    executed_instructions_count: u32,
     pub(crate) input_queue: VecDeque<NumberValue>,
     pub(crate) sent_value_count: NumberValue,
     pub(crate) mul_count: u32,
+    /// If snd and rcv sends and receives values (as in day 18 part 2), instead of
+    /// playing and recovering sounds.
+    duet: bool,
 }
 
 impl Program {
-    const MAX_INSTRUCTIONS: u32 = 100_000;
-
     pub(crate) fn parse(input: &str) -> Result<Self, String> {
         let instructions = input
             .lines()
@@ -119,90 +109,120 @@ impl Program {
             terminated: false,
             last_played_frequency: 0,
             instructions,
-            executed_instructions_count: 0,
             input_queue: VecDeque::new(),
             sent_value_count: 0,
             mul_count: 0,
+            duet: false,
         })
     }
 
     fn value_of(&self, value: Value) -> NumberValue {
-        match value {
-            Value::Register(register_specifier) => self.registers[register_specifier as usize],
-            Value::Number(number_value) => number_value,
-        }
+        value.value(&self.registers)
+    }
+
+    fn jump(&mut self, offset: NumberValue) {
+        // Jumping before the first instruction halts, just as jumping after the last one:
+        self.instruction_pointer =
+            usize::try_from(self.instruction_pointer as NumberValue + offset).unwrap_or(usize::MAX);
     }
 
+    /// Runs until a sound is recovered or, if `output_queue` is given, until there is no
+    /// value to receive.
     pub(crate) fn run_until_recover(
         &mut self,
         mut output_queue: Option<&mut VecDeque<NumberValue>>,
-    ) {
+        budget: &mut Budget,
+    ) -> Result<(), String> {
+        self.duet = output_queue.is_some();
         loop {
-            if self.executed_instructions_count >= Self::MAX_INSTRUCTIONS
-                || self.instruction_pointer as usize >= self.instructions.len()
-            {
-                self.terminated = true;
-                return;
-            }
-
-            self.executed_instructions_count += 1;
-
-            match self.instructions[self.instruction_pointer as usize] {
-                Instruction::Snd(x) => {
-                    let x_value = self.value_of(x);
+            match self.run_until(budget, |_, step| matches!(step, Step::Output(_)))? {
+                Step::Output(value) => {
                     if let Some(ref mut queue) = output_queue {
                         self.sent_value_count += 1;
-                        queue.push_back(x_value);
+                        queue.push_back(value);
                     } else {
-                        self.last_played_frequency = x_value;
+                        self.last_played_frequency = value;
                     }
                 }
-                Instruction::Set(x, y) => {
-                    self.registers[x as usize] = self.value_of(y);
+                Step::Halted => {
+                    self.terminated = true;
+                    return Ok(());
                 }
-                Instruction::Add(x, y) => {
-                    self.registers[x as usize] += self.value_of(y);
+                Step::Blocked | Step::Executed => {
+                    return Ok(());
                 }
-                Instruction::Sub(x, y) => {
-                    self.registers[x as usize] -= self.value_of(y);
-                }
-                Instruction::Mul(x, y) => {
-                    self.mul_count += 1;
-                    self.registers[x as usize] *= self.value_of(y);
-                }
-                Instruction::Mod(x, y) => {
-                    self.registers[x as usize] %= self.value_of(y);
+            }
+        }
+    }
+}
+
+impl Machine for Program {
+    type Output = NumberValue;
+    type State = (usize, Vec<NumberValue>);
+
+    fn step(&mut self) -> Result<Step<NumberValue>, String> {
+        let Some(&instruction) = self.instructions.get(self.instruction_pointer) else {
+            return Ok(Step::Halted);
+        };
+
+        let mut step = Step::Executed;
+        match instruction {
+            Instruction::Snd(x) => {
+                step = Step::Output(self.value_of(x));
+            }
+            Instruction::Set(x, y) => {
+                self.registers[x as usize] = self.value_of(y);
+            }
+            Instruction::Add(x, y) => {
+                self.registers[x as usize] += self.value_of(y);
+            }
+            Instruction::Sub(x, y) => {
+                self.registers[x as usize] -= self.value_of(y);
+            }
+            Instruction::Mul(x, y) => {
+                self.mul_count += 1;
+                self.registers[x as usize] *= self.value_of(y);
+            }
+            Instruction::Mod(x, y) => {
+                let divisor = self.value_of(y);
+                if divisor == 0 {
+                    return Err("Modulo by zero".to_string());
                 }
-                Instruction::Rcv(x) => {
-                    if output_queue.is_none() {
-                        if self.value_of(x) != 0 {
-                            return;
-                        }
-                    } else if let Some(value) = self.input_queue.pop_front() {
-                        if let Value::Register(specifier) = x {
-                            self.registers[specifier as usize] = value;
-                        }
-                    } else {
-                        return;
+                self.registers[x as usize] %= divisor;
+            }
+            Instruction::Rcv(x) => {
+                if !self.duet {
+                    // Recovering a sound is reported as blocking:
+                    if self.value_of(x) != 0 {
+                        return Ok(Step::Blocked);
                     }
-                }
-                Instruction::Jgz(x, y) => {
-                    if self.value_of(x) > 0 {
-                        self.instruction_pointer =
-                            (NumberValue::from(self.instruction_pointer) + self.value_of(y)) as u8;
-                        continue;
+                } else if let Some(value) = self.input_queue.pop_front() {
+                    if let Value::Register(specifier) = x {
+                        self.registers[specifier as usize] = value;
                     }
+                } else {
+                    return Ok(Step::Blocked);
                 }
-                Instruction::Jnz(x, y) => {
-                    if self.value_of(x) != 0 {
-                        self.instruction_pointer =
-                            (NumberValue::from(self.instruction_pointer) + self.value_of(y)) as u8;
-                        continue;
-                    }
+            }
+            Instruction::Jgz(x, y) => {
+                if self.value_of(x) > 0 {
+                    self.jump(self.value_of(y));
+                    return Ok(step);
+                }
+            }
+            Instruction::Jnz(x, y) => {
+                if self.value_of(x) != 0 {
+                    self.jump(self.value_of(y));
+                    return Ok(step);
                 }
             }
-
-            self.instruction_pointer += 1;
         }
+
+        self.instruction_pointer += 1;
+        Ok(step)
+    }
+
+    fn state(&self) -> Self::State {
+        (self.instruction_pointer, self.registers.clone())
     }
 }
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let index_offset_computer = if input.is_part_one() {
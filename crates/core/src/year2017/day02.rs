@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let row_evaluator = if input.is_part_one() {
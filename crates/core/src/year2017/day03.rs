@@ -1,6 +1,8 @@
 use crate::input::Input;
-use std::cmp::max;
-use std::collections::HashMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::cmp::max;
+use hashbrown::HashMap;
 
 #[derive(Copy, Clone)]
 enum State {
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     let anagrams_are_equal = input.is_part_two();
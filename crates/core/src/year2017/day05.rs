@@ -1,5 +1,7 @@
 use crate::common::parser::parse_lines;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let jump_change_computer = |offset| {
@@ -1,11 +1,14 @@
+use crate::common::cycle::floyd;
+use crate::common::progress;
 use crate::input::Input;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
-    const MAX_ITERATIONS: u32 = 1_000_000;
+    const MAX_ITERATIONS: u64 = 1_000_000;
 
-    let mut memory_banks: Vec<u32> = input
+    let memory_banks: Vec<u32> = input
         .text
         .split_ascii_whitespace()
         .enumerate()
@@ -19,41 +22,31 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         return Err("Invalid empty input".to_string());
     }
 
-    let mut seen_before = HashMap::new();
+    let cycle = floyd(&memory_banks, |banks| redistribute(banks), MAX_ITERATIONS)
+        .ok_or_else(|| progress::budget_exceeded(format_args!("{MAX_ITERATIONS} iterations")))?;
+    Ok(input.part_values(cycle.start + cycle.period, cycle.period) as u32)
+}
 
-    for current_step in 0..MAX_ITERATIONS {
-        match seen_before.entry(memory_banks.clone()) {
-            Entry::Occupied(value) => {
-                return Ok(current_step - input.part_values(0_u32, *value.get()));
-            }
-            Entry::Vacant(entry) => {
-                entry.insert(current_step);
+fn redistribute(memory_banks: &mut [u32]) {
+    let bank_to_redistribute = memory_banks
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (index, &blocks)| {
+            if blocks > memory_banks[acc] {
+                index
+            } else {
+                acc
             }
-        }
-
-        let bank_to_redistribute =
-            memory_banks
-                .iter()
-                .enumerate()
-                .fold(0, |acc, (index, &blocks)| {
-                    if blocks > memory_banks[acc] {
-                        index
-                    } else {
-                        acc
-                    }
-                });
-
-        let mut blocks_to_distribute = memory_banks[bank_to_redistribute];
-        memory_banks[bank_to_redistribute] = 0;
-        let mut current_index = bank_to_redistribute;
-        while blocks_to_distribute > 0 {
-            current_index = (current_index + 1) % memory_banks.len();
-            memory_banks[current_index] += 1;
-            blocks_to_distribute -= 1;
-        }
+        });
+
+    let mut blocks_to_distribute = memory_banks[bank_to_redistribute];
+    memory_banks[bank_to_redistribute] = 0;
+    let mut current_index = bank_to_redistribute;
+    while blocks_to_distribute > 0 {
+        current_index = (current_index + 1) % memory_banks.len();
+        memory_banks[current_index] += 1;
+        blocks_to_distribute -= 1;
     }
-
-    Err(format!("Aborting after {MAX_ITERATIONS} iterations"))
 }
 
 #[test]
@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::{HashMap, HashSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
 
 type ProgramId = usize;
 
@@ -72,14 +75,18 @@ impl<'a> ProgramTree<'a> {
             }
         }
 
-        let all_program_ids: HashSet<&ProgramId> = name_to_node.values().collect();
-        let children: HashSet<&ProgramId> = name_to_node
-            .values()
-            .flat_map(|&child_program_id| nodes[child_program_id].children.iter())
-            .collect();
-
-        let roots: Vec<&&ProgramId> = all_program_ids.difference(&children).collect();
-        let root_node = **roots[0];
+        let roots: Vec<ProgramId> = {
+            let all_program_ids: HashSet<&ProgramId> = name_to_node.values().collect();
+            let children: HashSet<&ProgramId> = name_to_node
+                .values()
+                .flat_map(|&child_program_id| nodes[child_program_id].children.iter())
+                .collect();
+            all_program_ids
+                .difference(&children)
+                .map(|&&program_id| program_id)
+                .collect()
+        };
+        let root_node = roots[0];
         if roots.len() == 1 {
             Ok(Self { nodes, root_node })
         } else {
@@ -1,6 +1,9 @@
 use crate::input::Input;
-use std::cmp::max;
-use std::collections::HashMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::max;
+use hashbrown::HashMap;
 
 pub fn solve(input: &Input) -> Result<i32, String> {
     let mut registers: HashMap<&str, i32> = HashMap::new();
@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     knot_hash(input.text, input.is_part_one())
@@ -52,7 +55,7 @@ pub fn knot_hash(input: &str, part1: bool) -> Result<String, String> {
         list.chunks(16)
             .map(|block| block.iter().fold(0, |acc, x| acc ^ x))
             .fold(String::new(), |mut output, number| {
-                use std::fmt::Write;
+                use core::fmt::Write;
                 let _ = write!(output, "{number:02x}");
                 output
             })
@@ -1,10 +1,12 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 /// Using double-height coordinates - see <https://www.redblobgames.com/grids/hexagons//>
 pub fn solve(input: &Input) -> Result<u32, String> {
     fn distance(location: (i32, i32)) -> u32 {
         location.0.unsigned_abs()
-            + std::cmp::max(0, (location.1.abs() - location.0.abs()) / 2) as u32
+            + core::cmp::max(0, (location.1.abs() - location.0.abs()) / 2) as u32
     }
 
     let mut furthest = 0;
@@ -26,7 +28,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         location = (location.0 + diff.0, location.1 + diff.1);
 
         if input.is_part_two() {
-            furthest = std::cmp::max(furthest, distance(location));
+            furthest = core::cmp::max(furthest, distance(location));
         }
     }
     Ok(input.part_values(distance(location), furthest))
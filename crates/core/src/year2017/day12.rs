@@ -1,9 +1,12 @@
 use crate::input::Input;
 use crate::year2017::disjoint_set::DisjointSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     let num_programs = input.text.lines().count();
-    let mut program_groups = DisjointSet::<8196>::new(num_programs);
+    let mut program_groups = DisjointSet::<[i32; 8196]>::new(num_programs);
 
     for (line_index, line) in input.text.lines().enumerate() {
         let error_message = || {
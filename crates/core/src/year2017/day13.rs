@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     const MAX_DELAY: usize = 10_000_000;
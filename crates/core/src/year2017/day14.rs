@@ -1,7 +1,9 @@
 use super::day10::knot_hash;
 use super::disjoint_set::DisjointSet;
 use crate::input::Input;
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     // Mapping from (x,y) coordinate of a used square to an identifier
@@ -33,7 +35,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
     Ok(if input.is_part_one() {
         used_counter as u32
     } else {
-        let mut disjoint_set = DisjointSet::<16392>::new(used_counter);
+        let mut disjoint_set = DisjointSet::<[i32; 16392]>::new(used_counter);
         for ((x, y), &this_set) in location_to_set_identifier.iter() {
             // Since coordinates are stored in an ordered set we only need to consider
             // neighbors to the right and below:
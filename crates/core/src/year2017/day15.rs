@@ -1,4 +1,9 @@
+use crate::common::number_theory::LinearFunction;
+use crate::common::parallel;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 #[derive(Copy, Clone)]
 struct Generator {
@@ -7,6 +12,23 @@ struct Generator {
     only_multiples_of: u64,
 }
 
+const MODULUS: u64 = 0x7fff_ffff;
+
+impl Generator {
+    /// The generator after producing `steps` values, which is only valid without filtering.
+    fn skip_ahead(self, steps: usize) -> Self {
+        let generate = LinearFunction {
+            multiplier: i128::from(self.factor),
+            increment: 0,
+            modulus: i128::from(MODULUS),
+        };
+        Self {
+            value: generate.pow(steps as u64).apply(i128::from(self.value)) as u64,
+            ..self
+        }
+    }
+}
+
 impl Iterator for Generator {
     type Item = u64;
 
@@ -63,11 +85,60 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         only_multiples_of: input.part_values(1, 8),
     };
 
-    let matches = first_generator
-        .zip(second_generator)
-        .take(input.part_values(40_000_000, 5_000_000))
-        .filter(|(a, b)| *a as u16 == *b as u16)
-        .count();
+    let num_pairs = input.part_values(40_000_000, 5_000_000);
+    let matches = if input.is_part_one() {
+        // Without filtering the generators can skip ahead, so chunks of pairs are
+        // independent of each other:
+        const NUM_CHUNKS: usize = 64;
+        parallel::map_reduce(
+            NUM_CHUNKS,
+            |chunk| {
+                let chunk_start = chunk * num_pairs / NUM_CHUNKS;
+                let chunk_end = (chunk + 1) * num_pairs / NUM_CHUNKS;
+                first_generator
+                    .skip_ahead(chunk_start)
+                    .zip(second_generator.skip_ahead(chunk_start))
+                    .take(chunk_end - chunk_start)
+                    .filter(|(a, b)| *a as u16 == *b as u16)
+                    .count()
+            },
+            |a, b| a + b,
+        )
+        .unwrap_or_default()
+    } else if parallel::num_threads() == 1 {
+        // With filtering values can only be generated in order:
+        first_generator
+            .zip(second_generator)
+            .take(num_pairs)
+            .filter(|(a, b)| *a as u16 == *b as u16)
+            .count()
+    } else {
+        // ...but the two generators are independent of each other, so with multiple threads
+        // they can generate a round of values each at the same time:
+        const VALUES_PER_ROUND: usize = 1 << 18;
+        let mut generators = [first_generator, second_generator];
+        let mut matches = 0;
+        for round_start in (0..num_pairs).step_by(VALUES_PER_ROUND) {
+            let round_size = VALUES_PER_ROUND.min(num_pairs - round_start);
+            let rounds = parallel::map(&generators, |&generator| {
+                let mut generator = generator;
+                let values = generator
+                    .by_ref()
+                    .take(round_size)
+                    .map(|value| value as u16)
+                    .collect::<Vec<_>>();
+                (values, generator)
+            });
+            matches += rounds[0]
+                .0
+                .iter()
+                .zip(&rounds[1].0)
+                .filter(|(a, b)| a == b)
+                .count();
+            generators = [rounds[0].1, rounds[1].1];
+        }
+        matches
+    };
     Ok(matches as u32)
 }
 
@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::HashMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashMap;
 
 // https://www.reddit.com/r/adventofcode/comments/7k572l/2017_day_16_solutions/drbqb27/
 fn parse(data: &str, programs: &[u8]) -> Result<(Vec<u8>, HashMap<u8, u8>), String> {
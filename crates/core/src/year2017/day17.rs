@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::{format, vec};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let steps = input
@@ -1,10 +1,15 @@
 use super::assembly::{NumberValue, Program};
+use crate::common::vm::Budget;
 use crate::input::Input;
+use alloc::string::String;
+
+const MAX_INSTRUCTIONS: u64 = 1_000_000;
 
 pub fn solve(input: &Input) -> Result<NumberValue, String> {
     let mut program_zero = Program::parse(input.text)?;
+    let mut budget = Budget::new(MAX_INSTRUCTIONS);
     if input.is_part_one() {
-        program_zero.run_until_recover(None);
+        program_zero.run_until_recover(None, &mut budget)?;
         Ok(program_zero.last_played_frequency)
     } else {
         let mut program_one = program_zero.clone();
@@ -16,10 +21,10 @@ pub fn solve(input: &Input) -> Result<NumberValue, String> {
 
         loop {
             if !program_zero.terminated {
-                program_zero.run_until_recover(Some(&mut program_one.input_queue));
+                program_zero.run_until_recover(Some(&mut program_one.input_queue), &mut budget)?;
             }
             if !program_one.terminated {
-                program_one.run_until_recover(Some(&mut program_zero.input_queue));
+                program_one.run_until_recover(Some(&mut program_zero.input_queue), &mut budget)?;
             }
 
             if (program_zero.terminated && program_one.terminated)
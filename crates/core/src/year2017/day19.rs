@@ -1,5 +1,7 @@
+use crate::common::progress;
 use crate::input::Input;
-use std::collections::HashMap;
+use alloc::string::{String, ToString};
+use hashbrown::HashMap;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let mut map = HashMap::new();
@@ -28,7 +30,9 @@ pub fn solve(input: &Input) -> Result<String, String> {
     'outer_loop: loop {
         packet_steps += 1;
         if packet_steps >= 100_000 {
-            return Err(format!("Aborting after {packet_steps} steps"));
+            return Err(progress::budget_exceeded(format_args!(
+                "{packet_steps} steps"
+            )));
         }
         current_position = (
             current_position.0 + direction.0,
@@ -72,4 +76,6 @@ pub fn tests() {
     let real_input = include_str!("day19_input.txt");
     test_part_one!(real_input => "KGPTMEJVS".to_string());
     test_part_two!(real_input => "16328".to_string());
+
+    test_part_one_error!(" |\n +-+\n | |\n +-+\n" => "Aborted after 100000 steps");
 }
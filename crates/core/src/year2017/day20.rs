@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 // Parse input in the format "A=<211,-141,-45>".
 fn parse_vector(input: &str) -> Option<(i32, i32, i32)> {
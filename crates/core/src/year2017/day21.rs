@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 /// A 2x2 tile represented as bits. Example: "../.#" is stored as `0b_10_00`.
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
@@ -1,6 +1,7 @@
 use crate::input::Input;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+use alloc::string::String;
+use hashbrown::HashMap;
+use hashbrown::hash_map::Entry;
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 enum NodeFlag {
@@ -1,15 +1,19 @@
 use super::assembly::{Instruction, Program, Value};
+use crate::common::vm::Budget;
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 fn is_prime(number: i32) -> bool {
-    let number_sqrt = f64::from(number).sqrt() as i32;
+    let number_sqrt = number.max(0).isqrt();
     (2..=number_sqrt).all(|i| number % i != 0)
 }
 
+const MAX_INSTRUCTIONS: u64 = 1_000_000;
+
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut program = Program::parse(input.text)?;
     if input.is_part_one() {
-        program.run_until_recover(None);
+        program.run_until_recover(None, &mut Budget::new(MAX_INSTRUCTIONS))?;
         Ok(program.mul_count)
     } else {
         // Register a is set to 1 at start.
@@ -66,7 +70,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         // So the program is counting the number of non-prime values of b.
         let start_value = {
             match program.instructions[0] {
-                Instruction::Set(_, Value::Number(number)) => 100 * (number as i32) + 100_000,
+                Instruction::Set(_, Value::Value(number)) => 100 * (number as i32) + 100_000,
                 _ => {
                     return Err("Unsupported program".to_string());
                 }
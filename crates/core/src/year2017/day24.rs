@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 type PieceComponent = u8;
 type Piece = (PieceComponent, PieceComponent);
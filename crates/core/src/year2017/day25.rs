@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 struct Action {
     write_one: bool,
@@ -1,21 +1,38 @@
-pub struct DisjointSet<const MAX_SIZE: usize> {
-    pub elements: [i32; MAX_SIZE],
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// A disjoint set with elements stored in `E`, which is either an array or a vector.
+pub struct DisjointSet<E: AsRef<[i32]> + AsMut<[i32]>> {
+    pub elements: E,
     num_groups: usize,
 }
 
-impl<const MAX_SIZE: usize> DisjointSet<MAX_SIZE> {
+impl<const MAX_SIZE: usize> DisjointSet<[i32; MAX_SIZE]> {
     pub const fn new(len: usize) -> Self {
         Self {
             elements: [-1; MAX_SIZE],
             num_groups: len,
         }
     }
+}
+
+impl DisjointSet<Vec<i32>> {
+    pub fn new_heap(len: usize) -> Self {
+        Self {
+            elements: vec![-1; len],
+            num_groups: len,
+        }
+    }
+}
+
+impl<E: AsRef<[i32]> + AsMut<[i32]>> DisjointSet<E> {
+    pub fn find(&mut self, index: usize) -> usize {
+        let elements = self.elements.as_mut();
 
-    pub const fn find(&mut self, index: usize) -> usize {
         // Find root:
         let mut root_index = index;
         loop {
-            let value = self.elements[root_index];
+            let value = elements[root_index];
             if value < 0 {
                 break;
             } else {
@@ -26,11 +43,11 @@ impl<const MAX_SIZE: usize> DisjointSet<MAX_SIZE> {
         // Compress paths:
         let mut new_index = index;
         loop {
-            let value = self.elements[new_index];
+            let value = elements[new_index];
             if value < 0 {
                 break;
             } else {
-                self.elements[new_index] = root_index as i32;
+                elements[new_index] = root_index as i32;
                 new_index = value as usize;
             }
         }
@@ -38,7 +55,7 @@ impl<const MAX_SIZE: usize> DisjointSet<MAX_SIZE> {
         root_index
     }
 
-    pub const fn join(&mut self, i: usize, j: usize) -> bool {
+    pub fn join(&mut self, i: usize, j: usize) -> bool {
         let root1 = self.find(i);
         let root2 = self.find(j);
 
@@ -46,25 +63,26 @@ impl<const MAX_SIZE: usize> DisjointSet<MAX_SIZE> {
             return false;
         }
 
-        let r1 = self.elements[root1];
-        let r2 = self.elements[root2];
+        let elements = self.elements.as_mut();
+        let r1 = elements[root1];
+        let r2 = elements[root2];
 
         // Join smaller tree with bigger:
         if r1 < r2 {
-            self.elements[root1] += r2;
-            self.elements[root2] = root1 as i32;
+            elements[root1] += r2;
+            elements[root2] = root1 as i32;
         } else {
-            self.elements[root2] += r1;
-            self.elements[root1] = root2 as i32;
+            elements[root2] += r1;
+            elements[root1] = root2 as i32;
         }
 
         self.num_groups -= 1;
         true
     }
 
-    pub const fn size(&mut self, i: usize) -> usize {
+    pub fn size(&mut self, i: usize) -> usize {
         let root = self.find(i);
-        -self.elements[root] as usize
+        -self.elements.as_ref()[root] as usize
     }
 
     pub const fn num_groups(&self) -> usize {
@@ -74,7 +92,7 @@ impl<const MAX_SIZE: usize> DisjointSet<MAX_SIZE> {
 
 #[test]
 fn test_disjoint_set() {
-    let mut set = DisjointSet::<100>::new(10);
+    let mut set = DisjointSet::<[i32; 100]>::new(10);
     assert_eq!(1, set.size(0));
     assert_eq!(10, set.num_groups());
 
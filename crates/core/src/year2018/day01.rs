@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::HashSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use hashbrown::HashSet;
 
 type Frequency = i32;
 
@@ -20,7 +23,7 @@ pub fn solve(input: &Input) -> Result<Frequency, String> {
         change_iterator.sum::<Result<_, _>>()
     } else {
         let mut frequency: Frequency = 0;
-        let mut seen_frequencies = HashSet::from([frequency]);
+        let mut seen_frequencies: HashSet<Frequency> = HashSet::from([frequency]);
 
         let changes: Vec<Frequency> = change_iterator.collect::<Result<_, _>>()?;
         for &change in changes.iter().cycle().take(MAX_ITERATIONS) {
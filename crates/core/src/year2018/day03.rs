@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 struct Fabric {
     num_claims: Vec<u32>,
@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::HashMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use hashbrown::HashMap;
 
 enum EntryType {
     BeginShift { guard_id: u32 },
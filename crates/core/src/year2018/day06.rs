@@ -1,8 +1,11 @@
 use crate::input::Input;
-use std::cmp;
-use std::cmp::Ordering;
-use std::collections::HashMap;
-use std::collections::HashSet;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp;
+use core::cmp::Ordering;
+use hashbrown::HashMap;
+use hashbrown::HashSet;
 
 struct Point {
     id: i32,
@@ -1,6 +1,10 @@
 use crate::input::Input;
-use std::cmp::Ordering;
-use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
+use alloc::collections::{BTreeSet, BinaryHeap};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use hashbrown::{HashMap, HashSet};
 
 #[derive(Eq)]
 struct Step {
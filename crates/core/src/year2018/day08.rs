@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 type InputNumber = u8;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
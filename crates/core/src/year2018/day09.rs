@@ -1,5 +1,8 @@
-use std::collections::VecDeque;
-use std::num::NonZeroU32;
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::num::NonZeroU32;
 
 use crate::input::Input;
 
@@ -1,6 +1,9 @@
 use crate::input::Input;
-use std::cmp::{max, min};
-use std::collections::HashSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::{max, min};
+use hashbrown::HashSet;
 
 struct Point {
     x: i32,
@@ -1,4 +1,8 @@
+use crate::common::parallel;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 type GridValue = i32;
 
 /// A summed-area table is a data structure for quickly generating sum of values in a rectangular grid.
@@ -70,22 +74,27 @@ pub fn solve(input: &Input) -> Result<String, String> {
 
     let table = SummedAreaTable::new(serial_number);
 
-    let mut optimal_power: GridValue = 0;
-    let mut optimal_square_width = 0;
-    let mut optimal_point = (0, 0);
-
-    for square_width in input.part_values(3..=3, 1..=300) {
-        for y in 1..=(SummedAreaTable::SIZE - square_width) {
-            for x in 1..=(SummedAreaTable::SIZE - square_width) {
-                let square_power = table.square_power(x, y, square_width);
-                if square_power > optimal_power {
-                    optimal_square_width = square_width;
-                    optimal_power = square_power;
-                    optimal_point = (x, y);
+    // The first square with the highest power, with squares of each width searched in
+    // parallel and compared in order of width:
+    let square_widths = input.part_values(3..=3, 1..=300);
+    let (_, optimal_point, optimal_square_width) = parallel::map_reduce(
+        square_widths.clone().count(),
+        |width_offset| {
+            let square_width = square_widths.start() + width_offset as u32;
+            let mut optimal = (0, (0, 0), 0);
+            for y in 1..=(SummedAreaTable::SIZE - square_width) {
+                for x in 1..=(SummedAreaTable::SIZE - square_width) {
+                    let square_power = table.square_power(x, y, square_width);
+                    if square_power > optimal.0 {
+                        optimal = (square_power, (x, y), square_width);
+                    }
                 }
             }
-        }
-    }
+            optimal
+        },
+        |optimal, other| if other.0 > optimal.0 { other } else { optimal },
+    )
+    .unwrap_or_default();
 
     Ok(if input.is_part_one() {
         format!("{},{}", optimal_point.0, optimal_point.1)
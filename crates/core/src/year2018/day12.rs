@@ -1,8 +1,12 @@
+use crate::common::cycle::CycleDetector;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 struct Tunnel {
-    current_gen: std::vec::Vec<bool>,
-    next_gen: std::vec::Vec<bool>,
+    current_gen: alloc::vec::Vec<bool>,
+    next_gen: alloc::vec::Vec<bool>,
     offset: usize,
     evolutions: [bool; 32],
 }
@@ -62,15 +66,18 @@ impl Tunnel {
             self.next_gen[i] = self.evolutions[current];
         }
 
-        std::mem::swap(&mut self.next_gen, &mut self.current_gen);
+        core::mem::swap(&mut self.next_gen, &mut self.current_gen);
     }
 
-    fn is_repeating(&self) -> bool {
-        self.current_gen
-            .iter()
-            .skip_while(|&&populated| !populated)
-            .zip(self.next_gen.iter().skip_while(|&&populated| !populated))
-            .all(|(a, b)| a == b)
+    /// The pots from the first to the last one with a plant, which evolve the same way
+    /// wherever they are.
+    fn pattern(&self) -> &[bool] {
+        let first = self.current_gen.iter().position(|&populated| populated);
+        let last = self.current_gen.iter().rposition(|&populated| populated);
+        match (first, last) {
+            (Some(first), Some(last)) => &self.current_gen[first..=last],
+            _ => &[],
+        }
     }
 
     fn score(&self) -> i64 {
@@ -97,17 +104,15 @@ pub fn solve(input: &Input) -> Result<i64, String> {
         return Ok(tunnel.score());
     }
 
-    let mut previous_score = -1;
-    for generation in 1..=max_steps {
+    // The pattern eventually repeats while moving, so that the score grows linearly:
+    let mut detector = CycleDetector::new();
+    detector.record(tunnel.pattern().to_vec());
+    let mut scores = vec![tunnel.score()];
+    for _ in 0..max_steps {
         tunnel.evolve();
-
-        let score_diff = tunnel.score() - previous_score;
-        previous_score = tunnel.score();
-
-        if tunnel.is_repeating() {
-            let remaining_generations = 50_000_000_000_i64 - generation as i64;
-            let final_score = tunnel.score() + remaining_generations * score_diff;
-            return Ok(final_score);
+        scores.push(tunnel.score());
+        if let Some(cycle) = detector.record(tunnel.pattern().to_vec()) {
+            return Ok(cycle.extrapolate(50_000_000_000, |generation| scores[generation]));
         }
     }
 
@@ -1,4 +1,7 @@
-use std::collections::{HashMap, HashSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
 
 use crate::input::Input;
 
@@ -191,8 +194,8 @@ impl Track {
                         if part1 {
                             return Ok(self.carts[cart_idx].position);
                         }
-                        self.carts.remove(std::cmp::max(cart_idx, other_cart_idx));
-                        self.carts.remove(std::cmp::min(cart_idx, other_cart_idx));
+                        self.carts.remove(core::cmp::max(cart_idx, other_cart_idx));
+                        self.carts.remove(core::cmp::min(cart_idx, other_cart_idx));
                         if other_cart_idx < cart_idx {
                             cart_idx -= 1;
                         }
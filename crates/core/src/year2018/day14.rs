@@ -1,4 +1,8 @@
+use crate::common::progress;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 fn run_until<F>(condition: F) -> Result<Vec<u8>, String>
 where
@@ -38,7 +42,9 @@ where
 
         loop_count += 1;
         if loop_count > MAX_ITERATIONS {
-            return Err(format!("Aborted after {MAX_ITERATIONS} iterations"));
+            return Err(progress::budget_exceeded(format_args!(
+                "{MAX_ITERATIONS} iterations"
+            )));
         }
     }
 }
@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 #[derive(Copy, Clone)]
 enum MapCell {
@@ -239,8 +242,8 @@ impl Board {
                         found.push((cost, visiting_x, visiting_y, visiting.3, visiting.4));
                         found_cost = cost;
                     }
-                    MapCell::Open if !self.visited[(x + y * self.height) as usize] => {
-                        self.visited[(x + y * self.height) as usize] = true;
+                    MapCell::Open if !self.visited[(x + y * self.width) as usize] => {
+                        self.visited[(x + y * self.width) as usize] = true;
                         let first_x: u32;
                         let first_y: u32;
                         if visiting_x == sx && visiting_y == sy {
@@ -304,6 +307,8 @@ pub fn solve(input: &Input) -> Result<i32, String> {
 fn tests() {
     test_part_one_error!("GE" => "No line in input");
     test_part_one_error!("GE\n" => "Map does not have a wall at edges");
+    // A map higher than it is wide:
+    test_part_one!("####\n#G.#\n#..#\n#..#\n#..#\n#..#\n#.E#\n####" => 138);
 
     test_part_one!(
             "#######
@@ -1,6 +1,9 @@
 use super::elfcode::{Opcode, Registers};
 use crate::input::Input;
-use std::collections::HashSet;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashSet;
 
 struct Sample {
     registers_before: Registers,
@@ -1,7 +1,10 @@
 use crate::input::Input;
-use std::cmp::{max, min};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::{max, min};
 #[cfg(feature = "debug-output")]
-use std::env;
+use std::{env, println};
 
 fn parse_point_interval(s: &str) -> Result<(u16, u16), String> {
     if s.contains("..") {
@@ -1,8 +1,11 @@
+use crate::common::cycle::CycleDetector;
 use crate::input::Input;
-use std::collections::HashMap;
-use std::collections::hash_map::{DefaultHasher, Entry};
-use std::hash::{Hash, Hasher};
-use std::mem::swap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::hash::BuildHasher;
+use core::mem::swap;
+use foldhash::fast::FixedState;
 
 struct Grid {
     width: usize,
@@ -115,28 +118,20 @@ pub fn solve(input: &Input) -> Result<usize, String> {
         }
         Ok(grid.resource_value())
     } else {
-        let mut seen = HashMap::new();
+        const MAX_MINUTES: usize = 100_000;
 
-        for i in 1..1_000_000_000 {
-            grid.advance_minute()?;
-
-            let mut hasher = DefaultHasher::new();
-            grid.cells.hash(&mut hasher);
-            let hash_value = hasher.finish();
-
-            match seen.entry(hash_value) {
-                Entry::Occupied(entry) => {
-                    let cycle_length = i - entry.get();
-                    let remaining_hashes = (1_000_000_000 - i) % cycle_length;
-                    for _ in 0..remaining_hashes {
-                        grid.advance_minute()?;
-                    }
-                    return Ok(grid.resource_value());
-                }
-                Entry::Vacant(entry) => {
-                    entry.insert(i);
-                }
+        let mut detector = CycleDetector::new();
+        let mut resource_values = Vec::new();
+        for _ in 0..MAX_MINUTES {
+            resource_values.push(grid.resource_value() as i64);
+            if let Some(cycle) = detector.record(FixedState::default().hash_one(&grid.cells)) {
+                input.trace("cycle start", cycle.start);
+                input.trace("cycle length", cycle.period);
+                return Ok(
+                    cycle.extrapolate(1_000_000_000, |minute| resource_values[minute]) as usize,
+                );
             }
+            grid.advance_minute()?;
         }
         Err("No solution found".to_string())
     }
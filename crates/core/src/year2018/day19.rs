@@ -1,42 +1,25 @@
 use super::elfcode::Program;
+use super::elfcode_analysis::Analysis;
+use crate::common::vm::Budget;
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut program = Program::parse(input.text)?;
+    let analysis = Analysis::new(&program);
 
-    if input.is_part_one() {
-        return program.execute_until_halt(10_000_000);
+    #[cfg(feature = "debug-output")]
+    if std::env::var("ADVENT_DEBUG").is_ok() {
+        std::println!("{}", analysis.pseudo_code(&program));
     }
 
-    program.registers.values[0] = 1;
-
-    program.optimize();
-
-    if program.instructions.len() < 3 {
-        return Err("Too few instructions".to_string());
-    }
-    let register = program.instructions[2].c as usize;
-    if register > 5 {
-        return Err("Register outside bounds".to_string());
-    }
-    while program.registers.values[register] == 0 {
-        program.execute_one_instruction()?;
+    if input.is_part_two() {
+        program.registers.values[0] = 1;
     }
 
-    let mut sum = 0;
-    // Assuming number to be factored is highest register value:
-    let &seed = program
-        .registers
-        .values
-        .iter()
-        .max()
-        .ok_or("Internal error: No registers")?;
-    for i in 1..=seed {
-        if seed % i == 0 {
-            sum += i;
-        }
-    }
-    Ok(sum)
+    // The program computes the sum of divisors of a number, which the analysis
+    // recognizes and executes directly instead of looping:
+    analysis.execute_until_halt(&mut program, &mut Budget::new(10_000_000))
 }
 
 #[test]
@@ -1,7 +1,11 @@
 use crate::input::Input;
-use std::cmp::max;
-use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet, VecDeque};
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::max;
+use hashbrown::hash_map::Entry;
+use hashbrown::{HashMap, HashSet};
 
 pub fn visit_rooms<F>(input_string: &str, mut callback: F) -> Result<(), String>
 where
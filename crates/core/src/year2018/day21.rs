@@ -1,66 +1,68 @@
-use super::elfcode::Program;
+use super::elfcode::{Opcode, Program};
+use super::elfcode_analysis::Analysis;
+use crate::common::cycle::CycleDetector;
+use crate::common::vm::Budget;
 use crate::input::Input;
-use std::collections::HashSet;
+use alloc::string::{String, ToString};
 
-fn parse(input_string: &str) -> Result<Program, String> {
-    let program = Program::parse(input_string)?;
-    if program.instructions.len() != 31 {
-        return Err("Expected 31 instructions in program".to_string());
+const MAX_STEPS: u64 = 1_000_000;
+
+/// Returns the instruction comparing register 0 with another register, together with that register.
+///
+/// Program termination depends on this comparison, which should be the only place in the program
+/// where register 0 is referenced.
+fn halting_check(program: &Program) -> Result<(usize, usize), String> {
+    let mut readers = Analysis::readers_of(program, 0);
+    let (Some(check), None) = (readers.next(), readers.next()) else {
+        return Err("Register 0 is not read by exactly one instruction".to_string());
+    };
+    let instruction = &program.instructions[check];
+    if instruction.opcode != Opcode::Eqrr {
+        return Err("Register 0 is not used in an equality check".to_string());
+    }
+    let compared_with = if instruction.a == 0 {
+        instruction.b
+    } else {
+        instruction.a
+    };
+    if compared_with >= 6 {
+        return Err("Invalid register in equality check".to_string());
     }
-    Ok(program)
+    Ok((check, compared_with as usize))
 }
 
-const MAX_INSTRUCTIONS: u64 = 1_000_000;
-
 pub fn solve(input: &Input) -> Result<u64, String> {
-    let mut program = parse(input.text)?;
-    if input.is_part_one() {
-        // The last three instructions are (as seen with program.pretty_print()):
-        //
-        // 28: r4 = (r3 == r0) ? 1 : 0
-        // 29: goto r4 + 30
-        // 30: goto 6
-        //
-        // which exits on instruction 29 only if r4 is non-zero, which means r0 must equal r3.
-        //
-        // Since this is the only place in the program where register 0 is referenced, we can
-        // set register 0 to the value it's first compared with here to exit as soon as possible.
+    let mut program = Program::parse(input.text)?;
+    let analysis = Analysis::new(&program);
+    let (check, compared_with) = halting_check(&program)?;
 
-        let mut loop_count = 0;
-        while program.instruction_pointer()? != 29 {
-            program.execute_one_instruction()?;
+    #[cfg(feature = "debug-output")]
+    if std::env::var("ADVENT_DEBUG").is_ok() {
+        std::println!("{}", analysis.pseudo_code(&program));
+    }
 
-            loop_count += 1;
-            if loop_count > MAX_INSTRUCTIONS {
-                return Err(format!("Aborted after {loop_count} instructions"));
+    // Since register 0 is only used in the halting check, the values compared with it
+    // there are the values of register 0 which would cause the program to halt.
+    //
+    // For part 1 the first such value halts the program as soon as possible, while for
+    // part 2 the last value before the sequence starts to repeat maximizes the execution.
+    let mut detector = CycleDetector::new();
+    let mut last_value = 0;
+    let mut budget = Budget::new(MAX_STEPS);
+    loop {
+        if program.instruction_pointer()? == check as u64 {
+            let value = program.registers.values[compared_with];
+            if input.is_part_one() {
+                return Ok(value);
+            } else if detector.record(value).is_some() {
+                return Ok(last_value);
             }
+            last_value = value;
         }
-        Ok(program.registers.values[program.instructions[28].a as usize])
-    } else {
-        let mut seen = HashSet::new();
-        let mut last_value = 0;
-        let mut loop_count = 0;
-        loop {
-            let ip = program.instruction_pointer()?;
-            if ip == 14 {
-                if program.registers.values[program.instructions[13].c as usize] == 0 {
-                    program.registers.values[program.instructions[6].c as usize] /= 256;
-                    program.registers.values[program.instruction_pointer_index as usize] = 8;
-                }
-            } else if ip == 29 {
-                let value = program.registers.values[program.instructions[28].a as usize];
-                if seen.insert(value) {
-                    last_value = value;
-                } else {
-                    return Ok(last_value);
-                }
-            }
-            program.execute_one_instruction()?;
 
-            loop_count += 1;
-            if loop_count > MAX_INSTRUCTIONS {
-                return Err(format!("Aborted after {loop_count} instructions"));
-            }
+        budget.consume()?;
+        if !analysis.execute_one_step(&mut program)? {
+            return Err("Program halted without reaching the check of register 0".to_string());
         }
     }
 }
@@ -1,6 +1,10 @@
 use crate::input::Input;
-use std::collections::hash_map::Entry;
-use std::collections::{BinaryHeap, HashMap, HashSet};
+use alloc::collections::BinaryHeap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::hash_map::Entry;
+use hashbrown::{HashMap, HashSet};
 
 type Coordinate = i16;
 
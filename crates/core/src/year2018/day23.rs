@@ -1,6 +1,9 @@
 use crate::input::Input;
-use std::cell::RefCell;
-use std::rc::Rc;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::cell::RefCell;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 struct Position {
@@ -20,17 +23,17 @@ impl Position {
 
     fn min(&self, p: Self) -> Self {
         Self {
-            x: std::cmp::min(self.x, p.x),
-            y: std::cmp::min(self.y, p.y),
-            z: std::cmp::min(self.z, p.z),
+            x: core::cmp::min(self.x, p.x),
+            y: core::cmp::min(self.y, p.y),
+            z: core::cmp::min(self.z, p.z),
         }
     }
 
     fn max(&self, p: Self) -> Self {
         Self {
-            x: std::cmp::max(self.x, p.x),
-            y: std::cmp::max(self.y, p.y),
-            z: std::cmp::max(self.z, p.z),
+            x: core::cmp::max(self.x, p.x),
+            y: core::cmp::max(self.y, p.y),
+            z: core::cmp::max(self.z, p.z),
         }
     }
 
@@ -39,7 +42,7 @@ impl Position {
     }
 }
 
-impl std::ops::Index<usize> for Position {
+impl core::ops::Index<usize> for Position {
     type Output = i32;
 
     fn index(&self, i: usize) -> &i32 {
@@ -53,7 +56,7 @@ impl std::ops::Index<usize> for Position {
     }
 }
 
-impl std::ops::Add<(i32, i32, i32)> for Position {
+impl core::ops::Add<(i32, i32, i32)> for Position {
     type Output = Self;
 
     fn add(self, other: (i32, i32, i32)) -> Self {
@@ -61,7 +64,7 @@ impl std::ops::Add<(i32, i32, i32)> for Position {
     }
 }
 
-impl std::ops::Add<Self> for Position {
+impl core::ops::Add<Self> for Position {
     type Output = Self;
 
     fn add(self, other: Self) -> Self {
@@ -69,7 +72,7 @@ impl std::ops::Add<Self> for Position {
     }
 }
 
-impl std::ops::Sub<Self> for Position {
+impl core::ops::Sub<Self> for Position {
     type Output = Self;
 
     fn sub(self, other: Self) -> Self {
@@ -77,7 +80,7 @@ impl std::ops::Sub<Self> for Position {
     }
 }
 
-impl std::ops::IndexMut<usize> for Position {
+impl core::ops::IndexMut<usize> for Position {
     fn index_mut(&mut self, i: usize) -> &mut i32 {
         #![allow(clippy::panic)]
         match i {
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 #[derive(Copy, Clone, PartialEq)]
 enum AttackType {
@@ -193,7 +195,7 @@ fn execute_battle(mut groups: Vec<ArmyGroup>) -> Vec<ArmyGroup> {
 
         // Attacking.
         let mut any_killed_units = false;
-        groups.sort_unstable_by_key(|b| std::cmp::Reverse(b.initiative));
+        groups.sort_unstable_by_key(|b| core::cmp::Reverse(b.initiative));
         for i in 0..groups.len() {
             let (attacking_group_id, is_alive, effective_power, attack_type) = {
                 let g = &groups[i];
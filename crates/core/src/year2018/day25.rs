@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     let mut points: Vec<(i32, i32, i32, i32, usize)> = input
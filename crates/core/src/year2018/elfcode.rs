@@ -1,11 +1,16 @@
-#[derive(Copy, Clone, PartialEq, Eq)]
+use crate::common::vm::{Machine, Step};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Registers {
     pub values: [u64; 6],
 }
 
 #[derive(Copy, Clone)]
 pub struct Instruction {
-    opcode: Opcode,
+    pub opcode: Opcode,
     pub a: u64,
     pub b: u64,
     pub c: u64,
@@ -42,17 +47,6 @@ impl Program {
         Ok(true)
     }
 
-    pub fn execute_until_halt(&mut self, max_instructions: u32) -> Result<u64, String> {
-        let mut loop_count = 0;
-        while self.execute_one_instruction()? {
-            loop_count += 1;
-            if loop_count > max_instructions {
-                return Err(format!("Aborting after {max_instructions} instructions"));
-            }
-        }
-        Ok(self.registers.values[0])
-    }
-
     pub fn parse(input_string: &str) -> Result<Self, String> {
         let mut lines = input_string.lines();
         let first_line = lines.next().ok_or("Empty input")?;
@@ -82,31 +76,22 @@ impl Program {
             registers: Registers::new(),
         })
     }
+}
 
-    pub fn optimize(&mut self) {
-        for (line, instruction) in self.instructions.iter_mut().enumerate() {
-            match instruction.opcode {
-                Opcode::Addi if instruction.a as u8 == self.instruction_pointer_index => {
-                    instruction.opcode = Opcode::Seti;
-                    instruction.a = line as u64 + instruction.b;
-                    instruction.b = 0; // ignored
-                }
-                Opcode::Mulr
-                    if instruction.a as u8 == self.instruction_pointer_index
-                        && instruction.b as u8 == self.instruction_pointer_index =>
-                {
-                    instruction.opcode = Opcode::Seti;
-                    instruction.a = line as u64 * line as u64;
-                    instruction.b = 0; // ignored
-                }
-                Opcode::Muli if instruction.a as u8 == self.instruction_pointer_index => {
-                    instruction.opcode = Opcode::Seti;
-                    instruction.a = line as u64 * instruction.b;
-                    instruction.b = 0; // ignored
-                }
-                _ => {}
-            }
-        }
+impl Machine for Program {
+    type Output = ();
+    type State = Registers;
+
+    fn step(&mut self) -> Result<Step<()>, String> {
+        Ok(if self.execute_one_instruction()? {
+            Step::Executed
+        } else {
+            Step::Halted
+        })
+    }
+
+    fn state(&self) -> Registers {
+        self.registers
     }
 }
 
@@ -130,6 +115,36 @@ pub enum Opcode {
     Eqrr, // (equal register/register) sets register C to 1 if register A is equal to register B. Otherwise, register C is set to 0.
 }
 
+impl Opcode {
+    /// If input A is a register (as opposed to an immediate value or ignored).
+    pub const fn reads_register_a(self) -> bool {
+        !matches!(self, Self::Seti | Self::Gtir | Self::Eqir)
+    }
+
+    /// If input B is a register (as opposed to an immediate value or ignored).
+    pub const fn reads_register_b(self) -> bool {
+        matches!(
+            self,
+            Self::Addr
+                | Self::Mulr
+                | Self::Banr
+                | Self::Borr
+                | Self::Gtir
+                | Self::Gtrr
+                | Self::Eqir
+                | Self::Eqrr
+        )
+    }
+
+    /// If the result is always either 0 or 1.
+    pub const fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            Self::Gtir | Self::Gtri | Self::Gtrr | Self::Eqir | Self::Eqri | Self::Eqrr
+        )
+    }
+}
+
 fn opcode_from_str(name: &str) -> Result<Opcode, String> {
     Ok(match name {
         "addr" => Opcode::Addr,
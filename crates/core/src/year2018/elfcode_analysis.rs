@@ -0,0 +1,629 @@
+//! Static analysis of elfcode programs.
+//!
+//! Builds a control flow graph by resolving writes to the instruction pointer register,
+//! and recognizes common idioms which are executed as a whole instead of instruction
+//! by instruction.
+use super::elfcode::{Instruction, Opcode, Program};
+use crate::common::number_theory::divisor_sum;
+use crate::common::vm::Budget;
+use alloc::format;
+use alloc::string::String;
+#[cfg(any(test, feature = "debug-output"))]
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+const NUM_REGISTERS: u64 = 6;
+
+/// The largest number whose divisors are summed directly, taking up to 2^24 steps.
+const MAX_DIVISOR_SUM_TARGET: u64 = 1 << 48;
+
+/// How control continues after an instruction has been executed.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Flow {
+    /// Continues with the next instruction.
+    Next,
+    /// Unconditional jump to the given instruction (which halts if outside the program).
+    Jump(usize),
+    /// Skips the next instruction if the given register, holding a comparison result, is 1.
+    SkipIf(u8),
+    /// Jump to a target which cannot be determined statically.
+    Indirect,
+}
+
+/// A sequence of instructions only entered at the start and only left at the end.
+#[cfg(any(test, feature = "debug-output"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BasicBlock {
+    pub start: usize,
+    /// Exclusive end of the block.
+    pub end: usize,
+    /// Statically known successor blocks, identified by start instruction.
+    pub successors: Vec<usize>,
+}
+
+/// A recognized instruction pattern with a known effect.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Idiom {
+    /// Nested loops computing `accumulator += sum of divisors of target`:
+    ///
+    /// ```text
+    /// for outer in 1..=target {
+    ///     for inner in 1..=target {
+    ///         if outer * inner == target { accumulator += outer; }
+    ///     }
+    /// }
+    /// ```
+    DivisorSum {
+        entry: usize,
+        exit: usize,
+        target: u8,
+        accumulator: u8,
+        outer: u8,
+        inner: u8,
+        condition: u8,
+    },
+    /// Loop finding the smallest `quotient` such that `(quotient + 1) * divisor > dividend`,
+    /// that is, computing `quotient = dividend / divisor`, entered after clearing `quotient`.
+    DivisionLoop {
+        start: usize,
+        quotient: u8,
+        dividend: u8,
+        divisor: u64,
+    },
+    /// An instruction always setting a register to zero.
+    RegisterClear { at: usize, register: u8 },
+}
+
+impl Idiom {
+    /// The instruction at which the idiom may be executed as a whole.
+    const fn shortcut_at(&self) -> Option<usize> {
+        match *self {
+            Self::DivisorSum { entry, .. } => Some(entry),
+            Self::DivisionLoop { start, .. } => Some(start),
+            Self::RegisterClear { .. } => None,
+        }
+    }
+}
+
+pub struct Analysis {
+    // Only kept for rendering pseudo-code:
+    #[cfg(any(test, feature = "debug-output"))]
+    pub flows: Vec<Flow>,
+    #[cfg(any(test, feature = "debug-output"))]
+    pub blocks: Vec<BasicBlock>,
+    pub idioms: Vec<Idiom>,
+    /// Index into `idioms` for instructions where an idiom can be executed as a whole.
+    shortcuts: Vec<Option<usize>>,
+}
+
+impl Analysis {
+    pub fn new(program: &Program) -> Self {
+        let flows = program
+            .instructions
+            .iter()
+            .enumerate()
+            .map(|(pc, instruction)| flow_of(program, pc, instruction))
+            .collect::<Vec<_>>();
+
+        // Register clears are recognized first, as other idioms may depend on them:
+        let mut idioms = program
+            .instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(pc, instruction)| register_clear_at(program, pc, instruction))
+            .collect::<Vec<_>>();
+        for pc in 0..program.instructions.len() {
+            if let Some(idiom) = divisor_sum_at(program, &flows, pc)
+                .or_else(|| division_loop_at(program, &flows, &idioms, pc))
+            {
+                idioms.push(idiom);
+            }
+        }
+
+        let mut shortcuts = vec![None; program.instructions.len()];
+        for (idiom_idx, idiom) in idioms.iter().enumerate() {
+            if let Some(pc) = idiom.shortcut_at() {
+                shortcuts[pc] = Some(idiom_idx);
+            }
+        }
+
+        Self {
+            #[cfg(any(test, feature = "debug-output"))]
+            blocks: basic_blocks(&flows),
+            #[cfg(any(test, feature = "debug-output"))]
+            flows,
+            idioms,
+            shortcuts,
+        }
+    }
+
+    /// Returns the instructions reading the specified register.
+    pub fn readers_of(program: &Program, register: u8) -> impl Iterator<Item = usize> + '_ {
+        let register = u64::from(register);
+        program
+            .instructions
+            .iter()
+            .enumerate()
+            .filter(move |(_, instruction)| {
+                (instruction.opcode.reads_register_a() && instruction.a == register)
+                    || (instruction.opcode.reads_register_b() && instruction.b == register)
+            })
+            .map(|(pc, _)| pc)
+    }
+
+    /// Executes one instruction, or a whole recognized idiom starting at the current instruction.
+    ///
+    /// Returns false if the program has halted.
+    pub fn execute_one_step(&self, program: &mut Program) -> Result<bool, String> {
+        let ip = program.instruction_pointer()?;
+        if let Some(&Some(idiom_idx)) = self.shortcuts.get(ip as usize) {
+            apply_idiom(program, &self.idioms[idiom_idx])?;
+        }
+        program.execute_one_instruction()
+    }
+
+    pub fn execute_until_halt(
+        &self,
+        program: &mut Program,
+        budget: &mut Budget,
+    ) -> Result<u64, String> {
+        loop {
+            budget.consume()?;
+            if !self.execute_one_step(program)? {
+                return Ok(program.registers.values[0]);
+            }
+        }
+    }
+
+    /// Renders the program as pseudo-code, one basic block at a time.
+    #[cfg(any(test, feature = "debug-output"))]
+    pub fn pseudo_code(&self, program: &Program) -> String {
+        use core::fmt::Write;
+
+        let mut result = String::new();
+        for block in &self.blocks {
+            let _ = writeln!(result, "block {} -> {:?}", block.start, block.successors);
+            for pc in block.start..block.end {
+                for idiom in &self.idioms {
+                    if let Some(comment) = idiom_comment(idiom, pc) {
+                        let _ = writeln!(result, "    // {comment}");
+                    }
+                }
+                let statement = statement(program, self.flows[pc], pc);
+                let _ = writeln!(result, "{pc:4}: {statement}");
+            }
+        }
+        result
+    }
+}
+
+fn flow_of(program: &Program, pc: usize, instruction: &Instruction) -> Flow {
+    let ip = u64::from(program.instruction_pointer_index);
+    if instruction.c != ip {
+        return Flow::Next;
+    }
+    let pc_value = pc as u64;
+    let target = |value: Option<u64>| {
+        value
+            .and_then(|v| v.checked_add(1))
+            .map_or(Flow::Indirect, |v| Flow::Jump(v as usize))
+    };
+    match instruction.opcode {
+        Opcode::Seti => target(Some(instruction.a)),
+        Opcode::Setr if instruction.a == ip => Flow::Jump(pc + 1),
+        Opcode::Addi if instruction.a == ip => target(pc_value.checked_add(instruction.b)),
+        Opcode::Muli if instruction.a == ip => target(pc_value.checked_mul(instruction.b)),
+        Opcode::Addr | Opcode::Mulr if instruction.a == ip && instruction.b == ip => {
+            if instruction.opcode == Opcode::Addr {
+                target(pc_value.checked_add(pc_value))
+            } else {
+                target(pc_value.checked_mul(pc_value))
+            }
+        }
+        Opcode::Addr if instruction.a == ip || instruction.b == ip => {
+            let condition = if instruction.a == ip {
+                instruction.b
+            } else {
+                instruction.a
+            };
+            let is_boolean = pc > 0 && {
+                let previous = &program.instructions[pc - 1];
+                previous.c == condition && previous.opcode.is_comparison()
+            };
+            if is_boolean && condition < NUM_REGISTERS {
+                Flow::SkipIf(condition as u8)
+            } else {
+                Flow::Indirect
+            }
+        }
+        _ => Flow::Indirect,
+    }
+}
+
+#[cfg(any(test, feature = "debug-output"))]
+fn basic_blocks(flows: &[Flow]) -> Vec<BasicBlock> {
+    let len = flows.len();
+    let mut leaders = vec![false; len + 1];
+    leaders[0] = true;
+    for (pc, flow) in flows.iter().enumerate() {
+        match *flow {
+            Flow::Next => {}
+            Flow::Jump(target) => {
+                leaders[target.min(len)] = true;
+                leaders[pc + 1] = true;
+            }
+            Flow::SkipIf(_) => {
+                leaders[pc + 1] = true;
+                leaders[(pc + 2).min(len)] = true;
+            }
+            Flow::Indirect => {
+                leaders[pc + 1] = true;
+            }
+        }
+    }
+
+    let mut blocks = Vec::new();
+    let mut start = 0;
+    for end in (1..=len).filter(|&end| leaders[end]) {
+        let last = end - 1;
+        let successors = match flows[last] {
+            Flow::Next => vec![end],
+            Flow::Jump(target) => vec![target],
+            Flow::SkipIf(_) => vec![last + 1, last + 2],
+            Flow::Indirect => Vec::new(),
+        };
+        blocks.push(BasicBlock {
+            start,
+            end,
+            successors: successors.into_iter().filter(|&s| s < len).collect(),
+        });
+        start = end;
+    }
+    blocks
+}
+
+/// Returns the loops in the program as (header, latch) pairs of backwards jumps.
+fn back_edges(flows: &[Flow]) -> impl Iterator<Item = (usize, usize)> + '_ {
+    flows
+        .iter()
+        .enumerate()
+        .filter_map(|(pc, flow)| match *flow {
+            Flow::Jump(target) if target <= pc => Some((target, pc)),
+            _ => None,
+        })
+}
+
+fn register_operand(value: u64, ip: u64) -> Option<u8> {
+    (value < NUM_REGISTERS && value != ip).then_some(value as u8)
+}
+
+/// The last instruction before `pc` writing to `register`.
+fn last_write_before(program: &Program, pc: usize, register: u8) -> Option<usize> {
+    (0..pc)
+        .rev()
+        .find(|&i| program.instructions[i].c == u64::from(register))
+}
+
+fn writes_in(program: &Program, range: core::ops::RangeInclusive<usize>, register: u8) -> usize {
+    program.instructions[range]
+        .iter()
+        .filter(|instruction| instruction.c == u64::from(register))
+        .count()
+}
+
+fn divisor_sum_at(program: &Program, flows: &[Flow], pc: usize) -> Option<Idiom> {
+    let ip = u64::from(program.instruction_pointer_index);
+    let multiplication = program.instructions.get(pc)?;
+    let comparison = program.instructions.get(pc + 1)?;
+    if multiplication.opcode != Opcode::Mulr || comparison.opcode != Opcode::Eqrr {
+        return None;
+    }
+    let outer = register_operand(multiplication.a, ip)?;
+    let inner = register_operand(multiplication.b, ip)?;
+    let product = register_operand(multiplication.c, ip)?;
+    let target = if comparison.a == u64::from(product) {
+        register_operand(comparison.b, ip)?
+    } else if comparison.b == u64::from(product) {
+        register_operand(comparison.a, ip)?
+    } else {
+        return None;
+    };
+    if outer == inner || product == target || [outer, inner].contains(&target) {
+        return None;
+    }
+
+    // The multiplication is commutative, so decide which factor is the outer loop
+    // counter by which one is initialized first:
+    let init_a = last_write_before(program, pc, outer)?;
+    let init_b = last_write_before(program, pc, inner)?;
+    let (outer, inner, entry, inner_entry) = if init_a < init_b {
+        (outer, inner, init_a, init_b)
+    } else {
+        (inner, outer, init_b, init_a)
+    };
+    for init in [entry, inner_entry] {
+        let instruction = &program.instructions[init];
+        if instruction.opcode != Opcode::Seti || instruction.a != 1 {
+            return None;
+        }
+    }
+
+    let (_, latch) = back_edges(flows)
+        .filter(|&(header, latch)| header > entry && header <= inner_entry && latch > pc)
+        .max_by_key(|&(_, latch)| latch)?;
+    let body = entry..=latch;
+
+    let is_bound_check = |counter: u8| {
+        program.instructions[body.clone()].iter().find(|i| {
+            i.opcode == Opcode::Gtrr
+                && i.a == u64::from(counter)
+                && i.b == u64::from(target)
+                && i.c < NUM_REGISTERS
+        })
+    };
+    let outer_check = is_bound_check(outer)?;
+    is_bound_check(inner)?;
+    let condition = outer_check.c as u8;
+
+    let accumulation = program.instructions[body.clone()].iter().find(|i| {
+        i.opcode == Opcode::Addr
+            && i.c != ip
+            && i.c < NUM_REGISTERS
+            && ((i.a == u64::from(outer) && i.b == i.c) || (i.b == u64::from(outer) && i.a == i.c))
+    })?;
+    let accumulator = accumulation.c as u8;
+    if [outer, inner, product, target, condition].contains(&accumulator) {
+        return None;
+    }
+
+    if writes_in(program, body.clone(), target) != 0
+        || writes_in(program, body.clone(), accumulator) != 1
+        || flows[body].contains(&Flow::Indirect)
+    {
+        return None;
+    }
+
+    Some(Idiom::DivisorSum {
+        entry,
+        exit: latch + 1,
+        target,
+        accumulator,
+        outer,
+        inner,
+        condition,
+    })
+}
+
+fn division_loop_at(
+    program: &Program,
+    flows: &[Flow],
+    idioms: &[Idiom],
+    pc: usize,
+) -> Option<Idiom> {
+    let ip = u64::from(program.instruction_pointer_index);
+    let increment = program.instructions.get(pc)?;
+    let multiplication = program.instructions.get(pc + 1)?;
+    let comparison = program.instructions.get(pc + 2)?;
+    if increment.opcode != Opcode::Addi
+        || increment.b != 1
+        || multiplication.opcode != Opcode::Muli
+        || multiplication.a != increment.c
+        || multiplication.c != increment.c
+        || multiplication.b == 0
+        || comparison.opcode != Opcode::Gtrr
+        || comparison.a != increment.c
+    {
+        return None;
+    }
+    let quotient = register_operand(increment.a, ip)?;
+    let scratch = register_operand(increment.c, ip)?;
+    let dividend = register_operand(comparison.b, ip)?;
+    register_operand(comparison.c, ip)?;
+    if quotient == scratch || dividend == scratch || dividend == quotient {
+        return None;
+    }
+    // The quotient counts up from zero, so that the loop computes the whole quotient:
+    let clear = Idiom::RegisterClear {
+        at: pc.checked_sub(1)?,
+        register: quotient,
+    };
+    if !idioms.contains(&clear) {
+        return None;
+    }
+
+    let (_, latch) = back_edges(flows).find(|&(header, _)| header == pc)?;
+    let body = pc..=latch;
+    let increments_quotient = program.instructions[body.clone()]
+        .iter()
+        .any(|i| i.opcode == Opcode::Addi && i.a == u64::from(quotient) && i.b == 1 && i.c == i.a);
+    if !increments_quotient
+        || writes_in(program, body.clone(), quotient) != 1
+        || writes_in(program, body.clone(), dividend) != 0
+        || flows[body].contains(&Flow::Indirect)
+    {
+        return None;
+    }
+
+    Some(Idiom::DivisionLoop {
+        start: pc,
+        quotient,
+        dividend,
+        divisor: multiplication.b,
+    })
+}
+
+fn register_clear_at(program: &Program, pc: usize, instruction: &Instruction) -> Option<Idiom> {
+    let register = register_operand(instruction.c, u64::from(program.instruction_pointer_index))?;
+    let is_clear = match instruction.opcode {
+        Opcode::Seti => instruction.a == 0,
+        Opcode::Muli | Opcode::Bani => instruction.b == 0,
+        Opcode::Gtir => instruction.a == 0,
+        _ => false,
+    };
+    is_clear.then_some(Idiom::RegisterClear { at: pc, register })
+}
+
+fn apply_idiom(program: &mut Program, idiom: &Idiom) -> Result<(), String> {
+    let registers = &mut program.registers.values;
+    match *idiom {
+        Idiom::DivisorSum {
+            exit,
+            target,
+            accumulator,
+            outer,
+            inner,
+            condition,
+            ..
+        } => {
+            let n = registers[target as usize];
+            if n > MAX_DIVISOR_SUM_TARGET {
+                return Err(format!("Too big number to sum divisors of: {n}"));
+            }
+            registers[accumulator as usize] = divisor_sum(n)
+                .and_then(|sum| registers[accumulator as usize].checked_add(sum))
+                .ok_or("Overflow in divisor sum")?;
+            registers[outer as usize] = n + 1;
+            registers[inner as usize] = n + 1;
+            registers[condition as usize] = 1;
+            // Continue at the exit, where the next executed instruction will be the one there:
+            program.registers.values[program.instruction_pointer_index as usize] = exit as u64;
+        }
+        Idiom::DivisionLoop {
+            quotient,
+            dividend,
+            divisor,
+            ..
+        } => {
+            // Leave one iteration, the exiting one, for the interpreter:
+            registers[quotient as usize] = registers[dividend as usize] / divisor;
+        }
+        Idiom::RegisterClear { register, .. } => {
+            registers[register as usize] = 0;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(any(test, feature = "debug-output"))]
+fn idiom_comment(idiom: &Idiom, pc: usize) -> Option<String> {
+    match *idiom {
+        Idiom::DivisorSum {
+            entry,
+            exit,
+            target,
+            accumulator,
+            ..
+        } if entry == pc => Some(format!(
+            "r{accumulator} += sum of divisors of r{target}, continuing at {exit}"
+        )),
+        Idiom::DivisionLoop {
+            start,
+            quotient,
+            dividend,
+            divisor,
+        } if start == pc => Some(format!("r{quotient} = r{dividend} / {divisor}")),
+        Idiom::RegisterClear { at, register } if at == pc => Some(format!("r{register} = 0")),
+        _ => None,
+    }
+}
+
+#[cfg(any(test, feature = "debug-output"))]
+fn statement(program: &Program, flow: Flow, pc: usize) -> String {
+    let instruction = &program.instructions[pc];
+    let ip = u64::from(program.instruction_pointer_index);
+    let register = |r: u64| {
+        if r == ip {
+            format!("{pc}")
+        } else {
+            format!("r{r}")
+        }
+    };
+    let (a, b) = (instruction.a, instruction.b);
+    let (ra, rb) = (register(a), register(b));
+    let expression = match instruction.opcode {
+        Opcode::Addr => format!("{ra} + {rb}"),
+        Opcode::Addi => format!("{ra} + {b}"),
+        Opcode::Mulr => format!("{ra} * {rb}"),
+        Opcode::Muli => format!("{ra} * {b}"),
+        Opcode::Banr => format!("{ra} & {rb}"),
+        Opcode::Bani => format!("{ra} & {b}"),
+        Opcode::Borr => format!("{ra} | {rb}"),
+        Opcode::Bori => format!("{ra} | {b}"),
+        Opcode::Setr => ra,
+        Opcode::Seti => format!("{a}"),
+        Opcode::Gtir => format!("{a} > {rb}"),
+        Opcode::Gtri => format!("{ra} > {b}"),
+        Opcode::Gtrr => format!("{ra} > {rb}"),
+        Opcode::Eqir => format!("{a} == {rb}"),
+        Opcode::Eqri => format!("{ra} == {b}"),
+        Opcode::Eqrr => format!("{ra} == {rb}"),
+    };
+    match flow {
+        Flow::Next => format!("r{} = {expression}", instruction.c),
+        Flow::Jump(target) if target >= program.instructions.len() => "halt".to_string(),
+        Flow::Jump(target) => format!("goto {target}"),
+        Flow::SkipIf(condition) => format!("if r{condition} goto {}", pc + 2),
+        Flow::Indirect => format!("goto ({expression}) + 1"),
+    }
+}
+
+#[test]
+fn tests() {
+    let program = Program::parse(include_str!("day19_input.txt")).unwrap();
+    let analysis = Analysis::new(&program);
+    assert!(analysis.idioms.contains(&Idiom::DivisorSum {
+        entry: 1,
+        exit: 16,
+        target: 2,
+        accumulator: 0,
+        outer: 4,
+        inner: 1,
+        condition: 3,
+    }));
+    assert_eq!(analysis.flows[0], Flow::Jump(17));
+    assert_eq!(analysis.flows[5], Flow::SkipIf(3));
+    assert_eq!(analysis.flows[16], Flow::Jump(257));
+    let pseudo_code = analysis.pseudo_code(&program);
+    assert!(pseudo_code.contains("   0: goto 17\n"));
+    assert!(pseudo_code.contains("   3: r3 = r4 * r1\n"));
+    assert!(pseudo_code.contains("r0 += sum of divisors of r2, continuing at 16"));
+    assert!(pseudo_code.contains("  16: halt\n"));
+
+    // Numbers too big to sum the divisors of directly, as in fuzzed programs:
+    let mut program = Program::parse(include_str!("day19_input.txt")).unwrap();
+    program.registers.values[2] = u64::MAX;
+    program.registers.values[program.instruction_pointer_index as usize] = 1;
+    assert_eq!(
+        analysis.execute_one_step(&mut program),
+        Err("Too big number to sum divisors of: 18446744073709551615".to_string())
+    );
+
+    let program = Program::parse(include_str!("day21_input.txt")).unwrap();
+    let analysis = Analysis::new(&program);
+    assert!(analysis.idioms.contains(&Idiom::DivisionLoop {
+        start: 18,
+        quotient: 4,
+        dividend: 2,
+        divisor: 256,
+    }));
+    assert!(analysis.idioms.contains(&Idiom::RegisterClear {
+        at: 17,
+        register: 4
+    }));
+    assert!(
+        analysis
+            .pseudo_code(&program)
+            .contains("    // r4 = 0\n  17: r4 = 0\n")
+    );
+    assert_eq!(
+        vec![28],
+        Analysis::readers_of(&program, 0).collect::<Vec<_>>()
+    );
+    assert!(
+        analysis
+            .blocks
+            .iter()
+            .any(|block| block.start == 18 && block.end == 22 && block.successors == [22, 23])
+    );
+}
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 fn sum_required_fuel(input_string: &str, fuel_calculator: fn(u32) -> u32) -> Result<u32, String> {
     input_string
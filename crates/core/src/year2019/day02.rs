@@ -1,6 +1,8 @@
 use super::int_code::Program;
 use super::int_code::Word;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<Word, String> {
     const DESIRED_OUTPUT: Word = 19_690_720;
@@ -1,6 +1,9 @@
 use crate::input::Input;
-use std::cmp;
-use std::ops;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp;
+use core::ops;
 
 #[derive(PartialEq, Eq, Copy, Clone)]
 struct Vector {
@@ -145,8 +148,8 @@ fn parse_wire_points(
                 *current_position += direction.multiply(steps);
 
                 let top_left = Vector {
-                    x: std::cmp::min(start_position.x, current_position.x),
-                    y: std::cmp::min(start_position.y, current_position.y),
+                    x: core::cmp::min(start_position.x, current_position.x),
+                    y: core::cmp::min(start_position.y, current_position.y),
                 };
 
                 let incoming_direction = top_left != start_position;
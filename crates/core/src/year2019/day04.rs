@@ -1,5 +1,6 @@
 use crate::input::Input;
-use std::cmp::Ordering;
+use alloc::string::{String, ToString};
+use core::cmp::Ordering;
 
 fn parse_input(input_string: &str) -> Result<(i32, i32), String> {
     let (from_str, to_str) = input_string
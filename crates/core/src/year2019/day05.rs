@@ -1,6 +1,7 @@
 use super::int_code::Program;
 use super::int_code::Word;
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<Word, String> {
     let mut program = Program::parse(input.text)?;
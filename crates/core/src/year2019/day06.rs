@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::{HashMap, HashSet, VecDeque};
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     fn checksum(map: &HashMap<&str, Vec<&str>>, name: &str, depth: u32) -> u32 {
@@ -1,7 +1,9 @@
 use super::int_code::{Program, Word};
 use crate::common::permutation::all_permutations;
 use crate::input::Input;
-use std::cell::RefCell;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cell::RefCell;
 
 pub fn solve(input: &Input) -> Result<i64, String> {
     let program = Program::parse(input.text)?;
@@ -44,7 +46,7 @@ pub fn solve(input: &Input) -> Result<i64, String> {
             }
         }
 
-        strongest_signal = std::cmp::max(strongest_signal, last_signal_output);
+        strongest_signal = core::cmp::max(strongest_signal, last_signal_output);
         Ok(())
     })?;
 
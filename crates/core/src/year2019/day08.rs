@@ -1,5 +1,8 @@
 use crate::common::character_recognition::{CHAR_HEIGHT, CHAR_WIDTH, recognize};
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 const NUM_LETTERS: usize = 5;
 const PIXELS_WIDE: usize = NUM_LETTERS * CHAR_WIDTH;
@@ -43,7 +46,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
         });
 
         let image_bytes = image.iter_mut().map(|b| *b == b'1').collect::<Vec<_>>();
-        recognize(&image_bytes)
+        recognize(input, &image_bytes)
     }
 }
 
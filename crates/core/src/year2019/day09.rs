@@ -1,5 +1,7 @@
 use super::int_code::Program;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let mut program = Program::parse(input.text)?;
@@ -1,15 +1,10 @@
+use crate::common::number_theory::gcd;
 use crate::input::Input;
-use std::cmp::Ordering;
-use std::collections::{HashMap, HashSet};
-
-pub const fn gcd(mut a: i64, mut b: i64) -> i64 {
-    while b != 0 {
-        let tmp = a;
-        a = b;
-        b = tmp % b;
-    }
-    a
-}
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use hashbrown::{HashMap, HashSet};
 
 pub fn parse_points(input_string: &str) -> Result<Vec<(usize, usize)>, String> {
     for c in input_string.chars() {
@@ -50,7 +45,7 @@ pub fn determine_station(points: &[(usize, usize)]) -> Result<(usize, (usize, us
                 .fold(HashSet::new(), |mut seen, other_point| {
                     let mut distance_x = other_point.0 as i64 - this_point.0 as i64;
                     let mut distance_y = other_point.1 as i64 - this_point.1 as i64;
-                    let divisor = gcd(distance_x.abs(), distance_y.abs());
+                    let divisor = gcd(distance_x.unsigned_abs(), distance_y.unsigned_abs()) as i64;
                     distance_x /= divisor;
                     distance_y /= divisor;
                     seen.insert((distance_x, distance_y));
@@ -81,7 +76,7 @@ fn part2_nth(input_string: &str, nth: u32) -> Result<(i64, i64), String> {
     for &(x, y) in points.iter().filter(|&&p| p != base_location) {
         let distance_x = x as i64 - base_location.0 as i64;
         let distance_y = y as i64 - base_location.1 as i64;
-        let divisor = gcd(distance_x.abs(), distance_y.abs());
+        let divisor = gcd(distance_x.unsigned_abs(), distance_y.unsigned_abs()) as i64;
         let direction_x = distance_x / divisor;
         let direction_y = distance_y / divisor;
 
@@ -103,10 +98,10 @@ fn part2_nth(input_string: &str, nth: u32) -> Result<(i64, i64), String> {
         // and negate value to get clockwise direction:
         // https://en.wikipedia.org/wiki/Atan2#/media/File:Atan2definition.svg
         let (x, y) = p1[0];
-        let a1 = -(x as f64).atan2(y as f64);
+        let a1 = -libm::atan2(x as f64, y as f64);
 
         let (x, y) = p2[0];
-        let a2 = -(x as f64).atan2(y as f64);
+        let a2 = -libm::atan2(x as f64, y as f64);
 
         a1.partial_cmp(&a2).unwrap_or(Ordering::Equal)
     });
@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use alloc::string::{String, ToString};
+use alloc::{format, vec};
+use hashbrown::HashMap;
 
 use crate::common::character_recognition::recognize;
 use crate::input::Input;
@@ -108,11 +110,11 @@ pub fn solve(input: &Input) -> Result<String, String> {
         let mut max_y = i32::MIN;
         for (&(x, y), color) in painted.iter() {
             if *color == Color::White {
-                min_x = std::cmp::min(min_x, x);
+                min_x = core::cmp::min(min_x, x);
                 // Add one column for end of last letter:
-                max_x = std::cmp::max(max_x, x + 1);
-                min_y = std::cmp::min(min_y, y);
-                max_y = std::cmp::max(max_y, y);
+                max_x = core::cmp::max(max_x, x + 1);
+                min_y = core::cmp::min(min_y, y);
+                max_y = core::cmp::max(max_y, y);
             }
         }
 
@@ -124,7 +126,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
                     true;
             }
         }
-        recognize(&screen)
+        recognize(input, &screen)
     }
 }
 
@@ -1,4 +1,8 @@
+use crate::common::number_theory::lcm;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 #[derive(Clone)]
 struct Moons {
@@ -6,23 +10,6 @@ struct Moons {
     velocities: [[i32; 3]; 4],
 }
 
-pub const fn gcd(mut a: u64, mut b: u64) -> u64 {
-    while b != 0 {
-        let tmp = a;
-        a = b;
-        b = tmp % b;
-    }
-    a
-}
-
-pub const fn lcd(a: u64, b: u64) -> u64 {
-    a * b / gcd(a, b)
-}
-
-pub const fn lcd3(a: u64, b: u64, c: u64) -> u64 {
-    lcd(a, lcd(b, c))
-}
-
 impl Moons {
     fn parse(input: &str) -> Result<Self, String> {
         let mut positions = [[0; 3]; 4];
@@ -121,11 +108,11 @@ pub fn solve(input: &Input) -> Result<u64, String> {
             }
         }
 
-        Ok(lcd3(
-            cycles[0].ok_or("Cycles not found")?,
-            cycles[1].ok_or("Cycles not found")?,
-            cycles[2].ok_or("Cycles not found")?,
-        ))
+        let mut result = 1;
+        for cycle in cycles {
+            result = lcm(result, cycle.ok_or("Cycles not found")?).ok_or("Too long cycle")?;
+        }
+        Ok(result)
     }
 }
 
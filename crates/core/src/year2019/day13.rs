@@ -1,5 +1,6 @@
 use super::int_code::{Program, Word};
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<Word, String> {
     let mut program = Program::parse(input.text)?;
@@ -1,5 +1,8 @@
 use crate::common::id_assigner::IdAssigner;
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 type ChemicalId = usize;
 type ChemicalAmount = i64;
@@ -1,6 +1,9 @@
 use super::int_code::{Program, Word};
 use crate::input::Input;
-use std::collections::{HashSet, VecDeque};
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use hashbrown::HashSet;
 
 const DIRECTIONS: [(i32, i32); 4] = [(0, 1), (0, -1), (-1, 0), (1, 0)];
 
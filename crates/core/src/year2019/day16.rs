@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 fn parse_digits(input_string: &str) -> Result<Vec<i32>, String> {
     let result = input_string
@@ -34,7 +37,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
                 *digit = (positives - negatives).abs() % 10;
             }
 
-            std::mem::swap(&mut digits, &mut new_digits);
+            core::mem::swap(&mut digits, &mut new_digits);
         }
 
         Ok(digits
@@ -1,7 +1,10 @@
 use super::int_code::Program;
 use crate::input::Input;
-use std::collections::VecDeque;
-use std::slice::Iter;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::slice::Iter;
 
 fn part1_map(map: &str) -> Result<String, String> {
     let map: Vec<&[u8]> = map.trim().lines().map(str::as_bytes).collect();
@@ -1,5 +1,9 @@
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use hashbrown::{HashMap, HashSet};
 
 use crate::input::Input;
 
@@ -1,5 +1,7 @@
 use super::int_code::{Program, Word};
+use crate::common::progress;
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 fn affected_by_beam(program: &Program, x: i32, y: i32) -> Result<bool, String> {
     let mut program_copy = program.clone();
@@ -43,7 +45,9 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             while !affected_by_beam(&program, left_edge, bottom_edge)? {
                 left_edge += 1;
                 if left_edge >= MAX_COORDINATE {
-                    return Err(format!("Aborting after reaching x={MAX_COORDINATE}"));
+                    return Err(progress::budget_exceeded(format_args!(
+                        "reaching x={MAX_COORDINATE}"
+                    )));
                 }
             }
 
@@ -55,7 +59,9 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             }
         }
 
-        Err(format!("Aborting after reaching y={MAX_COORDINATE}"))
+        Err(progress::budget_exceeded(format_args!(
+            "reaching y={MAX_COORDINATE}"
+        )))
     }
 }
 
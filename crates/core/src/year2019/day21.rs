@@ -1,5 +1,8 @@
 use super::int_code::{Program, Word};
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 fn run(intcode_program_string: &str, ascii_program_string: &str) -> Result<Word, String> {
     let mut intcode_program = Program::parse(intcode_program_string)?;
@@ -12,7 +15,7 @@ fn run(intcode_program_string: &str, ascii_program_string: &str) -> Result<Word,
     } else {
         let output_bytes: Vec<u8> = program_output.iter().map(|&value| value as u8).collect();
         let output_string =
-            std::str::from_utf8(&output_bytes).map_err(|_| "Output is not utf-8")?;
+            core::str::from_utf8(&output_bytes).map_err(|_| "Output is not utf-8")?;
         Err(format!(
             "No non-ASCII value found - showing last moments:\n{output_string}"
         ))
@@ -1,6 +1,8 @@
 use super::int_code::{Program, Word};
 use crate::input::Input;
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
+use alloc::string::String;
+use alloc::vec;
 
 pub fn solve(input: &Input) -> Result<Word, String> {
     let program = Program::parse(input.text)?;
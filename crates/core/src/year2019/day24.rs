@@ -1,6 +1,8 @@
 use crate::input::Input;
-use std::collections::HashSet;
-use std::slice::Iter;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use core::slice::Iter;
+use hashbrown::HashSet;
 
 #[derive(Copy, Clone)]
 enum Direction {
@@ -171,7 +173,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             next_generation[i] =
                 this_grid.advance(current_generation[i - 1], current_generation[i + 1]);
         }
-        std::mem::swap(&mut current_generation, &mut next_generation);
+        core::mem::swap(&mut current_generation, &mut next_generation);
     }
 
     Ok(current_generation
@@ -1,4 +1,8 @@
-use std::collections::{HashSet, VecDeque};
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashSet;
 
 use crate::input::Input;
 
@@ -84,7 +88,7 @@ fn execute_command(program: &mut Program, command: Command) -> Result<Room, Stri
 fn parse_output(program: &mut Program) -> Result<Room, String> {
     let output = program.run_for_output()?;
     let output: Vec<u8> = output.iter().map(|&b| b as u8).collect();
-    let output = std::str::from_utf8(&output).map_err(|_| "Invalid input: Not utf-8")?;
+    let output = core::str::from_utf8(&output).map_err(|_| "Invalid input: Not utf-8")?;
 
     let mut directions = Vec::new();
     let mut items = Vec::new();
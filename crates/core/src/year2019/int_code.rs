@@ -1,4 +1,8 @@
-use std::collections::VecDeque;
+use crate::common::progress;
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub type Word = i64;
 
@@ -59,7 +63,9 @@ impl Program {
 
             current_instruction += 1;
             if current_instruction == max_instructions {
-                return Err(format!("Aborted after {max_instructions} instructions"));
+                return Err(progress::budget_exceeded(format_args!(
+                    "{max_instructions} instructions"
+                )));
             }
         }
         Ok(())
@@ -67,12 +73,12 @@ impl Program {
 
     pub fn run_for_output(&mut self) -> Result<Vec<Word>, String> {
         self.run_until_halt_or_input(1_000_000_000)?;
-        Ok(std::mem::take(&mut self.output_values))
+        Ok(core::mem::take(&mut self.output_values))
     }
 
     pub fn run_for_output_limited(&mut self, max_instructions: u32) -> Result<Vec<Word>, String> {
         self.run_until_halt_or_input(max_instructions)?;
-        Ok(std::mem::take(&mut self.output_values))
+        Ok(core::mem::take(&mut self.output_values))
     }
 
     pub fn input(&mut self, input_value: Word) {
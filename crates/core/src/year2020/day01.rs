@@ -1,5 +1,7 @@
 use crate::common::parser::parse_lines;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 use core::cmp::Ordering::{Equal, Greater, Less};
 
 fn subsequence_summing_to(sorted_sequence: &[u32], desired_sum: u32) -> Option<u32> {
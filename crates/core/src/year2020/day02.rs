@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut valid_passwords = 0;
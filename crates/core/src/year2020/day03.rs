@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 
 pub struct Map {
     pub rows: usize,
@@ -38,7 +41,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
     Ok(slopes.iter().fold(1, |acc, slope| {
         let initial_position = (0, 0);
 
-        let trees_seen_now = std::iter::successors(Some(initial_position), |pos| {
+        let trees_seen_now = core::iter::successors(Some(initial_position), |pos| {
             let new_pos = (pos.0 + slope.0, pos.1 + slope.1);
             if new_pos.1 < map.rows {
                 Some(new_pos)
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 fn is_valid(field_idx: usize, value: &str) -> bool {
     fn in_range(string: &str, start: u32, end: u32) -> bool {
@@ -32,7 +34,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
     let mut fields_validity = [false; 7];
     let mut valid_passports_count = 0;
 
-    for line in input.text.lines().chain(std::iter::once("")) {
+    for line in input.text.lines().chain(core::iter::once("")) {
         if line.is_empty() {
             if fields_validity.iter().all(|&ok| ok) {
                 valid_passports_count += 1;
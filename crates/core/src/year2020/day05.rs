@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
 
 type SeatId = u16;
 
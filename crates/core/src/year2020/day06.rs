@@ -1,5 +1,6 @@
 use crate::input::Input;
-use std::ops::{BitAnd, BitOr};
+use alloc::string::{String, ToString};
+use core::ops::{BitAnd, BitOr};
 
 type AnswersBitSet = u32;
 
@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::{HashMap, HashSet};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
 
 const MAX_DEPTH: u32 = 100;
 
@@ -1,10 +1,12 @@
 use crate::common::parser::parse_lines;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
 
 /// Search for a subsequence which sums to the desired sum.
 fn subsequence_summing_to<T>(sequence: &[T], desired_sum: T) -> Option<&[T]>
 where
-    T: std::ops::AddAssign + Copy + PartialEq + PartialOrd + std::ops::SubAssign,
+    T: core::ops::AddAssign + Copy + PartialEq + PartialOrd + core::ops::SubAssign,
 {
     let mut window_start = 0;
     let mut window_sum = sequence[window_start];
@@ -60,7 +62,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
             let (min, max) = subsequence
                 .iter()
                 .fold((u64::MAX, u64::MIN), |(min, max), &number| {
-                    (std::cmp::min(min, number), std::cmp::max(max, number))
+                    (core::cmp::min(min, number), core::cmp::max(max, number))
                 });
             min + max
         })
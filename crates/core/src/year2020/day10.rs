@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 type JoltageAmount = u64;
 
@@ -7,7 +10,7 @@ pub fn solve(input: &Input) -> Result<JoltageAmount, String> {
     // its rating and still produce its rated output joltage":
     const MAX_DIFF: JoltageAmount = 3;
 
-    let mut joltages = std::iter::once(Ok(0))
+    let mut joltages = core::iter::once(Ok(0))
         .chain(input.text.lines().enumerate().map(|(line_idx, line)| {
             line.parse::<JoltageAmount>().map_err(|parse_error| {
                 format!("Line {}: Invalid joltage - {}", line_idx + 1, parse_error)
@@ -1,4 +1,8 @@
+use crate::common::progress;
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     const MAX_ITERATIONS: u32 = 10_000;
@@ -108,7 +112,9 @@ pub fn solve(input: &Input) -> Result<usize, String> {
         } else {
             iteration += 1;
             if iteration >= MAX_ITERATIONS {
-                return Err(format!("Aborting after {iteration} iterations"));
+                return Err(progress::budget_exceeded(format_args!(
+                    "{iteration} iterations"
+                )));
             }
         }
     }
@@ -136,7 +142,6 @@ L.LLLLL.LL";
 #[cfg(feature = "count-allocations")]
 #[test]
 pub fn limited_memory_allocations() {
-    use crate::input::{test_part_one, test_part_two};
     let real_input = include_str!("day11_input.txt");
     let info = allocation_counter::measure(|| {
         test_part_one!(real_input => 2222);
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 pub const SHIP_POSITION_ENTITY_IDX: usize = 0;
 pub const SHIP_DIRECTION_ENTITY_IDX: usize = 1;
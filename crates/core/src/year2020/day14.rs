@@ -1,6 +1,9 @@
 use crate::input::Input;
-use std::collections::HashSet;
-use std::hash::{BuildHasherDefault, Hasher};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::hash::{BuildHasherDefault, Hasher};
+use hashbrown::HashSet;
 
 #[derive(Default)]
 struct CustomHash {
@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     const NEVER_SEEN: u32 = 0;
@@ -31,7 +34,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             return Err(format!("Too big number: {next_number}"));
         }
 
-        let last_spoken_turn = std::mem::replace(&mut value_to_turn[next_number as usize], turn);
+        let last_spoken_turn = core::mem::replace(&mut value_to_turn[next_number as usize], turn);
 
         next_number = if last_spoken_turn == NEVER_SEEN {
             // If that was the first time the number has been spoken, the current player says 0:
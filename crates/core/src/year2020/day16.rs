@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const MAX_FIELD_VALUE: u32 = 1024;
@@ -1,6 +1,7 @@
 use crate::input::Input;
-use std::collections::hash_map::Entry;
-use std::collections::{HashMap, HashSet};
+use alloc::string::String;
+use hashbrown::hash_map::Entry;
+use hashbrown::{HashMap, HashSet};
 
 type CoordinateComponent = i8;
 
@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::HashMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use hashbrown::HashMap;
 
 #[derive(Copy, Clone)]
 struct Edge {
@@ -247,7 +250,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
             .product());
     }
 
-    let composed_image_tile_width = (tiles.len() as f64).sqrt() as u8;
+    let composed_image_tile_width = tiles.len().isqrt() as u8;
     let composed_image_pixel_width = composed_image_tile_width * 8;
 
     let a_corner = *tiles
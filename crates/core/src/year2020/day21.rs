@@ -1,5 +1,7 @@
 use crate::input::Input;
-use std::collections::{HashMap, HashSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::{HashMap, HashSet};
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let on_error = || "Invalid input";
@@ -1,5 +1,7 @@
 use crate::input::Input;
-use std::collections::{HashSet, VecDeque};
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use hashbrown::HashSet;
 
 enum Winner {
     Player1,
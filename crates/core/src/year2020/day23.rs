@@ -1,5 +1,8 @@
 use crate::input::Input;
-use std::collections::HashSet;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use hashbrown::HashSet;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let number_of_cups = input.part_values(9, 1_000_000);
@@ -1,5 +1,6 @@
 use crate::input::Input;
-use std::collections::{HashMap, HashSet};
+use alloc::string::{String, ToString};
+use hashbrown::{HashMap, HashSet};
 
 /// Using double-width coordinates - see <https://www.redblobgames.com/grids/hexagons//>
 pub fn solve(input: &Input) -> Result<u64, String> {
@@ -76,7 +77,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
                 }
             }
 
-            std::mem::swap(&mut black_tiles, &mut new_black_tiles);
+            core::mem::swap(&mut black_tiles, &mut new_black_tiles);
         }
     }
 
@@ -1,6 +1,7 @@
+use crate::common::number_theory::{mod_exp, mul_mod};
 use crate::input::Input;
-use crate::mod_exp::mod_exp;
-use std::collections::HashMap;
+use alloc::string::{String, ToString};
+use hashbrown::HashMap;
 
 const MODULO: u64 = 20_201_227;
 
@@ -18,7 +19,7 @@ fn babystep_giantstep(public_key: u32) -> Option<u32> {
     let baby_table = (0..SQRT_MODULO_MINUS_ONE)
         .scan(1_u32, |table_key, table_value| {
             let entry = Some((*table_key, table_value));
-            *table_key = ((u64::from(*table_key) * BASE) % MODULO) as u32;
+            *table_key = mul_mod(u64::from(*table_key), BASE, MODULO) as u32;
             entry
         })
         .collect::<HashMap<u32, u32>>();
@@ -43,7 +44,7 @@ fn babystep_giantstep(public_key: u32) -> Option<u32> {
         .try_fold(public_key, |state, giant_step| {
             baby_table.get(&state).map_or_else(
                 // No entry - continue with new state `(state * FACTOR) % MODULO`:
-                || Ok(((u64::from(state) * FACTOR) % MODULO) as u32),
+                || Ok(mul_mod(u64::from(state), FACTOR, MODULO) as u32),
                 // We have found x in `BASE ^ x % MODULO = state`, where
                 //   state = (public_key * FACTOR ^ giant_step) % MODULO.
                 // Multiply with `SQRT_MODULO_MINUS_ONE` (of which is `FACTOR` is
@@ -82,7 +83,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         i128::from(door_public_key),
         i128::from(card_loop_size),
         i128::from(MODULO),
-    ) as u64;
+    )? as u64;
 
     Ok(encryption_key)
 }
@@ -1,5 +1,6 @@
 use crate::common::parser::parse_lines;
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     Ok(parse_lines::<u32>(input.text)?
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
 
 struct Board {
     // Bitsets keeping track of squares with coordinates in the range [0,1000].
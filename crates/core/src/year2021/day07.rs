@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 const fn part_1_fuel_consumption(distance: i32) -> i32 {
     distance
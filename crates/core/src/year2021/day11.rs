@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const MAX_STEPS_PART_TWO: usize = 100_000;
@@ -1,4 +1,5 @@
 use crate::common::id_assigner::IdAssigner;
+use alloc::string::{String, ToString};
 
 use crate::input::Input;
 
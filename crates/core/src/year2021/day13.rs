@@ -1,5 +1,7 @@
 use crate::common::character_recognition::{CHAR_HEIGHT, CHAR_WIDTH, recognize};
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     const NUM_LETTERS: usize = 8;
@@ -55,7 +57,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
         }
         screen[usize::from(y) * NUM_LETTERS * CHAR_WIDTH + usize::from(x)] = true;
     }
-    recognize(&screen)
+    recognize(input, &screen)
 }
 
 #[test]
@@ -1,5 +1,7 @@
 use crate::input::Input;
-use std::collections::HashMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashMap;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut lines = input.text.lines();
@@ -1,6 +1,9 @@
 use crate::input::Input;
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
+use alloc::collections::BinaryHeap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
 
 pub struct Graph {
     risk_levels: Vec<u8>,
@@ -85,9 +88,11 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         y: 0,
     }));
     graph.mark_visited(0, 0);
+    let mut max_frontier_size = to_visit.len();
 
     while let Some(Reverse(state)) = to_visit.pop() {
         if (state.x, state.y) == destination {
+            input.trace("max frontier size", max_frontier_size);
             return Ok(state.risk);
         }
 
@@ -108,6 +113,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
                 }));
             }
         }
+        max_frontier_size = max_frontier_size.max(to_visit.len());
     }
 
     Err("No solution found".to_string())
@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 /// Constructs a u16 with the lowest n bits set.
 ///
@@ -38,7 +39,7 @@ impl<'a> Transmission<'a> {
         while remaining_bits > 0 {
             let hex_byte_offset = self.bit_offset / 4;
             let bits_left_in_byte = 4 - (self.bit_offset % 4);
-            let bits_to_read = std::cmp::min(remaining_bits, bits_left_in_byte);
+            let bits_to_read = core::cmp::min(remaining_bits, bits_left_in_byte);
             let hex_byte_value = bit_value(*self.hex_bytes.get(hex_byte_offset)?);
             remaining_bits -= bits_to_read;
             result |=
@@ -155,10 +156,10 @@ impl Packet {
                     current_value = current_value.checked_mul(next_package_value)?;
                 }
                 2 => {
-                    current_value = std::cmp::min(next_package_value, current_value);
+                    current_value = core::cmp::min(next_package_value, current_value);
                 }
                 3 => {
-                    current_value = std::cmp::max(next_package_value, current_value);
+                    current_value = core::cmp::max(next_package_value, current_value);
                 }
                 5 => {
                     let second_package_value = Self::parse_and_eval(transmission)?;
@@ -1,5 +1,6 @@
 use crate::input::Input;
-use std::ops::RangeInclusive;
+use alloc::string::{String, ToString};
+use core::ops::RangeInclusive;
 
 pub fn solve(input: &Input) -> Result<i32, String> {
     let trench = Trench::parse(input.text).ok_or_else(|| "Unable to parse trench".to_string())?;
@@ -45,7 +46,7 @@ impl Trench {
         if let Some((start, end)) = range.split_once("..") {
             let a = start.parse::<i16>().ok()?;
             let b = end.parse::<i16>().ok()?;
-            Some(std::cmp::min(a, b)..=std::cmp::max(a, b))
+            Some(core::cmp::min(a, b)..=core::cmp::max(a, b))
         } else {
             None
         }
@@ -67,7 +68,7 @@ fn probes_ends_in_trench(
         y += dy;
         dx -= dx.signum();
         dy -= 1;
-        max_y = std::cmp::max(y, max_y);
+        max_y = core::cmp::max(y, max_y);
 
         if y > i32::from(i16::MAX) {
             continue;
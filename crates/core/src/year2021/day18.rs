@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut lines = input.text.lines();
@@ -19,7 +21,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
                 if idx1 != idx2 {
                     let mut n = (*n1).clone();
                     n.add(n2);
-                    highest = std::cmp::max(highest, n.magnitude());
+                    highest = core::cmp::max(highest, n.magnitude());
                 }
             }
         }
@@ -1,6 +1,9 @@
 use crate::input::Input;
-use std::collections::HashMap;
-use std::ops::{Add, Mul, Sub};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::ops::{Add, Mul, Sub};
+use hashbrown::HashMap;
 
 // Based on the following nice solution:
 // - https://github.com/Mesoptier/advent-of-code-2021/blob/master/src/days/day19.rs
@@ -219,7 +222,7 @@ fn find_match(matched_scans: &[Scan], scan_to_join: &Scan) -> Option<(Point, Sca
             .filter(|(to_join_fingerprint, to_join_pairs)| {
                 matched_scan
                     .fingerprints
-                    .get(to_join_fingerprint)
+                    .get(*to_join_fingerprint)
                     .inspect(|matched_pairs| {
                         num_pairs_matching_fingerprint += to_join_pairs.len() * matched_pairs.len()
                     })
@@ -241,7 +244,7 @@ fn find_match(matched_scans: &[Scan], scan_to_join: &Scan) -> Option<(Point, Sca
                         .map(move |fingerprinted_pair| (fingerprint, fingerprinted_pair))
                 })
         {
-            for matched_pair in matched_scan.fingerprints.get(fingerprint)? {
+            for matched_pair in matched_scan.fingerprints.get(*fingerprint)? {
                 for (first, second) in [
                     (matched_pair, fingerprinted_beacons),
                     (fingerprinted_beacons, matched_pair),
@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let (algorithm, image) = parse(input.text)?;
@@ -16,8 +19,8 @@ impl Image {
         Self { pixels }
     }
 
-    fn size(&self) -> usize {
-        (self.pixels.len() as f64).sqrt() as usize
+    const fn size(&self) -> usize {
+        self.pixels.len().isqrt()
     }
 }
 
@@ -106,7 +109,7 @@ impl ImageEnhancementAlgorithm {
                 }
             }
 
-            std::mem::swap(&mut current, &mut new);
+            core::mem::swap(&mut current, &mut new);
         }
 
         current.iter().filter(|&&b| b).count() as u32
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::{format, vec};
 
 const MAX_POSITION: u64 = 10;
 const SCORE_REQUIRED_PART_2: u8 = 21;
@@ -19,12 +21,12 @@ pub fn solve(input: &Input) -> Result<u64, String> {
                 return Ok(u64::from(p2_score_saved) * die_roll_count);
             }
             game = game.switch_players();
-            std::mem::swap(&mut p1_score_saved, &mut p2_score_saved);
+            core::mem::swap(&mut p1_score_saved, &mut p2_score_saved);
         }
     } else {
         let mut outcome_cache = vec![GameOutcome::default(); Game::MAX_POSSIBLE_STATES];
         let wins = play_game_part_2(game, &mut outcome_cache);
-        Ok(std::cmp::max(wins.player_1_wins, wins.player_2_wins))
+        Ok(core::cmp::max(wins.player_1_wins, wins.player_2_wins))
     }
 }
 
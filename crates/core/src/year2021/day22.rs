@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut cuboids = input
@@ -110,8 +112,8 @@ impl Cuboid {
         if interval_b.0 > interval_a.1 || interval_a.0 > interval_b.1 {
             None
         } else {
-            let intersection_start = std::cmp::max(interval_a.0, interval_b.0);
-            let intersection_end = std::cmp::min(interval_a.1, interval_b.1);
+            let intersection_start = core::cmp::max(interval_a.0, interval_b.0);
+            let intersection_end = core::cmp::min(interval_a.1, interval_b.1);
             Some((intersection_start, intersection_end))
         }
     }
@@ -1,8 +1,11 @@
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
-use std::fmt::{Debug, Formatter, Write};
+use alloc::collections::BinaryHeap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use core::fmt::{Debug, Formatter, Write};
+use hashbrown::HashMap;
+use hashbrown::hash_map::Entry;
 
 use Amphipod::{Amber, Bronze, Copper, Desert};
 
@@ -351,7 +354,7 @@ const fn to_char(a: Option<Amphipod>) -> char {
 }
 
 impl<const SIDE_ROOM_SIZE: usize> Debug for SearchState<SIDE_ROOM_SIZE> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         f.write_str("#############\n")?;
         writeln!(
             f,
@@ -390,7 +393,7 @@ enum Amphipod {
 }
 
 impl Debug for Amphipod {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), core::fmt::Error> {
         let c = (*self as u8) + b'A';
         f.write_char(c as char)
     }
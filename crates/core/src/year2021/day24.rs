@@ -1,4 +1,10 @@
+use crate::common::vm::Budget;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use hashbrown::HashSet;
 
 const NUM_DIGITS_IN_MODEL_NUMBER: usize = 14;
 
@@ -10,7 +16,38 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         .collect::<Option<Vec<_>>>()
         .ok_or_else(|| "Invalid input".to_string())?;
 
-    let input_blocks = extract_input_blocks(&instructions)?;
+    // Use the fast path if the program has the expected MONAD structure, and fall back
+    // to a general search otherwise:
+    solve_structured(&instructions, input).or_else(|error| {
+        input.trace("general search", error);
+        solve_general(
+            &instructions,
+            input.is_part_one(),
+            Budget::new(MAX_GENERAL_INSTRUCTIONS),
+        )
+    })
+}
+
+/// Solves a program consisting of the 14 stereotyped blocks seen in MONAD inputs.
+fn solve_structured(instructions: &[Instruction], input: &Input) -> Result<u64, String> {
+    let part_one = input.is_part_one();
+    let input_blocks = extract_input_blocks(instructions)?;
+    input.trace(
+        "block constants",
+        fmt::from_fn(|f| {
+            for (block_idx, block) in input_blocks.iter().enumerate() {
+                if block_idx > 0 {
+                    f.write_char('\n')?;
+                }
+                write!(
+                    f,
+                    "block {block_idx}: z_division={}, x_addition={}, y_addition={}",
+                    block.z_division, block.x_addition, block.y_addition
+                )?;
+            }
+            Ok(())
+        }),
+    );
 
     let mut model_number = [0; NUM_DIGITS_IN_MODEL_NUMBER];
     let mut stack = Vec::new();
@@ -55,12 +92,12 @@ pub fn solve(input: &Input) -> Result<u64, String> {
                     "Assumption broken: input difference is not in the range [-8,8]".to_string(),
                 );
             }
-            let w_pushed = if input.is_part_one() {
+            let w_pushed = if part_one {
                 // We need highest value on this leftmost digit that results in rightmost digit <= 9.
-                std::cmp::min(9 - input_difference, 9)
+                core::cmp::min(9 - input_difference, 9)
             } else {
                 // We need lowest value on this leftmost digit that results in rightmost digit >= 1.
-                std::cmp::max(1 - input_difference, 1)
+                core::cmp::max(1 - input_difference, 1)
             };
             model_number[pushing_block_idx] = w_pushed;
             model_number[block_idx] = w_pushed + input_difference;
@@ -82,6 +119,9 @@ fn extract_input_blocks(instructions: &[Instruction]) -> Result<InputBlocks, Str
     for (instruction_idx, instruction) in instructions.iter().enumerate() {
         if let Instruction::Input(variable) = instruction {
             if *variable == Variable::W {
+                if instructions.len() < instruction_idx + 18 {
+                    return Err("Assumption broken: Input block is too short".to_string());
+                }
                 // After every input to w:
                 // Start: "x = z % 26"
                 if !(matches!(
@@ -117,7 +157,8 @@ fn extract_input_blocks(instructions: &[Instruction]) -> Result<InputBlocks, Str
                 if let Instruction::Add(Variable::X, VariableOrNumber::Number(x_addition)) =
                     instructions[instruction_idx + 5]
                 {
-                    input_blocks[input_instructions_count].x_addition = x_addition;
+                    input_blocks[input_instructions_count].x_addition = i8::try_from(x_addition)
+                        .map_err(|_| "Assumption broken: x addition out of range".to_string())?;
                 } else {
                     return Err("Assumption broken: x is not added to".to_string());
                 }
@@ -183,7 +224,8 @@ fn extract_input_blocks(instructions: &[Instruction]) -> Result<InputBlocks, Str
                     if y_addition > 16 {
                         return Err("Assumption broken: y addition is > 16".to_string());
                     }
-                    input_blocks[input_instructions_count].y_addition = y_addition;
+                    input_blocks[input_instructions_count].y_addition = i8::try_from(y_addition)
+                        .map_err(|_| "Assumption broken: y addition out of range".to_string())?;
                 } else {
                     return Err("Assumption broken: y is not added to".to_string());
                 }
@@ -230,6 +272,274 @@ fn extract_input_blocks(instructions: &[Instruction]) -> Result<InputBlocks, Str
     Ok(input_blocks)
 }
 
+/// Maximum number of digits in a model number, to fit in an u64.
+const MAX_GENERAL_DIGITS: usize = 19;
+
+/// Maximum number of instructions executed by the general search.
+const MAX_GENERAL_INSTRUCTIONS: u64 = 100_000_000;
+
+type Registers = [i64; 4];
+
+/// Solves any ALU program by a depth first search over the digits, executing the program
+/// one input at a time.
+///
+/// Branches are pruned when interval execution of the rest of the program, with all
+/// remaining inputs in 1..=9, shows that z cannot end up as 0. States (input index and
+/// registers) which have been seen not to lead to a valid model number are memoized,
+/// which are bounded in number by the instructions executed.
+fn solve_general(
+    instructions: &[Instruction],
+    part_one: bool,
+    budget: Budget,
+) -> Result<u64, String> {
+    let input_positions = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, instruction)| {
+            matches!(instruction, Instruction::Input(_)).then_some(idx)
+        })
+        .collect::<Vec<_>>();
+    if input_positions.is_empty() || input_positions.len() > MAX_GENERAL_DIGITS {
+        return Err(format!(
+            "Expected 1 to {MAX_GENERAL_DIGITS} input instructions, found {}",
+            input_positions.len()
+        ));
+    }
+    if input_positions[0] != 0 {
+        return Err("The program does not start with an input instruction".to_string());
+    }
+
+    let mut search = Search {
+        instructions,
+        input_positions: &input_positions,
+        digits: if part_one {
+            [9, 8, 7, 6, 5, 4, 3, 2, 1]
+        } else {
+            [1, 2, 3, 4, 5, 6, 7, 8, 9]
+        },
+        failed_states: HashSet::new(),
+        model_number: Vec::with_capacity(input_positions.len()),
+        budget,
+    };
+    if search.find(0, [0; 4])? {
+        Ok(search
+            .model_number
+            .iter()
+            .rev()
+            .fold(0, |acc, &digit| acc * 10 + digit))
+    } else {
+        Err("No valid model number".to_string())
+    }
+}
+
+struct Search<'a> {
+    instructions: &'a [Instruction],
+    input_positions: &'a [usize],
+    digits: [i64; 9],
+    failed_states: HashSet<(usize, Registers)>,
+    /// The digits of the found model number, with the last digit first.
+    model_number: Vec<u64>,
+    budget: Budget,
+}
+
+impl Search<'_> {
+    fn find(&mut self, input_idx: usize, registers: Registers) -> Result<bool, String> {
+        if input_idx == self.input_positions.len() {
+            return Ok(registers[Variable::Z as usize] == 0);
+        }
+        if self.failed_states.contains(&(input_idx, registers)) {
+            return Ok(false);
+        }
+
+        let block_start = self.input_positions[input_idx];
+        let block_end = self
+            .input_positions
+            .get(input_idx + 1)
+            .copied()
+            .unwrap_or(self.instructions.len());
+
+        if !z_may_end_as_zero(&self.instructions[block_start..], registers) {
+            self.failed_states.insert((input_idx, registers));
+            return Ok(false);
+        }
+
+        for digit in self.digits {
+            self.budget.consume_many((block_end - block_start) as u64)?;
+            if let Some(registers_after) =
+                execute_block(&self.instructions[block_start..block_end], registers, digit)
+                && self.find(input_idx + 1, registers_after)?
+            {
+                self.model_number.push(digit as u64);
+                return Ok(true);
+            }
+        }
+
+        self.failed_states.insert((input_idx, registers));
+        Ok(false)
+    }
+}
+
+/// Executes a block starting with an input instruction, returning None on an invalid operation.
+fn execute_block(
+    instructions: &[Instruction],
+    mut registers: Registers,
+    digit: i64,
+) -> Option<Registers> {
+    for instruction in instructions {
+        let (a, b) = match *instruction {
+            Instruction::Input(a) => {
+                registers[a as usize] = digit;
+                continue;
+            }
+            Instruction::Add(a, b)
+            | Instruction::Multiply(a, b)
+            | Instruction::Divide(a, b)
+            | Instruction::Modulo(a, b)
+            | Instruction::Equal(a, b) => (a, b),
+        };
+        let a_value = registers[a as usize];
+        let b_value = match b {
+            VariableOrNumber::Variable(v) => registers[v as usize],
+            VariableOrNumber::Number(n) => n,
+        };
+        registers[a as usize] = match instruction {
+            Instruction::Add(..) => a_value.checked_add(b_value)?,
+            Instruction::Multiply(..) => a_value.checked_mul(b_value)?,
+            Instruction::Divide(..) => a_value.checked_div(b_value)?,
+            Instruction::Modulo(..) => {
+                if a_value < 0 || b_value <= 0 {
+                    return None;
+                }
+                a_value % b_value
+            }
+            Instruction::Equal(..) => i64::from(a_value == b_value),
+            Instruction::Input(_) => unreachable!(),
+        };
+    }
+    Some(registers)
+}
+
+/// An inclusive range of possible values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+struct Interval {
+    min: i64,
+    max: i64,
+}
+
+impl Interval {
+    const UNKNOWN: Self = Self {
+        min: i64::MIN,
+        max: i64::MAX,
+    };
+
+    const fn exact(value: i64) -> Self {
+        Self {
+            min: value,
+            max: value,
+        }
+    }
+
+    const fn contains(self, value: i64) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    fn from_corners(corners: [Option<i64>; 4]) -> Self {
+        if corners.iter().any(Option::is_none) {
+            return Self::UNKNOWN;
+        }
+        let corners = corners.map(|c| c.unwrap_or_default());
+        Self {
+            min: corners.iter().copied().min().unwrap_or(i64::MIN),
+            max: corners.iter().copied().max().unwrap_or(i64::MAX),
+        }
+    }
+
+    const fn add(self, other: Self) -> Self {
+        Self {
+            min: self.min.saturating_add(other.min),
+            max: self.max.saturating_add(other.max),
+        }
+    }
+
+    fn multiply(self, other: Self) -> Self {
+        Self::from_corners([
+            self.min.checked_mul(other.min),
+            self.min.checked_mul(other.max),
+            self.max.checked_mul(other.min),
+            self.max.checked_mul(other.max),
+        ])
+    }
+
+    fn divide(self, other: Self) -> Self {
+        if other.contains(0) {
+            return Self::UNKNOWN;
+        }
+        // Truncating division is monotonic in each argument when the divisor has a fixed sign:
+        Self::from_corners([
+            self.min.checked_div(other.min),
+            self.min.checked_div(other.max),
+            self.max.checked_div(other.min),
+            self.max.checked_div(other.max),
+        ])
+    }
+
+    fn modulo(self, other: Self) -> Self {
+        if self.min < 0 || other.min <= 0 {
+            // Invalid operation for some values, so nothing can be said about the result:
+            return Self::UNKNOWN;
+        }
+        if self.max < other.min {
+            return self;
+        }
+        Self {
+            min: 0,
+            max: self.max.min(other.max - 1),
+        }
+    }
+
+    fn equal(self, other: Self) -> Self {
+        if self.min == self.max && self == other {
+            Self::exact(1)
+        } else if self.max < other.min || other.max < self.min {
+            Self::exact(0)
+        } else {
+            Self { min: 0, max: 1 }
+        }
+    }
+}
+
+/// Checks, by interval execution with all inputs in 1..=9, if z may end up as zero.
+fn z_may_end_as_zero(instructions: &[Instruction], registers: Registers) -> bool {
+    let mut intervals = registers.map(Interval::exact);
+    for instruction in instructions {
+        let (a, b) = match *instruction {
+            Instruction::Input(a) => {
+                intervals[a as usize] = Interval { min: 1, max: 9 };
+                continue;
+            }
+            Instruction::Add(a, b)
+            | Instruction::Multiply(a, b)
+            | Instruction::Divide(a, b)
+            | Instruction::Modulo(a, b)
+            | Instruction::Equal(a, b) => (a, b),
+        };
+        let a_interval = intervals[a as usize];
+        let b_interval = match b {
+            VariableOrNumber::Variable(v) => intervals[v as usize],
+            VariableOrNumber::Number(n) => Interval::exact(n),
+        };
+        intervals[a as usize] = match instruction {
+            Instruction::Add(..) => a_interval.add(b_interval),
+            Instruction::Multiply(..) => a_interval.multiply(b_interval),
+            Instruction::Divide(..) => a_interval.divide(b_interval),
+            Instruction::Modulo(..) => a_interval.modulo(b_interval),
+            Instruction::Equal(..) => a_interval.equal(b_interval),
+            Instruction::Input(_) => unreachable!(),
+        };
+    }
+    intervals[Variable::Z as usize].contains(0)
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 enum Variable {
     W,
@@ -255,7 +565,7 @@ impl Variable {
 #[derive(Copy, Clone)]
 enum VariableOrNumber {
     Variable(Variable),
-    Number(i8),
+    Number(i64),
 }
 
 impl VariableOrNumber {
@@ -319,7 +629,50 @@ type InputBlocks = [InputBlock; NUM_DIGITS_IN_MODEL_NUMBER];
 
 #[test]
 pub fn tests() {
+    // Not following the MONAD structure, where z is 0 if the first digit is twice the second:
+    let general_input = "inp w
+inp x
+mul x 2
+eql z w
+add z x
+eql z w
+mul z -1
+add z 1
+mul w 0
+inp y
+mul y 0";
+    test_part_one!(general_input => 849);
+    test_part_two!(general_input => 211);
+
     let real_input = include_str!("day24_input.txt");
     test_part_one!(real_input => 99_299_513_899_971);
     test_part_two!(real_input => 93_185_111_127_911);
+
+    let instructions = real_input
+        .lines()
+        .map(Instruction::parse)
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default();
+    let budget = || Budget::new(MAX_GENERAL_INSTRUCTIONS);
+    assert_eq!(
+        solve_general(&instructions, true, budget()),
+        Ok(99_299_513_899_971)
+    );
+    assert_eq!(
+        solve_general(&instructions, false, budget()),
+        Ok(93_185_111_127_911)
+    );
+
+    // Without a valid model number, which is only found out after trying all 18 digit numbers:
+    let unsolvable_input =
+        "inp w\nmul x 10\nadd x w\n".repeat(18) + "mod x 10\neql x 0\neql x 0\nadd z x";
+    let instructions = unsolvable_input
+        .lines()
+        .map(Instruction::parse)
+        .collect::<Option<Vec<_>>>()
+        .unwrap_or_default();
+    assert_eq!(
+        solve_general(&instructions, true, Budget::new(1_000_000)),
+        Err("Aborted after 1000000 instructions".to_string())
+    );
 }
@@ -1,40 +1,55 @@
-/// Categories: Bit manipulation
-use crate::common::u256::U256;
+use crate::common::bitset::BitSet;
 use crate::input::Input;
+use alloc::format;
+/// Categories: Bit manipulation
+use alloc::string::String;
+use alloc::vec::Vec;
 
 #[derive(Copy, Clone)]
-struct CucumberRow {
-    moving_east_bits: U256,
-    moving_south_bits: U256,
+struct CucumberRow<const WORDS: usize> {
+    moving_east_bits: BitSet<WORDS>,
+    moving_south_bits: BitSet<WORDS>,
 }
 
-impl CucumberRow {
-    fn bits(self) -> U256 {
+impl<const WORDS: usize> CucumberRow<WORDS> {
+    fn bits(self) -> BitSet<WORDS> {
         self.moving_east_bits | self.moving_south_bits
     }
 }
 
 pub fn solve(input: &Input) -> Result<u32, String> {
-    const MAX_ITERATIONS: u32 = 1000;
+    // Rows wrap around after the rightmost sea cucumber:
+    let width = input
+        .text
+        .lines()
+        .filter_map(|line| line.rfind(['>', 'v']))
+        .max()
+        .map_or(0, |offset| offset + 1);
 
-    let mut width = 0;
+    // Use rows just wide enough, to keep the common case fast:
+    match width.div_ceil(64) {
+        0..=4 => simulate::<4>(input, width),
+        5..=16 => simulate::<16>(input, width),
+        _ => Err(format!(
+            "Too wide input - max width is {}",
+            BitSet::<16>::CAPACITY
+        )),
+    }
+}
+
+fn simulate<const WORDS: usize>(input: &Input, width: usize) -> Result<u32, String> {
+    const MAX_ITERATIONS: u32 = 1000;
 
     let mut cucumber_rows = input
         .text
         .lines()
         .map(|line| {
-            let mut moving_east_bits = U256::default();
-            let mut moving_south_bits = U256::default();
+            let mut moving_east_bits = BitSet::<WORDS>::new();
+            let mut moving_south_bits = BitSet::<WORDS>::new();
             for (offset, col) in line.bytes().enumerate() {
                 match col {
-                    b'>' => {
-                        moving_east_bits.set_bit(offset);
-                        width = width.max(offset + 1);
-                    }
-                    b'v' => {
-                        moving_south_bits.set_bit(offset);
-                        width = width.max(offset + 1);
-                    }
+                    b'>' => moving_east_bits.set_bit(offset),
+                    b'v' => moving_south_bits.set_bit(offset),
                     _ => (),
                 }
             }
@@ -52,15 +67,15 @@ pub fn solve(input: &Input) -> Result<u32, String> {
 
         // "Every step, the sea cucumbers in the east-facing herd attempt to move forward one location":
         for row in cucumber_rows.iter_mut() {
-            let moved = row.moving_east_bits.shift_left(width) & !row.bits();
+            let moved = row.moving_east_bits.rotate_left_within(width) & !row.bits();
             any_cucumber_moved |= moved.non_zero();
-            let stay = row.moving_east_bits & !moved.shift_right(width);
+            let stay = row.moving_east_bits & !moved.rotate_right_within(width);
             row.moving_east_bits = moved | stay;
         }
 
         // "[..] then the sea cucumbers in the south-facing herd attempt to move forward one location":
         let orig_first_row = cucumber_rows[0];
-        let mut moving_south_scratch_bits = U256::default();
+        let mut moving_south_scratch_bits = BitSet::new();
         for i in 0..height {
             let row_to_the_south = if i == height - 1 {
                 orig_first_row
@@ -98,6 +113,18 @@ v.v..>>v.v
 ....v..v.>";
     test_part_one!(example => 58);
 
+    // Rows too wide for 256 bits:
+    let wide_example = example
+        .lines()
+        .map(|line| line.repeat(30))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let wide_example = wide_example.as_str();
+    test_part_one!(wide_example => 58);
+    let too_wide_input = ">".repeat(2000);
+    let too_wide_input = too_wide_input.as_str();
+    test_part_one_error!(too_wide_input => "Too wide input - max width is 1024");
+
     let real_input = include_str!("day25_input.txt");
     test_part_one!(real_input => 582);
 }
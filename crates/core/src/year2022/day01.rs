@@ -1,5 +1,6 @@
 use crate::common::highest_values::HighestValues;
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     if input.is_part_one() {
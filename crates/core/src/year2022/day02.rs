@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let scores_indexed_by_input =
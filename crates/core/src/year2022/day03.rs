@@ -1,5 +1,6 @@
 use crate::common::chunk_iterator::ChunkIteratorExt;
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let lines = input.text.lines();
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
 
 fn parse_intervals(line: &str) -> Option<((u8, u8), (u8, u8))> {
     fn parse_range(range: &str) -> Option<(u8, u8)> {
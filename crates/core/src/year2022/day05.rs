@@ -1,4 +1,6 @@
-use std::num::NonZeroU8;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::num::NonZeroU8;
 
 use crate::input::Input;
 
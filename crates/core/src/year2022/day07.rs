@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
 
 struct Stack {
     data: [u64; Self::MAX_SIZE],
@@ -1,4 +1,6 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     let grid = input
@@ -8,7 +10,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
         .map(|b| b - b'0')
         .collect::<Vec<_>>();
 
-    let grid_size = (grid.len() as f64).sqrt() as usize;
+    let grid_size = grid.len().isqrt();
     if grid_size * grid_size != grid.len() {
         return Err("Non-rectangular grid".to_string());
     }
@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     let snake_len = input.part_values(2, 10);
@@ -1,5 +1,6 @@
 use crate::common::character_recognition::recognize;
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 struct Device {
     register_x: i32,
@@ -54,7 +55,7 @@ pub fn solve(input: &Input) -> Result<String, String> {
     if input.is_part_one() {
         Ok(device.accumulated_signal_strength.to_string())
     } else {
-        recognize(&device.screen)
+        recognize(input, &device.screen)
     }
 }
 
@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use hashbrown::HashMap;
 
 use crate::input::Input;
 
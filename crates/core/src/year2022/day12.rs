@@ -1,5 +1,10 @@
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
 
+#[cfg(feature = "visualization")]
+use alloc::format;
 #[cfg(feature = "visualization")]
 use svgplot::{Coordinate, SvgColor, SvgImage, SvgPath, SvgScript, SvgShape, SvgStrokeLinecap};
 
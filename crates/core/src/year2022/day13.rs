@@ -1,5 +1,6 @@
+use alloc::string::String;
+use core::cmp::Ordering;
 use core::iter::Peekable;
-use std::cmp::Ordering;
 
 use crate::input::Input;
 
@@ -1,5 +1,8 @@
 use crate::common::tuple_window_iterator::TupleWindowIteratorExt;
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 struct Grid {
     data: Vec<bool>,
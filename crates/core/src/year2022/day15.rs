@@ -1,15 +1,32 @@
 // Adaption of https://github.com/SLiV9/AdventOfCode2022/blob/main/src/bin/day15/main.rs
 use crate::input::Input;
+use crate::params::Param;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
-const MAX_COORDINATE: i32 = 4_000_000;
+const ROW: Param = Param {
+    name: "row",
+    description: "The row to count positions where a beacon cannot be in (part 1)",
+    default: 2_000_000,
+    min: -100_000_000,
+    max: 100_000_000,
+};
+const MAX_COORDINATE: Param = Param {
+    name: "max_coordinate",
+    description: "The maximum x and y coordinate of the distress beacon (part 2)",
+    default: 4_000_000,
+    min: 0,
+    max: 100_000_000,
+};
+pub const PARAMS: [Param; 2] = [ROW, MAX_COORDINATE];
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let sensors = Sensor::parse(input.text).ok_or_else(|| "Invalid input".to_string())?;
 
     if input.is_part_one() {
-        Ok(solve_part_1(&sensors))
+        Ok(solve_part_1(&sensors, input.param(&ROW) as i32))
     } else {
-        solve_part_2(&sensors)
+        solve_part_2(&sensors, input.param(&MAX_COORDINATE) as i32)
     }
 }
 
@@ -54,12 +71,10 @@ impl Sensor {
     }
 }
 
-fn solve_part_1(sensors: &[Sensor]) -> u64 {
+fn solve_part_1(sensors: &[Sensor], row: i32) -> u64 {
     let mut not_possible_intervals = sensors
         .iter()
         .filter_map(|sensor| {
-            const ROW: i32 = 2_000_000;
-
             // Consider the sensor at S:
             //
             //     .........
@@ -73,17 +88,11 @@ fn solve_part_1(sensors: &[Sensor]) -> u64 {
             // The intersection at row R is at:
             //   x_start = S_x - radius + abs(R_y - S_y)
             //   x_end   = S_x + radius - abs(R_y - S_y)
-            if ROW.abs_diff(sensor.position.1) <= sensor.range {
-                let intersection_distance = ROW.abs_diff(sensor.position.1) as i32;
+            if row.abs_diff(sensor.position.1) <= sensor.range {
+                let intersection_distance = row.abs_diff(sensor.position.1) as i32;
                 let x_start = sensor.position.0 - sensor.range as i32 + intersection_distance;
                 let x_end = sensor.position.0 + sensor.range as i32 - intersection_distance;
-                // We then need to adjust the intersection if the beacon is there:
-                //   R-> ...B#B...
-                // That is done by adding one to x_start, or subtracting one from x_end, if necessary.
-                Some((
-                    x_start + i32::from((x_start, ROW) == sensor.closest_beacon),
-                    x_end - i32::from((x_end, ROW) == sensor.closest_beacon),
-                ))
+                Some((x_start, x_end))
             } else {
                 None
             }
@@ -96,11 +105,8 @@ fn solve_part_1(sensors: &[Sensor]) -> u64 {
     not_possible_intervals.sort_unstable_by_key(|a| a.0);
 
     for interval in not_possible_intervals {
-        if interval.0 <= last_interval.1 && interval.1 >= last_interval.0 {
-            last_interval = (
-                interval.0.min(last_interval.0),
-                interval.1.max(last_interval.1),
-            );
+        if interval.0 <= last_interval.1 {
+            last_interval.1 = interval.1.max(last_interval.1);
         } else {
             not_possible_positions_count += last_interval.1 - last_interval.0 + 1;
             last_interval = interval;
@@ -108,7 +114,16 @@ fn solve_part_1(sensors: &[Sensor]) -> u64 {
     }
     not_possible_positions_count += last_interval.1 - last_interval.0 + 1;
 
-    not_possible_positions_count as u64
+    // Beacons on the row are inside the interval of their sensor, but are not
+    // positions where a beacon cannot be:
+    let mut beacons_on_row = sensors
+        .iter()
+        .filter_map(|sensor| (sensor.closest_beacon.1 == row).then_some(sensor.closest_beacon.0))
+        .collect::<Vec<_>>();
+    beacons_on_row.sort_unstable();
+    beacons_on_row.dedup();
+
+    (not_possible_positions_count - beacons_on_row.len() as i32) as u64
 }
 
 /// Since there is only one possible space, it (unless it's at the
@@ -128,14 +143,14 @@ fn solve_part_1(sensors: &[Sensor]) -> u64 {
 /// These two diagonal lines must come from two different sensors.
 ///
 /// The possible space could also be along the edge of the boundary.
-fn solve_part_2(sensors: &[Sensor]) -> Result<u64, String> {
+fn solve_part_2(sensors: &[Sensor], max_coordinate: i32) -> Result<u64, String> {
     let ascending_lines = diagonal_line_candidates(sensors, true);
     let descending_lines = diagonal_line_candidates(sensors, false);
 
     for &ascending_line in ascending_lines.iter() {
         for &descending_line in descending_lines.iter() {
             let intersection = intersection_of(ascending_line, descending_line);
-            if is_within_bounds(intersection)
+            if is_within_bounds(intersection, max_coordinate)
                 && !sensors.iter().any(|sensor| sensor.contains(intersection))
             {
                 return Ok((intersection.0 as u64) * 4_000_000 + (intersection.1 as u64));
@@ -144,12 +159,12 @@ fn solve_part_2(sensors: &[Sensor]) -> Result<u64, String> {
     }
 
     // Also check the edge of the boundary:
-    for offset in 0..=MAX_COORDINATE {
+    for offset in 0..=max_coordinate {
         for position in [
             (0, offset),
-            (MAX_COORDINATE, offset),
+            (max_coordinate, offset),
             (offset, 0),
-            (offset, MAX_COORDINATE),
+            (offset, max_coordinate),
         ] {
             if !sensors.iter().any(|sensor| sensor.contains(position)) {
                 return Ok((position.0 as u64) * 4_000_000 + (position.1 as u64));
@@ -195,11 +210,11 @@ fn diagonal_line_candidates(sensors: &[Sensor], ascending: bool) -> Vec<i32> {
     diagonal_lines
 }
 
-const fn is_within_bounds(position: Point) -> bool {
+const fn is_within_bounds(position: Point, max_coordinate: i32) -> bool {
     position.0 >= 0
-        && position.0 <= MAX_COORDINATE
+        && position.0 <= max_coordinate
         && position.1 >= 0
-        && position.1 <= MAX_COORDINATE
+        && position.1 <= max_coordinate
 }
 
 /// Given the x origin of an ascending (represented with 'a' below) and
@@ -219,6 +234,23 @@ const fn intersection_of(ascending_origin_x: i32, descending_origin_x: i32) -> P
 
 #[test]
 pub fn tests() {
+    let test_input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+    test_part_one!(test_input, [("row", 10)] => 26);
+    test_part_two!(test_input, [("max_coordinate", 20)] => 56_000_011);
+
     let real_input = include_str!("day15_input.txt");
     test_part_one!(real_input => 5_240_818);
     test_part_two!(real_input => 13_213_086_906_101);
@@ -1,5 +1,9 @@
-use std::cmp::Reverse;
-use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Reverse;
+use hashbrown::{HashMap, HashSet};
 
 use crate::input::Input;
 
@@ -10,7 +14,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
     let (distances, flows) = parse(input.text).ok_or("Invalid input")?;
 
     // Compute min distances useful for upper bound calculations.
-    let min_distances = (0..=std::cmp::max(actor_1_remaining_minutes, actor_2_remaining_minutes))
+    let min_distances = (0..=core::cmp::max(actor_1_remaining_minutes, actor_2_remaining_minutes))
         .map(|minute| {
             let mut v = distances
                 .iter()
@@ -98,11 +102,11 @@ pub fn solve(input: &Input) -> Result<usize, String> {
                     ..state
                 };
                 if new_state.actor_1_remaining_minutes < new_state.actor_2_remaining_minutes {
-                    std::mem::swap(
+                    core::mem::swap(
                         &mut new_state.actor_1_remaining_minutes,
                         &mut new_state.actor_2_remaining_minutes,
                     );
-                    std::mem::swap(
+                    core::mem::swap(
                         &mut new_state.actor_1_flow_idx,
                         &mut new_state.actor_2_flow_idx,
                     );
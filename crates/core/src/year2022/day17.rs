@@ -1,13 +1,21 @@
-use std::collections::HashMap;
-use std::collections::hash_map::Entry;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
+use crate::common::cycle::CycleDetector;
 use crate::input::Input;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let target_rocks_count = input.part_values(2022, 1_000_000_000_000_u64);
     let mut grid = Grid::new();
 
-    let mut seen = HashMap::new();
+    // The state before each rock is identified by the next rock, the next direction and the
+    // surface of the pile, with the height after each rock kept to extrapolate from a cycle:
+    let mut detector = CycleDetector::new();
+    let mut heights = Vec::new();
+    if input.is_part_two() {
+        detector.record((0, 0, grid.surface()));
+        heights.push(0);
+    }
     let mut direction_iterator = input.text.bytes().enumerate().cycle();
 
     for (current_rock_count, &(rock_width, rock_bitmask)) in ROCK_SEQUENCE
@@ -44,27 +52,22 @@ pub fn solve(input: &Input) -> Result<u64, String> {
             }
 
             if input.is_part_two() {
-                let rock_and_direction_idx =
-                    (current_rock_count % ROCK_SEQUENCE.len(), direction_idx);
-
-                match seen.entry(rock_and_direction_idx) {
-                    Entry::Vacant(entry) => {
-                        entry.insert((current_rock_count, grid.highest_rock));
-                    }
-                    Entry::Occupied(entry) => {
-                        let (last_seen_rock_drop_iteration, last_seen_highest_rock) = *entry.get();
-                        let rocks_per_cycle =
-                            (current_rock_count - last_seen_rock_drop_iteration) as u64;
-                        let remaining_rocks = target_rocks_count - current_rock_count as u64;
-
-                        if remaining_rocks.is_multiple_of(rocks_per_cycle) {
-                            let remaining_cycles = remaining_rocks / rocks_per_cycle;
-                            let highest_rock_growth = grid.highest_rock - last_seen_highest_rock;
-                            return Ok(grid.highest_rock as u64
-                                + remaining_cycles * highest_rock_growth as u64
-                                - 1);
-                        }
-                    }
+                heights.push(grid.highest_rock as i64);
+                let state = (
+                    (current_rock_count + 1) % ROCK_SEQUENCE.len(),
+                    (direction_idx + 1) % input.text.len(),
+                    grid.surface(),
+                );
+                if let Some(cycle) = detector.record(state) {
+                    input.trace("rocks per cycle", cycle.period);
+                    input.trace(
+                        "height growth per cycle",
+                        heights[(cycle.start + cycle.period) as usize]
+                            - heights[cycle.start as usize],
+                    );
+                    return Ok(
+                        cycle.extrapolate(target_rocks_count, |rocks| heights[rocks]) as u64,
+                    );
                 }
             }
             break;
@@ -115,6 +118,17 @@ impl Grid {
         }
     }
 
+    /// The top 16 rows of the pile, which in practice is all that falling rocks can reach.
+    fn surface(&self) -> u128 {
+        (1..=16).fold(0, |surface, depth| {
+            let row = self
+                .highest_rock
+                .checked_sub(depth)
+                .map_or(0, |y| self.data[y]);
+            (surface << 8) | u128::from(row)
+        })
+    }
+
     fn can_place_rock(&self, rock: Rock, left_edge_x: usize, bottom_edge_y: usize) -> bool {
         rock & ((u16::from(self.data[bottom_edge_y] >> left_edge_x) & 0b1111)
             + ((u16::from(self.data[bottom_edge_y + 1] >> left_edge_x) & 0b1111) << 4)
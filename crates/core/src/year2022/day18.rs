@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     const MAX: i32 = 24;
@@ -123,7 +126,6 @@ pub fn tests() {
 #[cfg(feature = "count-allocations")]
 #[test]
 pub fn no_memory_allocations() {
-    use crate::input::{test_part_one, test_part_two};
     let real_input = include_str!("day18_input.txt");
     let info = allocation_counter::measure(|| {
         test_part_one!(real_input => 3498);
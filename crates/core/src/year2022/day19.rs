@@ -1,16 +1,22 @@
-use std::array;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::array;
 
+use crate::common::parallel;
 use crate::input::Input;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let minutes = input.part_values(24, 32);
     let max_blueprints = input.part_values(64, 3);
 
-    let blueprints = parse_blueprints(input.text);
-
-    let max_geodes = blueprints
+    let blueprints = parse_blueprints(input.text)
         .take(max_blueprints)
-        .map(|blueprint| most_geodes_opened(&blueprint, minutes));
+        .collect::<Vec<_>>();
+
+    let max_geodes = parallel::map(&blueprints, |blueprint| {
+        most_geodes_opened(blueprint, minutes)
+    })
+    .into_iter();
 
     Ok(if input.is_part_one() {
         max_geodes
@@ -83,7 +89,7 @@ fn most_geodes_opened(blueprint: &Blueprint, minutes: u32) -> u32 {
             continue;
         }
 
-        most_geodes_produced = std::cmp::max(
+        most_geodes_produced = core::cmp::max(
             most_geodes_produced,
             state.ores[3] + state.robots[3] * state.minutes_remaining,
         );
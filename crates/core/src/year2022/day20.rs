@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 pub fn solve(input: &Input) -> Result<i64, String> {
     let iterations = input.part_values(1, 10);
@@ -24,7 +27,7 @@ pub fn solve(input: &Input) -> Result<i64, String> {
 
     // Set up buckets containing indices, so that a rotation (that needs to lookup a position
     // from an index) does not need to search through and update numbers.len() entries.
-    let bucket_size = (numbers.len() as f64).sqrt() as usize;
+    let bucket_size = numbers.len().isqrt();
     let mut buckets = Vec::with_capacity(numbers.len() / bucket_size);
     for i in (0..numbers.len()).step_by(bucket_size) {
         let range_end = (i + bucket_size).min(numbers.len());
@@ -80,7 +83,7 @@ pub fn solve(input: &Input) -> Result<i64, String> {
         .position(|&n| n == zero_idx)
         .unwrap_or_default();
 
-    Ok(std::iter::from_fn(|| {
+    Ok(core::iter::from_fn(|| {
         (bucket_containing_number, number_offset_in_bucket) = find_bucket_and_offset(
             &buckets,
             bucket_containing_number,
@@ -1,12 +1,23 @@
-#[cfg(not(feature = "simd"))]
-#[cfg(not(feature = "webgpu-compute"))]
-#[cfg(not(feature = "visualization"))]
-pub fn solve(input: &crate::input::Input) -> Result<usize, String> {
+use crate::common::storage::{capacity_exceeded, with_heap_fallback};
+use crate::input::Input;
+use alloc::string::{String, ToString};
+
+/// Rejects input without elves, which have no rectangle containing them.
+pub fn check_has_elves(input: &str) -> Result<(), String> {
+    if input.contains('#') {
+        Ok(())
+    } else {
+        Err("No elves in input".to_string())
+    }
+}
+
+/// The scalar implementation, used when no alternative one is enabled by a feature.
+pub fn solve_scalar(input: &Input) -> Result<usize, String> {
+    use crate::common::bitset::BitSet;
     use crate::common::map_windows::MapWindowsIterator;
-    use crate::common::u256::U256;
 
     /// Each row is represented as a bitset.
-    type ElfGridRow = U256;
+    type ElfGridRow = BitSet<4>;
 
     #[derive(Clone, Copy)]
     enum Direction {
@@ -37,7 +48,7 @@ pub fn solve(input: &crate::input::Input) -> Result<usize, String> {
                         let storage_row = row + Self::ROW_OFFSET;
                         let storage_col = col + Self::COL_OFFSET;
                         if storage_row >= Self::NUM_ROWS || storage_col >= Self::NUM_COLS {
-                            return Err("Elves does not fit into optimised grid".to_string());
+                            return Err(capacity_exceeded());
                         }
                         grid.bit_rows[storage_row].set_bit(storage_col);
                     }
@@ -48,15 +59,15 @@ pub fn solve(input: &crate::input::Input) -> Result<usize, String> {
 
         const fn shift_cols_west(&row: &ElfGridRow) -> ElfGridRow {
             // Shift cols to the west/left (to _lower_ values).
-            row.shift_right(255)
+            row.rotate_right_within(255)
         }
 
         const fn shift_cols_east(&row: &ElfGridRow) -> ElfGridRow {
             // Shift cols to the east/right (to _higher_ values).
-            row.shift_left(255)
+            row.rotate_left_within(255)
         }
 
-        fn run_simulation(&mut self, max_rounds: usize) -> Option<usize> {
+        fn run_simulation(&mut self, max_rounds: usize) -> Result<Option<usize>, String> {
             let mut directions = [
                 Direction::North,
                 Direction::South,
@@ -64,12 +75,27 @@ pub fn solve(input: &crate::input::Input) -> Result<usize, String> {
                 Direction::East,
             ];
             for round in 0..max_rounds {
+                if self.is_at_border() {
+                    return Err(capacity_exceeded());
+                }
                 if !self.play_round(directions) {
-                    return Some(round + 1);
+                    return Ok(Some(round + 1));
                 }
                 directions.rotate_left(1);
             }
-            None
+            Ok(None)
+        }
+
+        /// If an elf is at the border of the grid, where it cannot move outward.
+        fn is_at_border(&self) -> bool {
+            self.bit_rows[..2]
+                .iter()
+                .chain(&self.bit_rows[Self::NUM_ROWS - 2..])
+                .any(|row| *row != ElfGridRow::default())
+                || self
+                    .bit_rows
+                    .iter()
+                    .any(|row| row.is_bit_set(0) || row.is_bit_set(Self::NUM_COLS - 1))
         }
 
         #[allow(unstable_name_collisions)]
@@ -180,21 +206,25 @@ pub fn solve(input: &crate::input::Input) -> Result<usize, String> {
         }
 
         fn populated_rect_size(&self) -> usize {
-            let bounds = (0..self.bit_rows.len())
-                .flat_map(|row| (0..Self::NUM_COLS).map(move |col| (row, col)))
+            let bounds = self
+                .bit_rows
+                .iter()
+                .enumerate()
+                .filter_map(|(row, bits)| {
+                    // The first and last elf in the row, if any:
+                    let first_col = bits.ones().next()?;
+                    let last_col = bits.select(bits.count_ones() - 1)?;
+                    Some((row, first_col, last_col))
+                })
                 .fold(
                     (usize::MAX, usize::MIN, usize::MAX, usize::MIN),
-                    |acc, (row, col)| {
-                        if self.bit_rows[row].is_bit_set(col) {
-                            (
-                                acc.0.min(row),
-                                acc.1.max(row),
-                                acc.2.min(col),
-                                acc.3.max(col),
-                            )
-                        } else {
-                            acc
-                        }
+                    |acc, (row, first_col, last_col)| {
+                        (
+                            acc.0.min(row),
+                            acc.1.max(row),
+                            acc.2.min(first_col),
+                            acc.3.max(last_col),
+                        )
                     },
                 );
             (bounds.1 + 1 - bounds.0) * (bounds.3 + 1 - bounds.2)
@@ -205,30 +235,126 @@ pub fn solve(input: &crate::input::Input) -> Result<usize, String> {
         }
     }
 
+    check_has_elves(input.text)?;
     let mut grid = ElfGrid::parse(input.text)?;
 
     if input.is_part_one() {
-        grid.run_simulation(10);
+        grid.run_simulation(10)?;
         Ok(grid.populated_rect_size() - grid.num_elves())
     } else {
-        grid.run_simulation(10000)
+        grid.run_simulation(10000)?
             .ok_or_else(|| "No solution found in 10,000 rounds".to_string())
     }
 }
 
+/// An implementation storing elf positions in a hash set, so without any limit on how far
+/// the elves spread out.
+pub fn solve_heap(input: &Input) -> Result<usize, String> {
+    use hashbrown::{HashMap, HashSet};
+
+    const ADJACENT: [(i32, i32); 8] = [
+        (-1, -1),
+        (0, -1),
+        (1, -1),
+        (-1, 0),
+        (1, 0),
+        (-1, 1),
+        (0, 1),
+        (1, 1),
+    ];
+    // The positions to check, in the initial order N, S, W, E, where the middle one is
+    // the move to propose:
+    const RULES: [[(i32, i32); 3]; 4] = [
+        [(-1, -1), (0, -1), (1, -1)],
+        [(-1, 1), (0, 1), (1, 1)],
+        [(-1, -1), (-1, 0), (-1, 1)],
+        [(1, -1), (1, 0), (1, 1)],
+    ];
+
+    check_has_elves(input.text)?;
+    let mut elves = input
+        .text
+        .lines()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.bytes()
+                .enumerate()
+                .filter(|&(_, b)| b == b'#')
+                .map(move |(x, _)| (x as i32, y as i32))
+        })
+        .collect::<HashSet<_>>();
+
+    // Proposed destinations, mapped to the elf proposing it and the number of proposals:
+    let mut proposals = HashMap::new();
+
+    for round in 0..input.part_values(10, 10000) {
+        for &(x, y) in &elves {
+            let is_free = |&(dx, dy): &(i32, i32)| !elves.contains(&(x + dx, y + dy));
+            if ADJACENT.iter().all(is_free) {
+                continue;
+            }
+            for rule_offset in 0..RULES.len() {
+                let checked = RULES[(round + rule_offset) % RULES.len()];
+                if checked.iter().all(is_free) {
+                    let (dx, dy) = checked[1];
+                    proposals
+                        .entry((x + dx, y + dy))
+                        .and_modify(|(_, count)| *count += 1)
+                        .or_insert(((x, y), 1));
+                    break;
+                }
+            }
+        }
+
+        let mut moved = false;
+        for (to, (from, count)) in proposals.drain() {
+            if count == 1 {
+                elves.remove(&from);
+                elves.insert(to);
+                moved = true;
+            }
+        }
+        if !moved && input.is_part_two() {
+            return Ok(round + 1);
+        }
+    }
+
+    if input.is_part_two() {
+        return Err("No solution found in 10,000 rounds".to_string());
+    }
+    let (min_x, max_x, min_y, max_y) = elves
+        .iter()
+        .fold((i32::MAX, i32::MIN, i32::MAX, i32::MIN), |acc, &(x, y)| {
+            (acc.0.min(x), acc.1.max(x), acc.2.min(y), acc.3.max(y))
+        });
+    Ok((max_x + 1 - min_x) as usize * (max_y + 1 - min_y) as usize - elves.len())
+}
+
+#[cfg(not(any(
+    feature = "simd",
+    feature = "webgpu-compute",
+    feature = "visualization"
+)))]
+use solve_scalar as solve_fixed;
+
 #[cfg(feature = "simd")]
-pub use super::day23_simd::solve;
+use super::day23_simd::solve as solve_fixed;
 
 #[cfg(feature = "webgpu-compute")]
-pub use super::day23_webgpu::solve;
+use super::day23_webgpu::solve as solve_fixed;
 
 #[cfg(feature = "visualization")]
-pub use super::day23_renderer::solve;
+use super::day23_renderer::solve as solve_fixed;
+
+/// Solves with the implementation enabled by features, or with [solve_heap] if the elves
+/// do not fit into its fixed-size grid.
+pub fn solve(input: &Input) -> Result<usize, String> {
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
 
 #[test]
 pub fn tests() {
-    use crate::input::Input;
-
+    use alloc::{format, vec};
     let test_input = "....#..
 ..###.#
 #...#.#
@@ -238,8 +364,37 @@ pub fn tests() {
 .#..#..";
     test_part_one!(test_input => 110);
     test_part_two!(test_input => 20);
+    assert_eq!(solve_heap(&Input::part_one(test_input)), Ok(110));
+    assert_eq!(solve_heap(&Input::part_two(test_input)), Ok(20));
+
+    // The example moved too far south for the grid of the scalar implementation:
+    let far_input = format!("{}{test_input}", "\n".repeat(200));
+    let far_input = far_input.as_str();
+    assert_eq!(
+        solve_scalar(&Input::part_one(far_input)),
+        Err(capacity_exceeded())
+    );
+    test_part_one!(far_input => 110);
+    test_part_two!(far_input => 20);
+
+    // Elves fitting into the grid of the scalar implementation, but spreading out of it:
+    let big_input = vec!["#".repeat(120); 120].join("\n");
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_scalar(&Input::part_two(big_input)),
+        Err(capacity_exceeded())
+    );
+    assert!(solve(&Input::part_two(big_input)).is_ok());
+
+    test_part_one_error!("...\n..." => "No elves in input");
+    assert_eq!(
+        solve_heap(&Input::part_two("...")),
+        Err("No elves in input".to_string())
+    );
 
     let real_input = include_str!("day23_input.txt");
     test_part_one!(real_input => 3920);
     test_part_two!(real_input => 889);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(3920));
+    assert_eq!(solve_heap(&Input::part_two(real_input)), Ok(889));
 }
@@ -1,5 +1,9 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 use svgplot::{SvgImage, SvgRect, SvgScript, SvgStyle};
 
+use crate::common::storage::capacity_exceeded;
 use crate::input::Input;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
@@ -44,6 +48,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
             || position.1 >= MAX_SIZE as i16
     };
 
+    super::day23::check_has_elves(input.text)?;
     let mut elves = input
         .text
         .lines()
@@ -56,13 +61,13 @@ pub fn solve(input: &Input) -> Result<usize, String> {
         .collect::<Vec<_>>();
 
     if elves.len() > MAX_ELVES {
-        return Err(format!("Too many elves - max {MAX_ELVES} supported"));
+        return Err(capacity_exceeded());
     }
 
     let mut elf_grid = vec![NO_ELF; MAX_SIZE * MAX_SIZE];
     for (elf_idx, &elf) in elves.iter().enumerate() {
         if is_outside_max(elf) {
-            return Err(format!("Elf is outside of [0,{MAX_SIZE})"));
+            return Err(capacity_exceeded());
         }
         elf_grid[elf.1 as usize * MAX_SIZE + elf.0 as usize] = elf_idx as u16;
     }
@@ -105,9 +110,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
             let elf = &mut elves[elf_idx];
             let new_position = (elf.0 + to_move.0, elf.1 + to_move.1);
             if is_outside_max(new_position) {
-                return Err(format!(
-                    "Elf tried to moved outside of [0,{MAX_SIZE}): {new_position:?}"
-                ));
+                return Err(capacity_exceeded());
             }
 
             let elf_idx_at_position =
@@ -206,7 +209,10 @@ window.onNewStep = (step) => {{\n\
         }
 
         if num_moves == 0 {
-            return Ok(round + 1);
+            if input.is_part_two() {
+                return Ok(round + 1);
+            }
+            break;
         }
     }
 
@@ -1,19 +1,22 @@
 /// Solution to Advent of Code, day 23 in 2022.
 /// Using portable simd in rust.
 /// Based on <https://github.com/Crazytieguy/advent-of-code/blob/master/2022/src/bin/day23/main.rs/>
-use std::simd::u8x32;
+use alloc::string::{String, ToString};
+use core::simd::u8x32;
 
 use crate::common::map_windows::MapWindowsIterator;
+use crate::common::storage::capacity_exceeded;
 use crate::input::Input;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
+    super::day23::check_has_elves(input.text)?;
     let mut grid = ElfGrid::parse(input.text)?;
 
     if input.is_part_one() {
-        grid.run_simulation(10);
+        grid.run_simulation(10)?;
         Ok(grid.populated_rect_size() - grid.num_elves())
     } else {
-        grid.run_simulation(10000)
+        grid.run_simulation(10000)?
             .ok_or_else(|| "No solution found in 10,000 rounds".to_string())
     }
 }
@@ -56,7 +59,7 @@ impl ElfGrid {
                     let storage_row = row + Self::ROW_OFFSET;
                     let storage_col = col + Self::COL_OFFSET;
                     if storage_row >= Self::NUM_ROWS || storage_col >= Self::NUM_COLS {
-                        return Err("Elves does not fit into optimised grid".to_string());
+                        return Err(capacity_exceeded());
                     }
                     grid.set_elf_at(storage_row, storage_col);
                 }
@@ -93,7 +96,7 @@ impl ElfGrid {
             | (row.rotate_elements_right::<1>() >> ElfGridRow::splat(7))
     }
 
-    fn run_simulation(&mut self, max_rounds: usize) -> Option<usize> {
+    fn run_simulation(&mut self, max_rounds: usize) -> Result<Option<usize>, String> {
         let mut directions = [
             Direction::North,
             Direction::South,
@@ -101,12 +104,25 @@ impl ElfGrid {
             Direction::East,
         ];
         for round in 0..max_rounds {
+            if self.is_at_border() {
+                return Err(capacity_exceeded());
+            }
             if !self.play_round(directions) {
-                return Some(round + 1);
+                return Ok(Some(round + 1));
             }
             directions.rotate_left(1);
         }
-        None
+        Ok(None)
+    }
+
+    /// If an elf is at the border of the grid, where it cannot move outward.
+    fn is_at_border(&self) -> bool {
+        self.bit_rows[..2]
+            .iter()
+            .chain(&self.bit_rows[Self::NUM_ROWS - 2..])
+            .any(|row| *row != ElfGridRow::splat(0))
+            || (0..Self::NUM_ROWS)
+                .any(|row| self.is_elf_at(row, 0) || self.is_elf_at(row, Self::NUM_COLS - 1))
     }
 
     #[allow(unstable_name_collisions)]
@@ -240,7 +256,7 @@ impl ElfGrid {
     fn num_elves(&self) -> usize {
         self.bit_rows
             .iter()
-            .flat_map(std::simd::Simd::as_array)
+            .flat_map(core::simd::Simd::as_array)
             .map(|x| x.count_ones() as usize)
             .sum()
     }
@@ -1,8 +1,11 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::{format, vec};
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     const MAX_SIZE: usize = 256;
 
+    super::day23::check_has_elves(input.text)?;
     let gpu = crate::common::gpu::setup()?;
 
     let is_outside_max = |position: (i16, i16)| {
@@ -22,7 +25,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
             .filter_map(move |(x, c)| (c == b'#').then_some((x as i16 + 72, y as i16 + 24)))
     }) {
         if is_outside_max((x, y)) {
-            return Err(format!("Elf is outside of [0,{MAX_SIZE})"));
+            return Err(crate::common::storage::capacity_exceeded());
         }
         elf_data[x as usize + y as usize * MAX_SIZE] = 1;
         num_elves += 1;
@@ -247,7 +250,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
             .write_buffer(&rule_buffer, 0, bytemuck::cast_slice(&[rule_bits]));
         rule_bits = rule_bits.rotate_right(8);
 
-        gpu.queue.submit(std::iter::once(command_encoder.finish()));
+        gpu.queue.submit(core::iter::once(command_encoder.finish()));
 
         if input.is_part_two() {
             let moved_staging_buffer_slice = moved_staging_buffer.slice(..);
@@ -282,7 +285,7 @@ pub fn solve(input: &Input) -> Result<usize, String> {
             0,
             gpu_elf_buffer_0.size(),
         );
-        gpu.queue.submit(std::iter::once(command_encoder.finish()));
+        gpu.queue.submit(core::iter::once(command_encoder.finish()));
         let debug_slice: wgpu::BufferSlice<'_> = debug_buffer.slice(..);
         debug_slice.map_async(wgpu::MapMode::Read, Result::unwrap);
         gpu.instance.poll_all(true);
@@ -1,4 +1,7 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 #[cfg(feature = "visualization")]
 use super::day24_renderer::Renderer;
@@ -57,7 +60,7 @@ pub fn solve(input: &Input) -> Result<i32, String> {
         let mut prev = if heading_down { top_row_bitmask } else { 0 };
         let last = if heading_down { 0 } else { bottom_row_bitmask };
         for x in 0..valley.width {
-            let prev = std::mem::replace(&mut prev, reachable[x]);
+            let prev = core::mem::replace(&mut prev, reachable[x]);
             let next = reachable.get(x + 1).copied().unwrap_or(last);
 
             // Expand reachable up, down, left and right:
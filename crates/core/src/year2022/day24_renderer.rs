@@ -1,4 +1,7 @@
 use crate::year2022::day24::Valley;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
 use svgplot::{
     SvgCircle, SvgColor, SvgGroup, SvgImage, SvgPath, SvgScript, SvgShape, SvgStyle, SvgTransform,
     SvgUse,
@@ -1,4 +1,6 @@
 use crate::input::{Input, on_error};
+use alloc::format;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     input
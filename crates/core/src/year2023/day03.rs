@@ -1,5 +1,6 @@
 use crate::common::triple_window_iterator::TripleWindowIteratorExt;
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     Ok(input
@@ -1,4 +1,5 @@
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const MAX_WINNING_NUMBERS: usize = 16;
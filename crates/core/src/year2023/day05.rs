@@ -1,5 +1,6 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 type Interval = (i64, i64);
 
@@ -37,7 +38,7 @@ pub fn solve(input: &Input) -> Result<i64, String> {
                 mapped_intervals.push(*source_interval)?;
             }
 
-            std::mem::swap(&mut source_intervals, &mut mapped_intervals);
+            core::mem::swap(&mut source_intervals, &mut mapped_intervals);
             mapped_intervals.clear();
         } else {
             let mut parts = line.split(' ');
@@ -64,7 +65,7 @@ pub fn solve(input: &Input) -> Result<i64, String> {
                 }
             }
 
-            std::mem::swap(&mut source_intervals, &mut scratch_intervals);
+            core::mem::swap(&mut source_intervals, &mut scratch_intervals);
             scratch_intervals.clear();
         }
     }
@@ -88,8 +89,8 @@ fn intersect_intervals(interval_a: Interval, interval_b: Interval) -> [Option<In
     if interval_b.0 > interval_a.1 || interval_a.0 > interval_b.1 {
         [None, None, None]
     } else {
-        let intersection_start = std::cmp::max(interval_a.0, interval_b.0);
-        let intersection_end = std::cmp::min(interval_a.1, interval_b.1);
+        let intersection_start = core::cmp::max(interval_a.0, interval_b.0);
+        let intersection_end = core::cmp::min(interval_a.1, interval_b.1);
         let inside = (intersection_start, intersection_end);
 
         let before = if interval_a.0 < intersection_start {
@@ -1,4 +1,5 @@
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut lines = input.text.lines();
@@ -29,16 +30,16 @@ fn num_wins((duration, record): (&str, &str)) -> u64 {
     //   duration * hold_time - hold_time^2 > record
     // =>
     //   hold_time^2 - duration * hold_time + record < 0
-    // (quadratic equation, with fused multiply-add (FMA) / mul_add()) =>
+    // (quadratic equation, with fused multiply-add (FMA) / fma()) =>
     let b = duration / 2.;
-    let sqrt = b.mul_add(b, -record).sqrt();
+    let sqrt = libm::sqrt(libm::fma(b, b, -record));
     // For floating points a, b, and the open interval [a, b],
     // the integers contained in the interval are: [a.floor() + 1, b.ceil() - 1].
     // The reason for floor() + 1 instead of ceil() (and similarly for b.ceil() - 1 vs floor())
     // is that with a or b being exact integer values, the integer values are not
     // contained in the interval.
-    let min = (b - sqrt).floor() as u64 + 1;
-    let max = (b + sqrt).ceil() as u64 - 1;
+    let min = libm::floor(b - sqrt) as u64 + 1;
+    let max = libm::ceil(b + sqrt) as u64 - 1;
     // +1 to beat the record:
     max - min + 1
 }
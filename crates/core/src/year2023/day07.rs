@@ -1,5 +1,6 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const MAX_HANDS: usize = 1024;
@@ -1,6 +1,8 @@
 use crate::common::array_stack::ArrayStack;
 use crate::common::id_assigner_copy::IdAssigner;
+use crate::common::number_theory::lcm;
 use crate::input::{Input, on_error};
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const MAX_ENTRIES: usize = 1024;
@@ -73,7 +75,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
             let entry = map[current_pos as usize];
             current_pos = if i == b'L' { entry.0 } else { entry.1 };
             if current_pos == END_NODE_ID {
-                result = lcm(result, (step + 1) as u64);
+                result = lcm(result, (step + 1) as u64).ok_or("Too many steps")?;
                 continue 'outer;
             }
         }
@@ -83,19 +85,6 @@ pub fn solve(input: &Input) -> Result<u64, String> {
     Ok(result)
 }
 
-const fn gcd(mut a: u64, mut b: u64) -> u64 {
-    while b != 0 {
-        let tmp = a;
-        a = b;
-        b = tmp % b;
-    }
-    a
-}
-
-const fn lcm(a: u64, b: u64) -> u64 {
-    a * (b / gcd(a, b))
-}
-
 #[test]
 pub fn tests() {
     let test_input = "RL
@@ -109,10 +98,6 @@ GGG = (GGG, GGG)
 ZZZ = (ZZZ, ZZZ)";
     test_part_one_no_allocations!(test_input => 2);
 
-    assert_eq!(lcm(4, 6), 12);
-    assert_eq!(lcm(8, 9), 72);
-    assert_eq!(lcm(1, 72), 72);
-
     let test_input = "LR
 
 11A = (11B, XXX)
@@ -1,5 +1,6 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<i32, String> {
     let mut stack = ArrayStack::<512, i32>::new();
@@ -1,15 +1,38 @@
 use crate::common::array_deque::ArrayDeque;
-use crate::common::u256::U256;
+use crate::common::bitset::BitSet;
+use crate::common::storage::{BitGrid, HeapBitGrid, capacity_exceeded, with_heap_fallback};
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
+
+pub fn solve_fixed(input: &Input) -> Result<u64, String> {
     const MAX_GRID_SIZE: usize = 150;
-    const MAX_STACK_SIZE: usize = 4;
 
     let map = Map::parse(input.text.trim().as_bytes())?;
     if map.num_rows > MAX_GRID_SIZE || map.num_cols > MAX_GRID_SIZE {
-        return Err(format!("Invalid input - max grid size is {MAX_GRID_SIZE}"));
+        return Err(capacity_exceeded());
     }
+    solve_with(
+        input,
+        &map,
+        &mut [BitSet::<{ MAX_GRID_SIZE.div_ceil(64) }>::new(); MAX_GRID_SIZE],
+    )
+}
+
+pub fn solve_heap(input: &Input) -> Result<u64, String> {
+    let map = Map::parse(input.text.trim().as_bytes())?;
+    solve_with(
+        input,
+        &map,
+        &mut HeapBitGrid::new(map.num_cols, map.num_rows),
+    )
+}
+
+fn solve_with(input: &Input, map: &Map, visited: &mut impl BitGrid) -> Result<u64, String> {
+    const MAX_STACK_SIZE: usize = 4;
 
     let start_idx = map
         .bytes
@@ -19,8 +42,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         .ok_or_else(on_error)?;
     let (start_x, start_y) = map.idx_to_xy(start_idx);
 
-    let mut visited_bitmask = [U256::default(); MAX_GRID_SIZE];
-    visited_bitmask[start_y].set_bit(start_x);
+    visited.set_bit(start_x, start_y);
 
     let mut to_visit = ArrayDeque::<MAX_STACK_SIZE, (usize, u64)>::new();
     to_visit.push_back((start_idx, 0))?;
@@ -41,9 +63,9 @@ pub fn solve(input: &Input) -> Result<u64, String> {
                         | (b'S' | b'-' | b'J' | b'7', b'-' | b'L' | b'F', -1, 0)
                         | (b'S' | b'|' | b'F' | b'7', b'|' | b'L' | b'J', 0, 1)
                         | (b'S' | b'|' | b'L' | b'J', b'|' | b'F' | b'7', 0, -1)
-                ) && !visited_bitmask[ny].is_bit_set(nx)
+                ) && !visited.is_bit_set(nx, ny)
                 {
-                    visited_bitmask[ny].set_bit(nx);
+                    visited.set_bit(nx, ny);
                     max_distance = distance + 1;
                     to_visit.push_back((map.xy_to_idx(nx, ny), max_distance))?;
                 }
@@ -56,10 +78,10 @@ pub fn solve(input: &Input) -> Result<u64, String> {
     }
 
     let mut inside_loop_count = 0;
-    for (y, bitset) in visited_bitmask.iter_mut().enumerate() {
+    for y in 0..map.num_rows {
         let mut inside_loop = false;
         for x in 0..map.num_cols {
-            if bitset.is_bit_set(x) {
+            if visited.is_bit_set(x, y) {
                 let b = map.get(x, y);
                 if matches!(b, b'|' | b'L' | b'J')
                     || (b == b'S' && (y != 0 && matches!(map.get(x, y - 1), b'|' | b'7' | b'F')))
@@ -114,6 +136,7 @@ impl<'a> Map<'a> {
 
 #[test]
 pub fn tests() {
+    use alloc::format;
     let test_input = ".....
 .S-7.
 .|.|.
@@ -150,7 +173,26 @@ LJ...";
 ..........";
     test_part_two_no_allocations!(test_input => 4);
 
+    assert_eq!(solve_heap(&Input::part_two(test_input)), Ok(4));
+
+    // A rectangular loop too big for the fixed-capacity grid:
+    let (width, height) = (300, 200);
+    let mut big_input = format!("S{}7\n", "-".repeat(width - 2));
+    for _ in 2..height {
+        big_input.push_str(&format!("|{}|\n", ".".repeat(width - 2)));
+    }
+    big_input.push_str(&format!("L{}J", "-".repeat(width - 2)));
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_fixed(&Input::part_one(big_input)),
+        Err(capacity_exceeded())
+    );
+    test_part_one!(big_input => (width + height - 2) as u64);
+    test_part_two!(big_input => ((width - 2) * (height - 2)) as u64);
+
     let real_input = include_str!("day10_input.txt");
     test_part_one_no_allocations!(real_input => 6875);
     test_part_two_no_allocations!(real_input => 471);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(6875));
+    assert_eq!(solve_heap(&Input::part_two(real_input)), Ok(471));
 }
@@ -1,12 +1,27 @@
 use crate::common::array_stack::ArrayStack;
-use crate::common::u256::U256;
+use crate::common::bitset::BitSet;
 use crate::input::Input;
+use crate::params::Param;
+use alloc::format;
+use alloc::string::String;
+
+const EXPANSION_FACTOR: Param = Param {
+    name: "expansion_factor",
+    description: "How many times larger each empty row and column becomes (part 2)",
+    default: 1_000_000,
+    min: 1,
+    max: 1_000_000,
+};
+pub const PARAMS: [Param; 1] = [EXPANSION_FACTOR];
 
 pub fn solve(input: &Input) -> Result<i64, String> {
     const MAX_GRID_SIZE: usize = 256;
     const MAX_GALAXIES: usize = 512;
 
-    let (mut rows, mut cols) = (U256::default(), U256::default());
+    let (mut rows, mut cols) = (
+        BitSet::<{ MAX_GRID_SIZE / 64 }>::new(),
+        BitSet::<{ MAX_GRID_SIZE / 64 }>::new(),
+    );
     for (row_idx, row) in input.text.lines().enumerate() {
         for (col_idx, b) in row.bytes().enumerate() {
             if b == b'#' {
@@ -19,7 +34,7 @@ pub fn solve(input: &Input) -> Result<i64, String> {
         }
     }
 
-    let empty_expansion = input.part_values(1, 999_999);
+    let empty_expansion = input.part_values(1, input.param(&EXPANSION_FACTOR) as u32 - 1);
     let mut galaxies = ArrayStack::<MAX_GALAXIES, (u32, u32)>::new();
     let mut row_offset = 0;
     for (row_idx, row) in input.text.lines().enumerate() {
@@ -61,6 +76,8 @@ pub fn tests() {
 .......#..
 #...#.....";
     test_part_one_no_allocations!(test_input => 374);
+    test_part_two!(test_input, [("expansion_factor", 10)] => 1030);
+    test_part_two!(test_input, [("expansion_factor", 100)] => 8410);
 
     let real_input = include_str!("day11_input.txt");
     test_part_one_no_allocations!(real_input => 10_422_930);
@@ -1,5 +1,6 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<usize, String> {
     let expected_smudges = input.part_values(0, 1);
@@ -1,7 +1,7 @@
 use crate::common::array_stack::ArrayStack;
+use crate::common::cycle::brent;
 use crate::input::Input;
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut moving = ArrayStack::<128, u128>::new();
@@ -34,51 +34,29 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         return Ok(total_load(moving.slice()));
     }
 
-    let mut hashes = ArrayStack::<256, u64>::new();
-    hashes.push(calculate_hash(moving.slice()))?;
+    let spin = |moving: &mut ArrayStack<128, u128>| {
+        spin_cycle(num_cols, moving.slice_mut(), fixed.slice());
+    };
+    let cycle = brent(&moving, spin, 1000).ok_or("No cycle found")?;
+    for _ in 0..cycle.equivalent_step(1_000_000_000) {
+        spin(&mut moving);
+    }
+    Ok(total_load(moving.slice()))
+}
 
-    let mut remaining = 1_000_000_000;
-    loop {
-        for x in 0..num_cols {
-            move_dir(0, 1, x, 0, num_cols, moving.slice_mut(), fixed.slice());
-        }
-        for y in 0..moving.len() {
-            move_dir(1, 0, 0, y, num_cols, moving.slice_mut(), fixed.slice());
-        }
-        for x in 0..num_cols {
-            move_dir(
-                0,
-                -1,
-                x,
-                moving.len() - 1,
-                num_cols,
-                moving.slice_mut(),
-                fixed.slice(),
-            );
-        }
-        for y in 0..moving.len() {
-            move_dir(
-                -1,
-                0,
-                num_cols - 1,
-                y,
-                num_cols,
-                moving.slice_mut(),
-                fixed.slice(),
-            );
-        }
-        let hash = calculate_hash(moving.slice());
-        remaining -= 1;
-        for i in 0..hashes.len() {
-            if hashes.elements[i] == hash {
-                let cycle_length = hashes.len() - i;
-                remaining %= cycle_length;
-            }
-        }
-        hashes.push(hash)?;
-        if remaining == 0 {
-            return Ok(total_load(moving.slice()));
-        }
+/// Tilts the platform north, west, south and east.
+fn spin_cycle(num_cols: usize, moving: &mut [u128], fixed: &[u128]) {
+    for x in 0..num_cols {
+        move_dir(0, 1, x, 0, num_cols, moving, fixed);
+    }
+    for y in 0..moving.len() {
+        move_dir(1, 0, 0, y, num_cols, moving, fixed);
+    }
+    for x in 0..num_cols {
+        move_dir(0, -1, x, moving.len() - 1, num_cols, moving, fixed);
+    }
+    for y in 0..moving.len() {
+        move_dir(-1, 0, num_cols - 1, y, num_cols, moving, fixed);
     }
 }
 
@@ -126,12 +104,6 @@ fn total_load(moving: &[u128]) -> u64 {
         .sum()
 }
 
-fn calculate_hash(t: &[u128]) -> u64 {
-    let mut s = DefaultHasher::new();
-    t.hash(&mut s);
-    s.finish()
-}
-
 #[test]
 pub fn tests() {
     let test_input = "O....#....
@@ -1,5 +1,6 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut up = ArrayStack::<128, u128>::new();
@@ -1,6 +1,12 @@
-use crate::common::array_stack::ArrayStack;
+use alloc::collections::BinaryHeap;
+use alloc::string::{String, ToString};
+use core::cmp::Reverse;
+
+use crate::common::bitset::BitSet;
 use crate::common::priority_queueu::PriorityQueue;
-use crate::common::u256::U256;
+use crate::common::storage::{
+    BitGrid, HeapBitGrid, MinQueue, capacity_exceeded, with_heap_fallback,
+};
 use crate::input::{Input, on_error};
 
 const WORK_QUEUE_MAX_SIZE: usize = 40_000;
@@ -15,19 +21,59 @@ const MAX_GRID_SIZE: usize = 192;
 const DIRECTION_AND_AMOUNT_SIZE: usize = 0b11_110;
 const VISITED_SIZE: usize = MAX_GRID_SIZE * DIRECTION_AND_AMOUNT_SIZE;
 
-#[allow(clippy::similar_names)]
+/// Cost plus heuristic, cost, x, y and steps, with coordinates of type `C`.
+type WorkItem<C> = (u16, u16, C, C, StepsInDirection);
+
 pub fn solve(input: &Input) -> Result<u16, String> {
-    let part2 = input.is_part_two();
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
+
+pub fn solve_fixed(input: &Input) -> Result<u16, String> {
+    let map = Map::parse(input.text.as_bytes())?;
+    if map.num_cols > BitSet::<4>::CAPACITY || map.num_rows > MAX_GRID_SIZE {
+        return Err(capacity_exceeded());
+    }
+    solve_with(
+        input,
+        &map,
+        &mut [BitSet::<4>::new(); VISITED_SIZE],
+        MAX_GRID_SIZE,
+        &mut PriorityQueue::<{ WORK_QUEUE_MAX_SIZE }, WorkItem<u8>>::new(),
+    )
+}
 
+pub fn solve_heap(input: &Input) -> Result<u16, String> {
     let map = Map::parse(input.text.as_bytes())?;
+    // Keep costs, which are at most 9 per step along the found path, within an u16:
+    if (map.num_cols + map.num_rows) * 9 * 2 > usize::from(u16::MAX) {
+        return Err("Too big map".to_string());
+    }
+    solve_with(
+        input,
+        &map,
+        &mut HeapBitGrid::new(map.num_cols, map.num_rows * DIRECTION_AND_AMOUNT_SIZE),
+        map.num_rows,
+        &mut BinaryHeap::<Reverse<WorkItem<u16>>>::new(),
+    )
+}
+
+/// Searches with visited states stored in `visited`, with `rows_per_state` rows for each
+/// combination of direction and amount of steps.
+#[allow(clippy::similar_names)]
+fn solve_with<C: Copy + Into<usize> + TryFrom<usize>>(
+    input: &Input,
+    map: &Map,
+    visited: &mut impl BitGrid,
+    rows_per_state: usize,
+    to_visit: &mut impl MinQueue<WorkItem<C>>,
+) -> Result<u16, String> {
+    let part2 = input.is_part_two();
+    let coordinate = |value: usize| C::try_from(value).map_err(|_| capacity_exceeded());
+
     if map.num_cols < 4 || map.num_rows < 4 {
         return Err("Too small map".to_string());
     }
 
-    let mut visited = ArrayStack::<{ VISITED_SIZE }, U256>::with_len(VISITED_SIZE);
-    let mut to_visit =
-        PriorityQueue::<{ WORK_QUEUE_MAX_SIZE }, (u16, u16, u8, u8, StepsInDirection)>::new();
-
     let initial = if part2 { 4_usize } else { 1 };
     for (x, y, direction) in [
         (initial, 0, Direction::Right),
@@ -48,23 +94,24 @@ pub fn solve(input: &Input) -> Result<u16, String> {
         to_visit.push((
             cost_plus_heuristic,
             cost,
-            x as u8,
-            y as u8,
+            coordinate(x)?,
+            coordinate(y)?,
             steps_in_direction,
         ))?;
     }
 
     while let Some((_, cost, x, y, steps)) = to_visit.pop() {
-        let array_offset = steps.array_offset(i16::from(y));
-        if visited.elements[array_offset].is_bit_set(x as usize) {
+        let (x, y): (usize, usize) = (x.into(), y.into());
+        let array_offset = steps.array_offset(y as i16, rows_per_state);
+        if visited.is_bit_set(x, array_offset) {
             continue;
         }
 
-        if x as usize == map.num_cols - 1 && y as usize == map.num_rows - 1 {
+        if x == map.num_cols - 1 && y == map.num_rows - 1 {
             return Ok(cost);
         }
 
-        visited.elements[array_offset].set_bit(x as usize);
+        visited.set_bit(x, array_offset);
 
         for turn in [0, 1, 2] {
             let multiplier = if turn != 0 && part2 { 4 } else { 1 };
@@ -85,7 +132,7 @@ pub fn solve(input: &Input) -> Result<u16, String> {
                 _ => direction.turn_right(),
             };
             let (dx, dy) = new_direction.xy();
-            let (x, y) = (i16::from(x), i16::from(y));
+            let (x, y) = (x as i16, y as i16);
             let nx = x + dx * i16::from(multiplier);
             let ny = y + dy * i16::from(multiplier);
             if nx < 0 || nx as usize >= map.num_cols || ny < 0 || ny as usize >= map.num_rows {
@@ -105,13 +152,13 @@ pub fn solve(input: &Input) -> Result<u16, String> {
                 };
 
             let new_steps = StepsInDirection::new(new_num_steps, new_direction, part2);
-            if !visited.elements[new_steps.array_offset(ny)].is_bit_set(nx_usize) {
+            if !visited.is_bit_set(nx_usize, new_steps.array_offset(ny, rows_per_state)) {
                 let new_cost_plus_heuristic = new_cost + map.heuristic(nx_usize, ny_usize);
                 to_visit.push((
                     new_cost_plus_heuristic,
                     new_cost,
-                    nx as u8,
-                    ny as u8,
+                    coordinate(nx_usize)?,
+                    coordinate(ny_usize)?,
                     new_steps,
                 ))?;
             }
@@ -157,7 +204,7 @@ impl Direction {
     }
 }
 
-#[derive(PartialOrd, PartialEq, Copy, Clone, Default)]
+#[derive(PartialOrd, Ord, PartialEq, Eq, Copy, Clone, Default)]
 struct StepsInDirection {
     bits: u8,
 }
@@ -184,8 +231,8 @@ impl StepsInDirection {
     const fn direction(self) -> Direction {
         Direction::from_idx((self.bits & 0b11) as i8)
     }
-    const fn array_offset(self, y: i16) -> usize {
-        (self.bits as usize) * MAX_GRID_SIZE + (y as usize)
+    const fn array_offset(self, y: i16, rows_per_state: usize) -> usize {
+        (self.bits as usize) * rows_per_state + (y as usize)
     }
 }
 
@@ -227,6 +274,7 @@ impl<'a> Map<'a> {
 
 #[test]
 pub fn tests() {
+    use alloc::vec;
     let steps_in_direction = StepsInDirection::new(2, Direction::Down, false);
     assert_eq!(steps_in_direction.num_steps(false), 2);
     assert!(steps_in_direction.direction() == Direction::Down);
@@ -260,7 +308,23 @@ pub fn tests() {
 999999999991";
     test_part_two_no_allocations!(test_input => 71);
 
+    assert_eq!(solve_heap(&Input::part_two(test_input)), Ok(71));
+
+    // A map too big for the fixed-capacity storage, where the cheapest
+    // paths cost the manhattan distance to the lower right corner:
+    let (width, height) = (300, 250);
+    let big_input = vec!["1".repeat(width); height].join("\n");
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_fixed(&Input::part_one(big_input)),
+        Err(capacity_exceeded())
+    );
+    test_part_one!(big_input => (width + height - 2) as u16);
+    test_part_two!(big_input => (width + height - 2) as u16);
+
     let real_input = include_str!("day17_input.txt");
     test_part_one_no_allocations!(real_input => 1039);
     test_part_two_no_allocations!(real_input => 1201);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(1039));
+    assert_eq!(solve_heap(&Input::part_two(real_input)), Ok(1201));
 }
@@ -1,4 +1,5 @@
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<i64, String> {
     let (mut x, mut y) = (0, 0);
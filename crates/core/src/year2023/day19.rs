@@ -1,6 +1,7 @@
 use crate::common::array_stack::ArrayStack;
 use crate::common::id_assigner::IdAssigner;
 use crate::input::{Input, on_error};
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut workflows = [Workflow::default(); MAX_WORKFLOWS];
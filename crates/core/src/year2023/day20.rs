@@ -1,12 +1,24 @@
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use hashbrown::HashMap;
+
 use crate::common::array_deque::ArrayDeque;
+use crate::common::cycle::Cycle;
 use crate::common::id_assigner::IdAssigner;
+use crate::common::storage::with_heap_fallback;
 use crate::input::{Input, on_error};
 
+const MAX_ITERATIONS: u64 = 10_000;
+const EXPECTED_NUM_RX_TOGGLES: u32 = 4;
+
 pub fn solve(input: &Input) -> Result<u64, String> {
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
+
+pub fn solve_fixed(input: &Input) -> Result<u64, String> {
     const MAX_MODULES: usize = 64;
-    const MAX_ITERATIONS: u64 = 10_000;
     const MAX_WORK_QUEUE_SIZE: usize = 128;
-    const EXPECTED_NUM_RX_TOGGLES: u32 = 4;
 
     let mut id_assigner = IdAssigner::<MAX_MODULES, str>::new("");
     let mut is_flip_flop = 0_u64;
@@ -16,7 +28,12 @@ pub fn solve(input: &Input) -> Result<u64, String> {
     let mut num_sources = [0_u32; MAX_MODULES];
     let mut destinations = [0_u64; MAX_MODULES];
     let (mut sent_lows, mut sent_highs) = (0, 0);
-    let mut cycle_length = 1_u64;
+    // The sources of the 'rx' emitter are separate counters, each sending a low pulse
+    // once per cycle of button presses, so 'rx' receives one when their cycles align:
+    let mut cycle = Cycle {
+        start: 0,
+        period: 1,
+    };
     let mut found_cycles_bitset = 0_u32;
     let mut rx_emitter_idx = usize::MAX;
     let mut rx_emitter_sources = 0_u64;
@@ -86,9 +103,14 @@ pub fn solve(input: &Input) -> Result<u64, String> {
                 let bit = 1 << src_idx;
                 if found_cycles_bitset & bit == 0 {
                     found_cycles_bitset |= bit;
-                    cycle_length *= button_presses + 1;
+                    cycle = cycle
+                        .combine(&Cycle {
+                            start: 0,
+                            period: button_presses + 1,
+                        })
+                        .ok_or("Too many button presses")?;
                     if found_cycles_bitset.count_ones() == EXPECTED_NUM_RX_TOGGLES {
-                        return Ok(cycle_length);
+                        return Ok(cycle.period);
                     }
                 }
             }
@@ -132,8 +154,161 @@ pub fn solve(input: &Input) -> Result<u64, String> {
     Err("No solution found".to_string())
 }
 
+/// Solves the input like [solve_fixed], but with modules stored in vectors instead of bitsets
+/// limiting their number.
+pub fn solve_heap(input: &Input) -> Result<u64, String> {
+    #[derive(Default)]
+    struct Module {
+        is_flip_flop: bool,
+        enabled: bool,
+        /// The destination modules, with the index of this module among their sources.
+        destinations: Vec<(usize, usize)>,
+        /// For a conjunction, if the last pulse from each of its sources was high.
+        remembered_highs: Vec<bool>,
+        num_remembered_highs: usize,
+    }
+
+    fn id_of<'a>(
+        name: &'a str,
+        ids: &mut HashMap<&'a str, usize>,
+        modules: &mut Vec<Module>,
+    ) -> usize {
+        *ids.entry(name).or_insert_with(|| {
+            modules.push(Module::default());
+            modules.len() - 1
+        })
+    }
+
+    let mut ids = HashMap::new();
+    let mut modules = Vec::new();
+    let mut broadcaster_idx = 0;
+    let mut rx_emitter_idx = None;
+
+    for line in input.text.lines() {
+        let (src_module_name, destinations_str) = line.split_once(" -> ").ok_or_else(on_error)?;
+        let src_module_idx = id_of(&src_module_name[1..], &mut ids, &mut modules);
+
+        if src_module_name == "broadcaster" {
+            broadcaster_idx = src_module_idx;
+        } else if src_module_name.as_bytes()[0] == b'%' {
+            modules[src_module_idx].is_flip_flop = true;
+        };
+
+        for destination_name in destinations_str.split(", ") {
+            let destination_idx = id_of(destination_name, &mut ids, &mut modules);
+            let source_idx = modules[destination_idx].remembered_highs.len();
+            modules[destination_idx].remembered_highs.push(false);
+            modules[src_module_idx]
+                .destinations
+                .push((destination_idx, source_idx));
+            if destination_name == "rx" && input.is_part_two() {
+                if rx_emitter_idx.is_some() {
+                    return Err("Multiple emitters to 'rx'".to_string());
+                }
+                rx_emitter_idx = Some(src_module_idx);
+            }
+        }
+    }
+
+    let mut rx_emitter_sources = Vec::new();
+    if input.is_part_two() {
+        let Some(rx_emitter_idx) = rx_emitter_idx else {
+            return Err("No emitter to 'rx'".to_string());
+        };
+        if modules[rx_emitter_idx].is_flip_flop {
+            return Err("The emitter to 'rx' is not a conjunction".to_string());
+        }
+        for (i, module) in modules.iter().enumerate() {
+            if module
+                .destinations
+                .iter()
+                .any(|&(destination_idx, _)| destination_idx == rx_emitter_idx)
+            {
+                if module.is_flip_flop {
+                    return Err(
+                        "Not all input sources to the 'rx' emitter module are conjunctions"
+                            .to_string(),
+                    );
+                }
+                rx_emitter_sources.push((i, false));
+            }
+        }
+        if rx_emitter_sources.len() != EXPECTED_NUM_RX_TOGGLES as usize {
+            return Err("Not four emitters to 'rx'".to_string());
+        }
+    }
+
+    let (mut sent_lows, mut sent_highs) = (0, 0);
+    let mut cycle = Cycle {
+        start: 0,
+        period: 1,
+    };
+    let mut num_found_cycles = 0;
+    let mut work_queue = VecDeque::new();
+
+    for button_presses in 0..MAX_ITERATIONS {
+        if input.is_part_one() && button_presses == 1000 {
+            return Ok(sent_lows * sent_highs);
+        }
+
+        work_queue.push_back((broadcaster_idx, 0, false));
+
+        while let Some((dest_idx, source_idx, high_pulse)) = work_queue.pop_front() {
+            if input.is_part_one() {
+                sent_highs += u64::from(high_pulse);
+                sent_lows += u64::from(!high_pulse);
+            } else if !high_pulse
+                && let Some((_, found)) = rx_emitter_sources
+                    .iter_mut()
+                    .find(|(idx, found)| *idx == dest_idx && !*found)
+            {
+                *found = true;
+                num_found_cycles += 1;
+                cycle = cycle
+                    .combine(&Cycle {
+                        start: 0,
+                        period: button_presses + 1,
+                    })
+                    .ok_or("Too many button presses")?;
+                if num_found_cycles == rx_emitter_sources.len() {
+                    return Ok(cycle.period);
+                }
+            }
+
+            let module = &mut modules[dest_idx];
+            let emit_high = if dest_idx == broadcaster_idx {
+                false
+            } else if module.is_flip_flop {
+                if high_pulse {
+                    continue;
+                }
+                module.enabled = !module.enabled;
+                module.enabled
+            } else {
+                let remembered_high = &mut module.remembered_highs[source_idx];
+                if *remembered_high != high_pulse {
+                    *remembered_high = high_pulse;
+                    if high_pulse {
+                        module.num_remembered_highs += 1;
+                    } else {
+                        module.num_remembered_highs -= 1;
+                    }
+                }
+                module.num_remembered_highs != module.remembered_highs.len()
+            };
+
+            for &(destination_idx, source_idx) in &module.destinations {
+                work_queue.push_back((destination_idx, source_idx, emit_high));
+            }
+        }
+    }
+
+    Err("No solution found".to_string())
+}
+
 #[test]
 pub fn tests() {
+    use alloc::format;
     let test_input = "broadcaster -> a, b, c
 %a -> b
 %b -> c
@@ -147,7 +322,34 @@ pub fn tests() {
 &con -> output";
     test_part_one_no_allocations!(test_input => 11_687_500);
 
+    assert_eq!(solve_heap(&Input::part_one(test_input)), Ok(11_687_500));
+
+    // More modules than fit into the fixed-capacity bitsets. Each button press sends a
+    // low pulse to the broadcaster, which sends low pulses to all flip-flops, which send
+    // high pulses to the output on odd presses and low pulses on even ones:
+    let num_flip_flops = 100;
+    let names = (0..num_flip_flops)
+        .map(|i| format!("f{i}"))
+        .collect::<Vec<_>>();
+    let mut big_input = format!("broadcaster -> {}", names.join(", "));
+    for name in &names {
+        big_input.push_str(&format!("\n%{name} -> output"));
+    }
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_fixed(&Input::part_one(big_input)),
+        Err(crate::common::storage::capacity_exceeded())
+    );
+    let sent_lows = 1000 * (1 + num_flip_flops) + 500 * num_flip_flops;
+    let sent_highs = 500 * num_flip_flops;
+    test_part_one!(big_input => sent_lows * sent_highs);
+
     let real_input = include_str!("day20_input.txt");
     test_part_one_no_allocations!(real_input => 812_721_756);
     test_part_two_no_allocations!(real_input => 233_338_595_643_977);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(812_721_756));
+    assert_eq!(
+        solve_heap(&Input::part_two(real_input)),
+        Ok(233_338_595_643_977)
+    );
 }
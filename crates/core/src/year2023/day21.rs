@@ -1,27 +1,77 @@
+use alloc::collections::VecDeque;
+use alloc::format;
+use alloc::string::String;
+
 use crate::common::array_deque::ArrayDeque;
-use crate::common::array_stack::ArrayStack;
-use crate::common::u256::U256;
+use crate::common::bitset::BitSet;
+use crate::common::storage::{BitGrid, HeapBitGrid, Queue, capacity_exceeded, with_heap_fallback};
 use crate::input::Input;
+use crate::params::Param;
+
+const STEPS: Param = Param {
+    name: "steps",
+    description: "The number of steps the elf takes (part 1)",
+    default: 64,
+    min: 0,
+    max: 1_000,
+};
+const STEPS_PART_TWO: Param = Param {
+    name: "steps_part_two",
+    description: "The number of steps the elf takes, ending at the edge of a garden map copy (part 2)",
+    default: 26_501_365,
+    min: 0,
+    max: 1_000_000_000,
+};
+pub const PARAMS: [Param; 2] = [STEPS, STEPS_PART_TWO];
 
 const MAX_GRID_SIZE: usize = 192;
 
-type Grid = ArrayStack<MAX_GRID_SIZE, U256>;
 type Point = (i32, i32);
 
-/// Solution adapted from @maneatingape - https://github.com/maneatingape/advent-of-code-rust/blob/main/src/year2023/day21.rs
 pub fn solve(input: &Input) -> Result<u64, String> {
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
+
+pub fn solve_fixed(input: &Input) -> Result<u64, String> {
+    let (width, height) = grid_size(input.text);
+    if width > BitSet::<4>::CAPACITY || height > MAX_GRID_SIZE {
+        return Err(capacity_exceeded());
+    }
+    solve_with(
+        input,
+        [BitSet::<4>::new(); MAX_GRID_SIZE],
+        &mut ArrayDeque::<512, (Point, u32)>::new(),
+    )
+}
+
+pub fn solve_heap(input: &Input) -> Result<u64, String> {
+    // The searches assume a square grid, so make room for that:
+    let (width, height) = grid_size(input.text);
+    let size = width.max(height);
+    solve_with(input, HeapBitGrid::new(size, size), &mut VecDeque::new())
+}
+
+fn grid_size(text: &str) -> (usize, usize) {
+    let width = text.lines().map(str::len).max().unwrap_or_default();
+    (width, text.lines().count())
+}
+
+/// Solution adapted from @maneatingape - https://github.com/maneatingape/advent-of-code-rust/blob/main/src/year2023/day21.rs
+fn solve_with<G: BitGrid + Clone>(
+    input: &Input,
+    mut rocks: G,
+    todo: &mut impl Queue<(Point, u32)>,
+) -> Result<u64, String> {
     // Bit set if cell is a rock, represented by a '#'.
-    let mut rocks = ArrayStack::<MAX_GRID_SIZE, U256>::new();
     let mut grid_width = 0;
     let mut start_position = None;
 
     for (y, row_str) in input.text.lines().enumerate() {
-        let mut rock_row = U256::default();
         for (col_idx, col_byte) in row_str.bytes().enumerate() {
             grid_width = col_idx as i32 + 1;
             match col_byte {
                 b'#' => {
-                    rock_row.set_bit(col_idx);
+                    rocks.set_bit(col_idx, y);
                 }
                 b'S' => {
                     start_position = Some((col_idx as i32, y as i32));
@@ -29,22 +79,43 @@ pub fn solve(input: &Input) -> Result<u64, String> {
                 _ => {}
             }
         }
-        rocks.push(rock_row)?;
     }
 
     let Some(start_position) = start_position else {
         return Err("No start position".into());
     };
 
-    // Search from the center tile outwards.
-    let num_steps = 130;
-    let (even_inner, even_outer, odd_inner, odd_outer) =
-        bfs(&rocks, grid_width, &[start_position], num_steps)?;
-
     if input.is_part_one() {
-        return Ok(even_inner);
+        let num_steps = input.param(&STEPS) as u32;
+        let (even_inner, even_outer, odd_inner, odd_outer) =
+            bfs(&rocks, grid_width, &[start_position], num_steps, todo)?;
+        return Ok(if num_steps.is_multiple_of(2) {
+            even_inner + even_outer
+        } else {
+            odd_inner + odd_outer
+        });
+    }
+
+    if grid_width < 3 {
+        return Err("Too small grid".into());
+    }
+    let num_steps = input.param(&STEPS_PART_TWO) as i32;
+    if num_steps < start_position.1 || (num_steps - start_position.1) % (2 * grid_width) != 0 {
+        return Err(format!(
+            "The number of steps must be {} plus a multiple of twice the grid width",
+            start_position.1
+        ));
     }
 
+    // Search from the center tile outwards.
+    let (even_inner, even_outer, odd_inner, odd_outer) = bfs(
+        &rocks,
+        grid_width,
+        &[start_position],
+        grid_width as u32 - 1,
+        todo,
+    )?;
+
     let even_full = even_inner + even_outer;
     let odd_full = odd_inner + odd_outer;
     let remove_corners = odd_outer;
@@ -56,11 +127,15 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         (0, grid_width - 1),
         (grid_width - 1, grid_width - 1),
     ];
-    let (even_inner, ..) = bfs(&rocks, grid_width, &corners, 64)?;
+    let (even_inner, ..) = bfs(
+        &rocks,
+        grid_width,
+        &corners,
+        grid_width as u32 / 2 - 1,
+        todo,
+    )?;
     let add_corners = even_inner;
 
-    let num_steps = 26501365;
-    //
     let diamond_tile_width = (num_steps - start_position.1) as u64 / grid_width as u64;
 
     // With diamond_tile_width=2:
@@ -105,14 +180,14 @@ pub fn solve(input: &Input) -> Result<u64, String> {
 }
 
 /// Breadth first search from any number of starting locations with a limit on maximum steps.
-fn bfs(
-    grid: &Grid,
+fn bfs<G: BitGrid + Clone>(
+    grid: &G,
     grid_width: i32,
     starts: &[Point],
     limit: u32,
+    todo: &mut impl Queue<(Point, u32)>,
 ) -> Result<(u64, u64, u64, u64), String> {
     let mut grid = grid.clone();
-    let mut todo = ArrayDeque::<512, (Point, u32)>::new();
 
     let mut even_inside = 0;
     let mut even_outside = 0;
@@ -120,7 +195,7 @@ fn bfs(
     let mut odd_outside = 0;
 
     for &start in starts {
-        grid.elements[start.1 as usize].set_bit(start.0 as usize);
+        grid.set_bit(start.0 as usize, start.1 as usize);
         todo.push_back((start, 0))?;
     }
 
@@ -142,9 +217,9 @@ fn bfs(
                     && next.0 < grid_width
                     && next.1 >= 0
                     && next.1 < grid_width
-                    && !grid.elements[next.1 as usize].is_bit_set(next.0 as usize)
+                    && !grid.is_bit_set(next.0 as usize, next.1 as usize)
                 {
-                    grid.elements[next.1 as usize].set_bit(next.0 as usize);
+                    grid.set_bit(next.0 as usize, next.1 as usize);
                     todo.push_back((next, cost + 1))?;
                 }
             }
@@ -156,7 +231,58 @@ fn bfs(
 
 #[test]
 pub fn tests() {
+    use alloc::vec;
+    let test_input = "...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........";
+    test_part_one!(test_input, [("steps", 6)] => 16);
+
+    let open_input = "...........
+...........
+...........
+...........
+...........
+.....S.....
+...........
+...........
+...........
+...........
+...........";
+    test_part_one!(open_input, [("steps", 3)] => 16);
+    test_part_two!(open_input, [("steps_part_two", 5 + 2 * 11)] => 28 * 28);
+    test_part_two!(open_input, [("steps_part_two", 5 + 4 * 11)] => 50 * 50);
+
+    assert_eq!(
+        solve_heap(&Input::part_two(open_input).with_params(&[("steps_part_two", 27)])),
+        Ok(28 * 28)
+    );
+
+    // An open grid too big for the fixed-capacity storage:
+    let mut rows = vec![".".repeat(203); 203];
+    rows[101].replace_range(101..102, "S");
+    let big_input = rows.join("\n");
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_fixed(&Input::part_one(big_input)),
+        Err(capacity_exceeded())
+    );
+    test_part_one!(big_input => 65 * 65);
+    test_part_two!(big_input, [("steps_part_two", 101 + 2 * 203)] => 508 * 508);
+
     let real_input = include_str!("day21_input.txt");
     test_part_one_no_allocations!(real_input => 3773);
     test_part_two_no_allocations!(real_input => 625_628_021_226_274);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(3773));
+    assert_eq!(
+        solve_heap(&Input::part_two(real_input)),
+        Ok(625_628_021_226_274)
+    );
 }
@@ -1,7 +1,8 @@
 use crate::common::array_deque::ArrayDeque;
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
-use std::cmp::Ordering;
+use alloc::string::String;
+use core::cmp::Ordering;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let mut bricks = ArrayStack::<1500, Brick>::new();
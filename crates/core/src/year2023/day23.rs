@@ -1,6 +1,7 @@
 use crate::common::array_stack::ArrayStack;
 use crate::common::id_assigner_copy::IdAssigner;
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u16, String> {
     const MAX_COMPACTED_GRAPH_LEN: usize = 60;
@@ -1,7 +1,8 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
-use std::ops::RangeInclusive;
+use core::ops::RangeInclusive;
 
 const RANGE: RangeInclusive<i64> = 200_000_000_000_000..=400_000_000_000_000;
 
@@ -1,6 +1,9 @@
 use crate::input::Input;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
 
-use std::collections::VecDeque;
+use alloc::collections::VecDeque;
 
 /// Store the graph as an [adjacency list](https://en.wikipedia.org/wiki/Adjacency_list).
 /// Each node has a unique index in the `nodes` vec.
@@ -1,5 +1,6 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut left_list = ArrayStack::<1024, u32>::new();
@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     Ok(if input.is_part_one() {
@@ -1,5 +1,6 @@
-use crate::common::u256::U256;
+use crate::common::bitset::BitSet;
 use crate::input::{Input, on_error};
+use alloc::string::{String, ToString};
 
 const DIRECTIONS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
 const JUMP_MAP_WIDTH: usize = 192;
@@ -21,9 +22,9 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         return Err("Invalid input - not a rectangle".to_string());
     }
 
-    let mut visited = [U256::default(); 192];
+    let mut visited = [BitSet::<4>::new(); 192];
     // Indexed by direction idx:
-    let mut repeated_visit = [[U256::default(); 192]; 4];
+    let mut repeated_visit = [[BitSet::<4>::new(); 192]; 4];
     // Indexed by direction idx - how many positions to jump if not obstacle placed:
     let mut jump_map = [[u8::MAX; JUMP_MAP_WIDTH * JUMP_MAP_WIDTH]; 4];
 
@@ -41,7 +42,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         }
     }
 
-    let mut placed_obstacles = [U256::default(); 192];
+    let mut placed_obstacles = [BitSet::<4>::new(); 192];
     placed_obstacles[initial_position.1 as usize].set_bit(initial_position.0 as usize);
 
     let mut num_loops = 0;
@@ -98,7 +99,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
     }
 
     Ok(if input.is_part_one() {
-        visited.iter().map(U256::count_ones).sum()
+        visited.iter().map(BitSet::count_ones).sum()
     } else {
         num_loops
     })
@@ -107,7 +108,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
 fn does_movements_repeat(
     grid: &Grid,
     jump_map: &mut [[u8; JUMP_MAP_WIDTH * JUMP_MAP_WIDTH]; 4],
-    repeated_visit: &[[U256; 192]; 4],
+    repeated_visit: &[[BitSet<4>; 192]; 4],
     obstacle_position: (i32, i32),
     mut current_position: (i32, i32),
     mut current_direction_idx: usize,
@@ -1,5 +1,6 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let width = input
@@ -18,7 +19,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
 
     let mut visited = [0_u64; 64];
     let mut antennas: [ArrayStack<10, (u8, u8)>; u8::MAX as usize] =
-        std::array::from_fn(|_idx| ArrayStack::<10, (u8, u8)>::new());
+        core::array::from_fn(|_idx| ArrayStack::<10, (u8, u8)>::new());
 
     for y in 0..grid.width {
         for x in 0..grid.width {
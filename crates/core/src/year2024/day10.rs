@@ -1,4 +1,5 @@
 use crate::input::{Input, on_error};
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let width = input
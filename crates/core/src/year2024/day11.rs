@@ -1,4 +1,6 @@
-use std::collections::HashMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use hashbrown::HashMap;
 
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
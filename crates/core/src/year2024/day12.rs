@@ -1,5 +1,6 @@
-use crate::common::u256::U256;
+use crate::common::bitset::BitSet;
 use crate::input::{Input, on_error};
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let width = input
@@ -18,7 +19,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         return Err("Invalid input - too big rectangle".to_string());
     }
 
-    let mut visited = [U256::default(); 192];
+    let mut visited = [BitSet::<4>::new(); 192];
 
     let mut sum = 0;
     for y in 0..grid.width {
@@ -59,7 +60,7 @@ fn visit_region(
     current_region_type: u8,
     position: (i32, i32),
     grid: &Grid,
-    visited: &mut [U256; 192],
+    visited: &mut [BitSet<4>; 192],
     part1: bool,
 ) -> (/*area*/ i32, /*perimeter*/ i32) {
     #![allow(clippy::unusual_byte_groupings)]
@@ -1,4 +1,5 @@
 use crate::input::{Input, on_error};
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<i64, String> {
     let offset = input.part_values(0, 10_000_000_000_000);
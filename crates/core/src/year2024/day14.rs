@@ -1,15 +1,36 @@
 use crate::{
     common::array_stack::ArrayStack,
     input::{Input, on_error},
+    params::Param,
 };
+use alloc::format;
+use alloc::string::String;
+
+const SPACE_WIDTH: Param = Param {
+    name: "width",
+    description: "The width of the space the robots move in",
+    default: 101,
+    min: 1,
+    max: 101,
+};
+const SPACE_HEIGHT: Param = Param {
+    name: "height",
+    description: "The height of the space the robots move in",
+    default: 103,
+    min: 1,
+    max: 103,
+};
+pub const PARAMS: [Param; 2] = [SPACE_WIDTH, SPACE_HEIGHT];
 
 pub fn solve(input: &Input) -> Result<u32, String> {
-    const SPACE_WIDTH: i32 = 101;
-    const SPACE_HEIGHT: i32 = 103;
-    const MIDDLE_X: i32 = SPACE_WIDTH / 2;
-    const MIDDLE_Y: i32 = SPACE_HEIGHT / 2;
+    const MAX_WIDTH: usize = SPACE_WIDTH.max as usize;
+    const MAX_HEIGHT: usize = SPACE_HEIGHT.max as usize;
     const MAX_TIME_PART2: usize = 10_000;
 
+    let space_width = input.param(&SPACE_WIDTH) as i32;
+    let space_height = input.param(&SPACE_HEIGHT) as i32;
+    let (middle_x, middle_y) = (space_width / 2, space_height / 2);
+
     let mut robots = ArrayStack::<512, (i8, i8, i8, i8)>::new();
 
     for line in input.text.lines() {
@@ -26,37 +47,38 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         let mut quadrants = [0; 4];
         for &(p_x, p_y, v_x, v_y) in robots.slice() {
             let (p_x, p_y, v_x, v_y) = (p_x as i32, p_y as i32, v_x as i32, v_y as i32);
-            let f_x = (p_x + v_x * 100).rem_euclid(SPACE_WIDTH);
-            let f_y = (p_y + v_y * 100).rem_euclid(SPACE_HEIGHT);
-            if f_x != MIDDLE_X && f_y != MIDDLE_Y {
-                let part_x = f_x / ((SPACE_WIDTH + 1) / 2);
-                let part_y = f_y / ((SPACE_HEIGHT + 1) / 2);
+            let f_x = (p_x + v_x * 100).rem_euclid(space_width);
+            let f_y = (p_y + v_y * 100).rem_euclid(space_height);
+            if f_x != middle_x && f_y != middle_y {
+                let part_x = f_x / ((space_width + 1) / 2);
+                let part_y = f_y / ((space_height + 1) / 2);
                 quadrants[part_y as usize * 2 + part_x as usize] += 1;
             }
         }
         Ok(quadrants.iter().product())
     } else {
         // Thanks https://github.com/maneatingape/advent-of-code-rust/blob/main/src/year2024/day14.rs
-        let mut x_over_time = [[0_i8; 500]; SPACE_WIDTH as usize];
-        let mut y_over_time = [[0_i8; 500]; SPACE_HEIGHT as usize];
+        let mut x_over_time = [[0_i8; 500]; MAX_WIDTH];
+        let mut y_over_time = [[0_i8; 500]; MAX_HEIGHT];
         for (time, row) in x_over_time.iter_mut().enumerate() {
             for (i, (x, _, dx, _)) in robots.slice().iter().enumerate() {
-                row[i] = (*x as i32 + *dx as i32 * time as i32).rem_euclid(SPACE_WIDTH) as i8;
+                row[i] = (*x as i32 + *dx as i32 * time as i32).rem_euclid(space_width) as i8;
             }
         }
         for (time, row) in y_over_time.iter_mut().enumerate() {
             for (i, (_, y, _, dy)) in robots.slice().iter().enumerate() {
-                row[i] = (*y as i32 + *dy as i32 * time as i32).rem_euclid(SPACE_HEIGHT) as i8;
+                row[i] = (*y as i32 + *dy as i32 * time as i32).rem_euclid(space_height) as i8;
             }
         }
 
-        let mut visited = [0_usize; (SPACE_WIDTH * SPACE_HEIGHT) as usize];
+        let mut visited = [0_usize; MAX_WIDTH * MAX_HEIGHT];
         'time: for time in 1..MAX_TIME_PART2 {
-            for (&x, &y) in x_over_time[time % (SPACE_WIDTH as usize)]
+            for (&x, &y) in x_over_time[time % (space_width as usize)]
                 .iter()
-                .zip(y_over_time[time % (SPACE_HEIGHT as usize)].iter())
+                .zip(y_over_time[time % (space_height as usize)].iter())
+                .take(robots.len())
             {
-                let idx = y as usize + (x as usize) * (SPACE_WIDTH as usize);
+                let idx = x as usize + (y as usize) * (space_width as usize);
                 if visited[idx] == time {
                     continue 'time;
                 }
@@ -80,6 +102,20 @@ fn parse_it<'a, I: Iterator<Item = &'a str>>(it: &mut I) -> Result<i8, String> {
 
 #[test]
 pub fn tests() {
+    let test_input = "p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3";
+    test_part_one!(test_input, [("width", 11), ("height", 7)] => 12);
+
     let real_input = include_str!("day14_input.txt");
     test_part_one_no_allocations!(real_input => 226_236_192);
     test_part_two_no_allocations!(real_input => 8_168);
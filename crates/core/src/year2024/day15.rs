@@ -1,6 +1,7 @@
 use crate::common::array_stack::ArrayStack;
 use crate::input::{Input, on_error};
-use std::mem::swap;
+use alloc::string::String;
+use core::mem::swap;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
     let (grid_str, moves) = input.text.split_once("\n\n").ok_or_else(on_error)?;
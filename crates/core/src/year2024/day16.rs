@@ -1,27 +1,66 @@
+use alloc::collections::{BinaryHeap, VecDeque};
+use alloc::string::{String, ToString};
+use alloc::vec;
+
 use crate::common::array_deque::ArrayDeque;
+use crate::common::bitset::BitSet;
 use crate::common::priority_queueu::PriorityQueue;
-use crate::common::u256::U256;
+use crate::common::storage::{
+    BitGrid, HeapBitGrid, MinQueue, Queue, capacity_exceeded, with_heap_fallback,
+};
 use crate::input::{Input, on_error};
 
 const MAX_GRID_SIZE: usize = 142;
 const WORK_QUEUE_MAX_SIZE: usize = 8000;
 
+type Position = (i16, i16);
+
 pub fn solve(input: &Input) -> Result<u32, String> {
-    let width = input
-        .text
-        .lines()
-        .next()
-        .map(|line| line.len())
-        .ok_or_else(on_error)? as i16;
-    let grid = Grid {
-        s: input.text.as_bytes(),
-        width,
-    };
-    if grid.s.len() != ((grid.width + 1) * grid.width - 1) as usize {
-        return Err("Invalid input - not a rectangle".to_string());
-    } else if grid.width >= MAX_GRID_SIZE as i16 {
-        return Err("Invalid input - too big rectangle".to_string());
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
+
+pub fn solve_fixed(input: &Input) -> Result<u32, String> {
+    let grid = Grid::parse(input.text)?;
+    if grid.width >= MAX_GRID_SIZE as i16 {
+        return Err(capacity_exceeded());
     }
+    solve_with(
+        input,
+        &grid,
+        &mut [u32::MAX; 4 * MAX_GRID_SIZE * MAX_GRID_SIZE],
+        &mut PriorityQueue::<{ WORK_QUEUE_MAX_SIZE }, (u32, Position, Direction)>::new(),
+        &mut [BitSet::<{ MAX_GRID_SIZE.div_ceil(64) }>::new(); MAX_GRID_SIZE],
+        &mut ArrayDeque::<128, (i32, Position, Direction)>::new(),
+    )
+}
+
+pub fn solve_heap(input: &Input) -> Result<u32, String> {
+    let grid = Grid::parse(input.text)?;
+    let width = grid.width as usize;
+    solve_with(
+        input,
+        &grid,
+        &mut vec![u32::MAX; 4 * width * width],
+        &mut BinaryHeap::new(),
+        &mut HeapBitGrid::new(width, width),
+        &mut VecDeque::new(),
+    )
+}
+
+/// Searches with the lowest cost of each position and direction stored in `costs`, consisting
+/// of equally sized parts for each direction.
+fn solve_with(
+    input: &Input,
+    grid: &Grid,
+    costs: &mut [u32],
+    to_visit: &mut impl MinQueue<(u32, Position, Direction)>,
+    visited: &mut impl BitGrid,
+    to_visit_backwards: &mut impl Queue<(i32, Position, Direction)>,
+) -> Result<u32, String> {
+    let cells = costs.len() / 4;
+    let cost_idx = |direction: Direction, position: Position| {
+        direction.idx() * cells + position.1 as usize * grid.width as usize + position.0 as usize
+    };
 
     let mut start_location = (0, 0);
     let mut end_location = (0, 0);
@@ -40,12 +79,8 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         return Err("No end location".to_string());
     }
 
-    let mut costs = [[u32::MAX; MAX_GRID_SIZE * MAX_GRID_SIZE]; 4];
-    let mut to_visit =
-        PriorityQueue::<{ WORK_QUEUE_MAX_SIZE }, (u32, (i16, i16), Direction)>::new();
-
-    costs[Direction::East.idx()][(start_location.1 * grid.width + start_location.0) as usize] = 0;
-    to_visit.push((0, start_location, Direction::East)).unwrap();
+    costs[cost_idx(Direction::East, start_location)] = 0;
+    to_visit.push((0, start_location, Direction::East))?;
 
     let mut lowest_end_cost = u32::MAX;
     'outer: while let Some((cost, position, direction)) = to_visit.pop() {
@@ -54,11 +89,10 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             (cost + 1000, position, direction.rotate(false)),
             (cost + 1, direction.advance(position), direction),
         ] {
-            let best_cost = costs[next_direction.idx()]
-                [(next_position.1 * grid.width + next_position.0) as usize];
-            if grid.at(next_position) != b'#' && next_cost < best_cost {
-                costs[next_direction.idx()]
-                    [(next_position.1 * grid.width + next_position.0) as usize] = next_cost;
+            if grid.at(next_position) != b'#'
+                && next_cost < costs[cost_idx(next_direction, next_position)]
+            {
+                costs[cost_idx(next_direction, next_position)] = next_cost;
                 if next_position == end_location {
                     if input.is_part_one() {
                         return Ok(next_cost);
@@ -69,9 +103,7 @@ pub fn solve(input: &Input) -> Result<u32, String> {
                     }
                 }
 
-                to_visit
-                    .push((next_cost, next_position, next_direction))
-                    .unwrap();
+                to_visit.push((next_cost, next_position, next_direction))?;
             }
         }
     }
@@ -80,19 +112,15 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         return Err("No solution found".to_string());
     }
 
-    let mut visited = [U256::default(); MAX_GRID_SIZE];
-    let mut to_visit = ArrayDeque::<128, (i32, (i16, i16), Direction)>::new();
-
-    visited[end_location.1 as usize].set_bit(end_location.0 as usize);
+    let to_visit = to_visit_backwards;
+    visited.set_bit(end_location.0 as usize, end_location.1 as usize);
     for direction in [
         Direction::North,
         Direction::East,
         Direction::South,
         Direction::West,
     ] {
-        if costs[direction.idx()][(end_location.1 * grid.width + end_location.0) as usize]
-            != u32::MAX
-        {
+        if costs[cost_idx(direction, end_location)] != u32::MAX {
             to_visit.push_back((lowest_end_cost as i32, end_location, direction))?;
         }
     }
@@ -103,18 +131,16 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             (cost - 1000, position, direction.rotate(true)),
             (cost - 1000, position, direction.rotate(false)),
         ] {
-            let seen_cost = costs[next_direction.idx()]
-                [(next_position.1 * grid.width + next_position.0) as usize];
+            let seen_cost = costs[cost_idx(next_direction, next_position)];
             if seen_cost != u32::MAX && next_cost == seen_cost as i32 {
-                visited[next_position.1 as usize].set_bit(next_position.0 as usize);
+                visited.set_bit(next_position.0 as usize, next_position.1 as usize);
                 to_visit.push_back((next_cost, next_position, next_direction))?;
-                costs[next_direction.idx()]
-                    [(next_position.1 * grid.width + next_position.0) as usize] = u32::MAX;
+                costs[cost_idx(next_direction, next_position)] = u32::MAX;
             }
         }
     }
 
-    Ok(visited.iter().map(|b| b.count_ones()).sum())
+    Ok(visited.count_ones())
 }
 
 struct Grid<'a> {
@@ -122,13 +148,25 @@ struct Grid<'a> {
     width: i16,
 }
 
-impl Grid<'_> {
+impl<'a> Grid<'a> {
+    fn parse(text: &'a str) -> Result<Self, String> {
+        let width = text.lines().next().map(str::len).ok_or_else(on_error)?;
+        let grid = Self {
+            s: text.as_bytes(),
+            width: i16::try_from(width).map_err(|_| "Invalid input - too big rectangle")?,
+        };
+        if grid.s.len() != (width + 1) * width - 1 {
+            return Err("Invalid input - not a rectangle".to_string());
+        }
+        Ok(grid)
+    }
+
     const fn at(&self, position: (i16, i16)) -> u8 {
         if position.0 < 0 || position.0 >= self.width || position.1 < 0 || position.1 >= self.width
         {
             return b'@';
         }
-        self.s[(position.0 + (self.width + 1) * position.1) as usize]
+        self.s[position.0 as usize + (self.width as usize + 1) * position.1 as usize]
     }
 }
 
@@ -177,6 +215,7 @@ impl Direction {
 
 #[test]
 pub fn tests() {
+    use alloc::format;
     let test_input = "###############
 #.......#....E#
 #.#.###.#.###.#
@@ -203,7 +242,28 @@ pub fn tests() {
 #######";
     test_part_two_no_allocations!(test_input => 12);
 
+    assert_eq!(solve_heap(&Input::part_two(test_input)), Ok(12));
+
+    // An open room too big for the fixed-capacity storage, where the best path goes
+    // east along the bottom wall and then turns north along the right wall:
+    let size = 200;
+    let mut rows = vec![format!("#{}#", ".".repeat(size - 2)); size];
+    rows[0] = "#".repeat(size);
+    rows[size - 1] = "#".repeat(size);
+    rows[1].replace_range(size - 2..size - 1, "E");
+    rows[size - 2].replace_range(1..2, "S");
+    let big_input = rows.join("\n");
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_fixed(&Input::part_one(big_input)),
+        Err(capacity_exceeded())
+    );
+    test_part_one!(big_input => 2 * (size as u32 - 3) + 1000);
+    test_part_two!(big_input => 2 * (size as u32 - 3) + 1);
+
     let real_input = include_str!("day16_input.txt");
     test_part_one_no_allocations!(real_input => 90_440);
     test_part_two_no_allocations!(real_input => 479);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(90_440));
+    assert_eq!(solve_heap(&Input::part_two(real_input)), Ok(479));
 }
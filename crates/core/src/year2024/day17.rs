@@ -1,6 +1,11 @@
 use crate::common::array_deque::ArrayDeque;
 use crate::common::array_stack::ArrayStack;
+use crate::common::vm::{Budget, Machine, Step};
 use crate::input::{Input, on_error};
+use alloc::format;
+use alloc::string::{String, ToString};
+
+const MAX_INSTRUCTIONS: u64 = 1_000_000;
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let mut program = ArrayStack::<32, u8>::new();
@@ -25,7 +30,8 @@ pub fn solve(input: &Input) -> Result<String, String> {
 
     if input.is_part_one() {
         let mut result = String::new();
-        while let Some(output) = computer.run_for_output() {
+        let mut budget = Budget::new(MAX_INSTRUCTIONS);
+        while let Some(output) = computer.run_until_output(&mut budget)? {
             if !result.is_empty() {
                 result.push(',');
             }
@@ -62,8 +68,8 @@ pub fn solve(input: &Input) -> Result<String, String> {
                 let register_a_next_three_bits = (register_a_bits_so_far << 3) | first_three_bits;
                 computer.registers[0] = register_a_next_three_bits;
                 computer.instruction_pointer = 0;
-                if computer.run_for_output()
-                    == Some(computer.program[offset_from_end as usize - 1] as u64)
+                if computer.run_until_output(&mut Budget::new(MAX_INSTRUCTIONS))?
+                    == Some(u64::from(computer.program[offset_from_end as usize - 1]))
                 {
                     if offset_from_end - 1 == 0 {
                         return Ok(format!("{register_a_next_three_bits}"));
@@ -84,63 +90,68 @@ struct Computer<'a> {
 }
 
 impl Computer<'_> {
-    fn combo_operand(&self) -> u64 {
+    fn combo_operand(&self) -> Result<u64, String> {
         let val = self.program[self.instruction_pointer + 1];
         match val {
-            0..=3 => val as u64,
-            4..=6 => self.registers[val as usize - 4],
-            _ => {
-                unreachable!();
-            }
+            0..=3 => Ok(u64::from(val)),
+            4..=6 => Ok(self.registers[val as usize - 4]),
+            _ => Err(format!("Invalid combo operand: {val}")),
         }
     }
 
-    const fn literal_operand(&self) -> u64 {
-        self.program[self.instruction_pointer + 1] as u64
+    fn literal_operand(&self) -> u64 {
+        u64::from(self.program[self.instruction_pointer + 1])
     }
+}
 
-    fn run_for_output(&mut self) -> Option<u64> {
-        loop {
-            if (self.instruction_pointer + 1) >= self.program.len() {
-                return None;
-            }
-            let instruction_val = self.program[self.instruction_pointer];
-            match instruction_val {
+impl Machine for Computer<'_> {
+    type Output = u64;
+    type State = (usize, [u64; 3]);
+
+    fn step(&mut self) -> Result<Step<u64>, String> {
+        if (self.instruction_pointer + 1) >= self.program.len() {
+            return Ok(Step::Halted);
+        }
+        let mut step = Step::Executed;
+        let instruction_val = self.program[self.instruction_pointer];
+        match instruction_val {
             0 /* adv */ => {
-                self.registers[0] >>= self.combo_operand() as u32;
+                self.registers[0] = self.registers[0].checked_shr(self.combo_operand()? as u32).unwrap_or(0);
             }
             1 /* bxl */ => {
                 self.registers[1] ^= self.literal_operand();
             }
             2 /* bst */ => {
-                self.registers[1] = self.combo_operand() % 8;
+                self.registers[1] = self.combo_operand()? % 8;
             }
             3 /* jnz */ => {
                 if self.registers[0] != 0 {
                     self.instruction_pointer = self.literal_operand() as usize;
-                    continue;
+                    return Ok(step);
                 }
             }
             4 /* bxc */ => {
                 self.registers[1] ^= self.registers[2];
             }
             5 /* out */ => {
-                let result = Some(self.combo_operand() % 8);
-                self.instruction_pointer += 2;
-                return result;
+                step = Step::Output(self.combo_operand()? % 8);
             }
             6 /* bdv */ => {
-                self.registers[1] = self.registers[0] >> self.combo_operand() as u32;
+                self.registers[1] = self.registers[0].checked_shr(self.combo_operand()? as u32).unwrap_or(0);
             }
             7 /* cdv */ => {
-                self.registers[2] = self.registers[0] >> self.combo_operand() as u32;
+                self.registers[2] = self.registers[0].checked_shr(self.combo_operand()? as u32).unwrap_or(0);
             }
             _ => {
-                return None;
+                return Err(format!("Invalid opcode: {instruction_val}"));
             }
         }
-            self.instruction_pointer += 2;
-        }
+        self.instruction_pointer += 2;
+        Ok(step)
+    }
+
+    fn state(&self) -> Self::State {
+        (self.instruction_pointer, self.registers)
     }
 }
 
@@ -1,6 +1,8 @@
 use crate::common::array_deque::ArrayDeque;
 use crate::common::priority_queueu::PriorityQueue;
 use crate::input::{Input, on_error};
+use alloc::format;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<String, String> {
     let mut grid = [[u16::MAX; 71]; 71];
@@ -1,4 +1,6 @@
 use crate::input::{Input, on_error};
+use alloc::format;
+use alloc::string::{String, ToString};
 
 const MAX_TRIE_NODES: usize = 1024;
 const MAX_DESIGN_LEN: usize = 64;
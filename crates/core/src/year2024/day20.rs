@@ -1,5 +1,10 @@
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec;
+
 use crate::{
     common::array_deque::ArrayDeque,
+    common::storage::{Queue, capacity_exceeded, with_heap_fallback},
     input::{Input, on_error},
 };
 
@@ -7,21 +12,45 @@ const MAX_GRID_SIZE: usize = 150;
 const WORK_QUEUE_MAX_SIZE: usize = 1024;
 
 pub fn solve(input: &Input) -> Result<u32, String> {
-    let width = input
-        .text
-        .lines()
-        .next()
-        .map(|line| line.len())
-        .ok_or_else(on_error)? as i16;
-    let grid = Grid {
-        s: input.text.as_bytes(),
-        width,
-    };
-    if grid.s.len() != ((grid.width + 1) * grid.width - 1) as usize {
-        return Err("Invalid input - not a rectangle".to_string());
-    } else if grid.width >= MAX_GRID_SIZE as i16 {
-        return Err("Invalid input - too big rectangle".to_string());
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
+
+pub fn solve_fixed(input: &Input) -> Result<u32, String> {
+    let grid = Grid::parse(input.text)?;
+    if grid.width >= MAX_GRID_SIZE as i16 {
+        return Err(capacity_exceeded());
     }
+    solve_with(
+        input,
+        &grid,
+        &mut [u32::MAX; MAX_GRID_SIZE * MAX_GRID_SIZE],
+        MAX_GRID_SIZE,
+        &mut ArrayDeque::<{ WORK_QUEUE_MAX_SIZE }, (u32, (i16, i16))>::new(),
+    )
+}
+
+pub fn solve_heap(input: &Input) -> Result<u32, String> {
+    let grid = Grid::parse(input.text)?;
+    let width = grid.width as usize;
+    solve_with(
+        input,
+        &grid,
+        &mut vec![u32::MAX; width * width],
+        width,
+        &mut VecDeque::new(),
+    )
+}
+
+/// Solves with the cost of reaching each position stored in `costs`, which has rows of
+/// length `row_length`.
+fn solve_with(
+    input: &Input,
+    grid: &Grid,
+    costs: &mut [u32],
+    row_length: usize,
+    to_visit: &mut impl Queue<(u32, (i16, i16))>,
+) -> Result<u32, String> {
+    let cost_idx = |(x, y): (i16, i16)| y as usize * row_length + x as usize;
 
     let mut start_location = (0, 0);
     for y in 0..grid.width {
@@ -35,15 +64,13 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         return Err("No start location".to_string());
     }
 
-    let mut costs = [[u16::MAX; MAX_GRID_SIZE]; MAX_GRID_SIZE];
-    let mut to_visit = ArrayDeque::<{ WORK_QUEUE_MAX_SIZE }, (u16, (i16, i16))>::new();
     to_visit.push_back((0, start_location))?;
 
     while let Some((cost, position)) = to_visit.pop_front() {
-        if costs[position.1 as usize][position.0 as usize] <= cost {
+        if costs[cost_idx(position)] <= cost {
             continue;
         }
-        costs[position.1 as usize][position.0 as usize] = cost;
+        costs[cost_idx(position)] = cost;
         for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
             let next = (position.0 + dx, position.1 + dy);
             if grid.at(next) != b'#' {
@@ -57,8 +84,8 @@ pub fn solve(input: &Input) -> Result<u32, String> {
     let mut num_great_cheats = 0;
     for y in 1..(grid.width - 1) {
         for x in 1..(grid.width - 1) {
-            let cost = costs[y as usize][x as usize];
-            if cost == u16::MAX {
+            let cost = costs[cost_idx((x, y))];
+            if cost == u32::MAX {
                 continue;
             }
             for dy in -diamond_size..=diamond_size {
@@ -66,8 +93,8 @@ pub fn solve(input: &Input) -> Result<u32, String> {
                     let manhattan_distance = dx.abs() + dy.abs();
                     let cheat = (x + dx, y + dy);
                     if grid.at(cheat) != b'#'
-                        && let Some(gain) = costs[cheat.1 as usize][cheat.0 as usize]
-                            .checked_sub(cost + manhattan_distance as u16)
+                        && let Some(gain) =
+                            costs[cost_idx(cheat)].checked_sub(cost + manhattan_distance as u32)
                         && gain >= 100
                     {
                         num_great_cheats += 1;
@@ -85,19 +112,53 @@ struct Grid<'a> {
     width: i16,
 }
 
-impl Grid<'_> {
+impl<'a> Grid<'a> {
+    fn parse(text: &'a str) -> Result<Self, String> {
+        let width = text.lines().next().map(str::len).ok_or_else(on_error)?;
+        let grid = Self {
+            s: text.as_bytes(),
+            width: i16::try_from(width).map_err(|_| "Invalid input - too big rectangle")?,
+        };
+        if grid.s.len() != (width + 1) * width - 1 {
+            return Err("Invalid input - not a rectangle".to_string());
+        }
+        Ok(grid)
+    }
+
     const fn at(&self, position: (i16, i16)) -> u8 {
         if position.0 < 0 || position.0 >= self.width || position.1 < 0 || position.1 >= self.width
         {
             return b'#';
         }
-        self.s[(position.0 + (self.width + 1) * position.1) as usize]
+        self.s[position.0 as usize + (self.width as usize + 1) * position.1 as usize]
     }
 }
 
 #[test]
 pub fn tests() {
+    use alloc::format;
+    // A track too big for the fixed-capacity storage, going east along the second row,
+    // turning down and then going west along the fourth row, so that cheats save time by
+    // passing the third row:
+    let size = 200;
+    let mut rows = vec!["#".repeat(size); size];
+    rows[1] = format!("#S{}#", ".".repeat(size - 3));
+    rows[2].replace_range(size - 2..size - 1, ".");
+    rows[3] = format!("#E{}#", ".".repeat(size - 3));
+    let big_input = rows.join("\n");
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_fixed(&Input::part_one(big_input)),
+        Err(capacity_exceeded())
+    );
+    // A cheat from x1 on the second row to x2 on the fourth one saves at least 100
+    // picoseconds if both are at most size-52, for part two within a distance of 18:
+    test_part_one!(big_input => 148);
+    test_part_two!(big_input => 148 * 37 - 2 * (1..=18).sum::<u32>());
+
     let real_input = include_str!("day20_input.txt");
     test_part_one_no_allocations!(real_input => 1338);
     test_part_two_no_allocations!(real_input => 975_376);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(1338));
+    assert_eq!(solve_heap(&Input::part_two(real_input)), Ok(975_376));
 }
@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use alloc::string::String;
+use hashbrown::HashMap;
 
 use crate::common::permutation::all_permutations;
 use crate::input::{Input, on_error};
@@ -1,4 +1,7 @@
+use crate::common::parallel;
 use crate::input::{Input, on_error};
+use alloc::string::String;
+use core::sync::atomic::{AtomicU16, Ordering};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const HASH_BASE: u32 = 18;
@@ -7,45 +10,65 @@ pub fn solve(input: &Input) -> Result<u64, String> {
 
     let part2 = input.is_part_two();
 
-    let mut secret_num_sum = 0;
-    let mut sequence_buys = [0_u16; HASH_MAX_VAL];
+    // Buyers are independent, so are split into interleaved groups of lines handled in
+    // parallel - with the bananas of each group added to a shared table at the end:
+    let sequence_buys = [const { AtomicU16::new(0) }; HASH_MAX_VAL];
+    let num_groups = parallel::num_threads();
+    let secret_num_sum = parallel::map_reduce(
+        num_groups,
+        |group| {
+            let mut secret_num_sum = 0;
+            let mut group_sequence_buys = [0_u16; HASH_MAX_VAL];
+            for line in input.text.lines().skip(group).step_by(num_groups) {
+                let mut this_sequence_buys = [false; HASH_MAX_VAL];
+                let mut changes = 0_u32;
+                let mut last_banana_value = 0;
 
-    for line in input.text.lines() {
-        let mut this_sequence_buys = [false; HASH_MAX_VAL];
-        let mut changes = 0_u32;
-        let mut last_banana_value = 0;
+                let mut n: u64 = line.parse().map_err(|_| on_error())?;
+                for i in 0..2000 {
+                    n = (n ^ (n << 6)) & 16777215;
+                    n ^= n >> 5;
+                    n = (n ^ (n << 11)) & 16777215;
 
-        let mut n: u64 = line.parse().map_err(|_| on_error())?;
-        for i in 0..2000 {
-            n = (n ^ (n << 6)) & 16777215;
-            n ^= n >> 5;
-            n = (n ^ (n << 11)) & 16777215;
+                    let this_banana_value = (n % 10) as i8;
+                    let banan_value_change = this_banana_value - last_banana_value;
+                    last_banana_value = this_banana_value;
 
-            let this_banana_value = (n % 10) as i8;
-            let banan_value_change = this_banana_value - last_banana_value;
-            last_banana_value = this_banana_value;
+                    changes = ((changes << 5) | ((banan_value_change + 9) as u32))
+                        & 0b11111_11111_11111_11111;
 
-            changes =
-                ((changes << 5) | ((banan_value_change + 9) as u32)) & 0b11111_11111_11111_11111;
-
-            if i >= 3 && part2 {
-                let hash_val = (HASH_BASE.pow(3) * ((changes >> 15) & 0b11111)
-                    + HASH_BASE.pow(2) * ((changes >> 10) & 0b11111)
-                    + HASH_BASE * ((changes >> 5) & 0b11111)
-                    + (changes & 0b11111)) as usize;
-                if !this_sequence_buys[hash_val] {
-                    sequence_buys[hash_val] += this_banana_value as u16;
-                    this_sequence_buys[hash_val] = true;
+                    if i >= 3 && part2 {
+                        let hash_val = (HASH_BASE.pow(3) * ((changes >> 15) & 0b11111)
+                            + HASH_BASE.pow(2) * ((changes >> 10) & 0b11111)
+                            + HASH_BASE * ((changes >> 5) & 0b11111)
+                            + (changes & 0b11111)) as usize;
+                        if !this_sequence_buys[hash_val] {
+                            group_sequence_buys[hash_val] += this_banana_value as u16;
+                            this_sequence_buys[hash_val] = true;
+                        }
+                    }
+                }
+                secret_num_sum += n;
+            }
+            if part2 {
+                for (buys, &group_buys) in sequence_buys.iter().zip(&group_sequence_buys) {
+                    buys.fetch_add(group_buys, Ordering::Relaxed);
                 }
             }
-        }
-        secret_num_sum += n;
-    }
+            Ok(secret_num_sum)
+        },
+        |a: Result<u64, String>, b| Ok(a? + b?),
+    )
+    .unwrap_or(Ok(0))?;
 
     Ok(if input.is_part_one() {
         secret_num_sum
     } else {
-        *sequence_buys.iter().max().unwrap_or(&0) as u64
+        sequence_buys
+            .iter()
+            .map(|buys| buys.load(Ordering::Relaxed))
+            .max()
+            .unwrap_or(0) as u64
     })
 }
 
@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use hashbrown::HashMap;
 
 use crate::common::array_stack::ArrayStack;
 use crate::input::Input;
@@ -1,6 +1,9 @@
 use crate::common::array_stack::ArrayStack;
 use crate::common::id_assigner::IdAssigner;
 use crate::input::Input;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 type Wire = u16;
 
@@ -1,4 +1,6 @@
 use crate::input::{Input, on_error};
+use alloc::format;
+use alloc::string::String;
 
 #[allow(clippy::zero_prefixed_literal)]
 pub fn solve(input: &Input) -> Result<u64, String> {
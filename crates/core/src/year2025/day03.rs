@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::String;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut result = 0;
@@ -1,4 +1,5 @@
 use crate::input::Input;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const MAX_WIDTH: usize = 150;
@@ -29,7 +30,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         if removed == 0 || input.is_part_one() {
             return Ok(total_removed);
         } else {
-            std::mem::swap(&mut grid, &mut grid_ng);
+            core::mem::swap(&mut grid, &mut grid_ng);
         }
     }
 }
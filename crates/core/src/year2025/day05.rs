@@ -2,6 +2,7 @@ use crate::{
     common::array_stack::ArrayStack,
     input::{Input, on_error},
 };
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const MAX_RANGES: usize = 256;
@@ -48,7 +49,7 @@ pub fn solve(input: &Input) -> Result<u64, String> {
     }
 }
 
-// The std::ops::RangeInclusive does not implement Copy, so we make our own version.
+// The core::ops::RangeInclusive does not implement Copy, so we make our own version.
 // https://stackoverflow.com/questions/43416914/why-doesnt-opsranget-implement-copy-even-if-t-is-copy
 #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
 struct RangeInclusiveCopy {
@@ -65,8 +66,8 @@ impl RangeInclusiveCopy {
     }
     fn join_if_overlaps(&self, other: &Self) -> Option<Self> {
         self.overlaps(other).then(|| Self {
-            start: std::cmp::min(self.start, other.start),
-            end: std::cmp::max(self.end, other.end),
+            start: core::cmp::min(self.start, other.start),
+            end: core::cmp::max(self.end, other.end),
         })
     }
 }
@@ -2,6 +2,8 @@ use crate::{
     common::array_stack::ArrayStack,
     input::{Input, on_error},
 };
+use alloc::format;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     const MAX_COLUMNS: usize = 6400;
@@ -1,16 +1,45 @@
 use crate::common::highest_values::HighestValues;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use crate::common::array_stack::ArrayStack;
+use crate::common::storage::{Stack, capacity_exceeded, with_heap_fallback};
 use crate::input::{Input, on_error};
 use crate::year2017::disjoint_set::DisjointSet;
 
+const MAX_POINTS: usize = 1000;
+
 pub fn solve(input: &Input) -> Result<u32, String> {
-    const MAX_POINTS: usize = 1000;
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
+
+pub fn solve_fixed(input: &Input) -> Result<u32, String> {
+    let num_circuits = input.text.lines().count();
+    if num_circuits > MAX_POINTS {
+        return Err(capacity_exceeded());
+    }
+    solve_with(
+        input,
+        &mut ArrayStack::<MAX_POINTS, Point>::new(),
+        &mut DisjointSet::<[i32; MAX_POINTS]>::new(num_circuits),
+    )
+}
 
+pub fn solve_heap(input: &Input) -> Result<u32, String> {
     let num_circuits = input.text.lines().count();
-    let mut circuits = DisjointSet::<MAX_POINTS>::new(num_circuits);
-    let mut points = ArrayStack::<MAX_POINTS, Point>::new();
+    solve_with(
+        input,
+        &mut Vec::with_capacity(num_circuits),
+        &mut DisjointSet::new_heap(num_circuits),
+    )
+}
 
+fn solve_with<E: AsRef<[i32]> + AsMut<[i32]>>(
+    input: &Input,
+    points: &mut impl Stack<Point>,
+    circuits: &mut DisjointSet<E>,
+) -> Result<u32, String> {
+    let num_circuits = input.text.lines().count();
     for line in input.text.lines() {
         let mut parts = line.split(',');
         let x = parts
@@ -32,9 +61,10 @@ pub fn solve(input: &Input) -> Result<u32, String> {
         points.push(point)?;
     }
 
+    let points = points.slice();
     let mut distances = Vec::with_capacity(points.len() * points.len());
-    for (lower_idx, lower_point) in points.slice().iter().enumerate() {
-        for (higher_idx, higher_point) in points.slice().iter().enumerate().skip(lower_idx + 1) {
+    for (lower_idx, lower_point) in points.iter().enumerate() {
+        for (higher_idx, higher_point) in points.iter().enumerate().skip(lower_idx + 1) {
             let distance = lower_point.distance_from(higher_point);
             distances.push((distance, lower_idx, higher_idx));
         }
@@ -47,17 +77,15 @@ pub fn solve(input: &Input) -> Result<u32, String> {
             && input.is_part_two()
             && circuits.num_groups() == 1
         {
-            return Ok(points.slice()[lower_point].x * points.slice()[higher_point].x);
+            return Ok(points[lower_point].x * points[higher_point].x);
         }
 
         connections_made += 1;
         if input.is_part_one() && connections_made == 1000 {
-            let mut inspected_groups = [false; MAX_POINTS];
             let mut biggest_groups = HighestValues::<3>::new();
             for idx in 0..num_circuits {
-                let group = circuits.find(idx);
-                if !inspected_groups[group] {
-                    inspected_groups[group] = true;
+                // Only inspect each group once, through its root:
+                if circuits.find(idx) == idx {
                     let group_size = circuits.size(idx) as u32;
                     biggest_groups.on_value(group_size as u64);
                 }
@@ -85,6 +113,7 @@ impl Point {
 
 #[test]
 pub fn tests() {
+    use alloc::format;
     let test_input = "162,817,812
 57,618,57
 906,360,560
@@ -107,7 +136,24 @@ pub fn tests() {
 425,690,689";
     test_part_two!(test_input => 25272);
 
+    assert_eq!(solve_heap(&Input::part_two(test_input)), Ok(25272));
+
+    // Points along a line, too many for the fixed-capacity storage, where the last
+    // connection needed joins the two points furthest apart:
+    let big_input = (0..1500)
+        .map(|i| format!("{},0,0", i * 10 + if i == 1499 { 5 } else { 0 }))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_fixed(&Input::part_two(big_input)),
+        Err(capacity_exceeded())
+    );
+    test_part_two!(big_input => 14_980 * 14_995);
+
     let real_input = include_str!("day08_input.txt");
     test_part_one!(real_input => 123_930);
     test_part_two!(real_input => 27_338_688);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(123_930));
+    assert_eq!(solve_heap(&Input::part_two(real_input)), Ok(27_338_688));
 }
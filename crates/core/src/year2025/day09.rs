@@ -1,29 +1,41 @@
-use std::collections::BTreeMap;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 
 use crate::{
     common::array_stack::ArrayStack,
+    common::storage::{Stack, with_heap_fallback},
     input::{Input, on_error},
 };
 
 pub fn solve(input: &Input) -> Result<u64, String> {
+    with_heap_fallback(input, solve_fixed, solve_heap)
+}
+
+pub fn solve_fixed(input: &Input) -> Result<u64, String> {
     const MAX_POINTS: usize = 1000;
-    let mut points = ArrayStack::<MAX_POINTS, Point>::new();
+    solve_with(input, &mut ArrayStack::<MAX_POINTS, Point>::new())
+}
 
+pub fn solve_heap(input: &Input) -> Result<u64, String> {
+    solve_with(input, &mut Vec::new())
+}
+
+fn solve_with(input: &Input, points: &mut impl Stack<Point>) -> Result<u64, String> {
     for line in input.text.lines() {
         let (x_str, y_str) = line.split_once(',').ok_or_else(on_error)?;
         let x = x_str.parse::<u32>().map_err(|_| on_error())?;
         let y = y_str.parse::<u32>().map_err(|_| on_error())?;
         points.push(Point { x, y })?;
     }
+    let points = points.slice();
 
     if input.is_part_one() {
         points
-            .slice()
             .iter()
             .enumerate()
             .flat_map(|(lower_idx, lower_point)| {
                 points
-                    .slice()
                     .iter()
                     .skip(lower_idx + 1)
                     .map(|&higher_point| lower_point.rectangle_size(higher_point))
@@ -34,8 +46,8 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         let mut horizontal_lines_by_y: BTreeMap<u32, Vec<Line>> = BTreeMap::new();
         let mut vertical_lines_by_x: BTreeMap<u32, Vec<Line>> = BTreeMap::new();
         for i in 0..points.len() {
-            let p1 = points.elements[i];
-            let p2 = points.elements[(i + 1) % points.len()];
+            let p1 = points[i];
+            let p2 = points[(i + 1) % points.len()];
             if p1.x == p2.x {
                 vertical_lines_by_x.entry(p1.x).or_default().push(Line {
                     start: p1.y.min(p2.y),
@@ -50,8 +62,8 @@ pub fn solve(input: &Input) -> Result<u64, String> {
         }
 
         let mut highest_area = 0;
-        for (lower_idx, &lower_point) in points.slice().iter().enumerate() {
-            'higher: for &higher_point in points.slice().iter().skip(lower_idx + 1) {
+        for (lower_idx, &lower_point) in points.iter().enumerate() {
+            'higher: for &higher_point in points.iter().skip(lower_idx + 1) {
                 let this_area = lower_point.rectangle_size(higher_point);
                 if this_area > highest_area {
                     let rect = Rectangle::from_exclusive(lower_point, higher_point);
@@ -140,6 +152,7 @@ impl Rectangle {
 
 #[test]
 pub fn tests() {
+    use alloc::format;
     let test_input = "7,1
 11,1
 11,7
@@ -151,7 +164,26 @@ pub fn tests() {
     test_part_one!(test_input => 50);
     test_part_two!(test_input => 24);
 
+    assert_eq!(solve_heap(&Input::part_two(test_input)), Ok(24));
+
+    // A square with too many red tiles along its bottom edge for the fixed-capacity storage:
+    let size = 1200;
+    let big_input = (0..=size)
+        .map(|x| format!("{x},0"))
+        .chain([format!("{size},{size}"), format!("0,{size}")])
+        .collect::<Vec<_>>()
+        .join("\n");
+    let big_input = big_input.as_str();
+    assert_eq!(
+        solve_fixed(&Input::part_one(big_input)),
+        Err(crate::common::storage::capacity_exceeded())
+    );
+    test_part_one!(big_input => (size + 1) * (size + 1));
+    test_part_two!(big_input => (size + 1) * (size + 1));
+
     let real_input = include_str!("day09_input.txt");
     test_part_one!(real_input => 4_763_040_296);
     test_part_two!(real_input => 1_396_494_456);
+    assert_eq!(solve_heap(&Input::part_one(real_input)), Ok(4_763_040_296));
+    assert_eq!(solve_heap(&Input::part_two(real_input)), Ok(1_396_494_456));
 }
@@ -2,6 +2,9 @@ use crate::{
     common::{array_deque::ArrayDeque, array_stack::ArrayStack},
     input::{Input, on_error},
 };
+use alloc::string::String;
+use alloc::vec::Vec;
+use alloc::{format, vec};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut total_presses = 0;
@@ -129,7 +132,7 @@ fn simplex(lhs: &[Vec<f64>], c: &[f64]) -> (f64, Option<Vec<f64>>) {
                 }
                 for j in 0..n + 2 {
                     if j != s {
-                        d[i][j] = (d[r][j] * d[i][s]).mul_add(-k, d[i][j]);
+                        d[i][j] = libm::fma(d[r][j] * d[i][s], -k, d[i][j]);
                     }
                 }
             }
@@ -142,7 +145,7 @@ fn simplex(lhs: &[Vec<f64>], c: &[f64]) -> (f64, Option<Vec<f64>>) {
             }
             d[r][s] = k;
 
-            std::mem::swap(&mut b_idx[r], &mut n_idx[s]);
+            core::mem::swap(&mut b_idx[r], &mut n_idx[s]);
         };
 
     let find =
@@ -237,7 +240,7 @@ fn simplex(lhs: &[Vec<f64>], c: &[f64]) -> (f64, Option<Vec<f64>>) {
         }
         let mut sum_val = 0.0;
         for i in 0..n {
-            sum_val = c[i].mul_add(x[i], sum_val);
+            sum_val = libm::fma(c[i], x[i], sum_val);
         }
         return (sum_val, Some(x));
     }
@@ -265,7 +268,7 @@ fn solve_integer_linear_programming_branch_and_bound(
 
         if let Some(x) = x_opt {
             for (i, &xv) in x.iter().enumerate() {
-                if (xv - xv.round()).abs() > EPS {
+                if (xv - libm::round(xv)).abs() > EPS {
                     fractional_idx = Some(i);
                     fractional_val = xv;
                     break;
@@ -273,7 +276,7 @@ fn solve_integer_linear_programming_branch_and_bound(
             }
 
             if let Some(idx) = fractional_idx {
-                let floor_v = fractional_val.floor();
+                let floor_v = libm::floor(fractional_val);
                 let n_cols = current_a[0].len();
 
                 let mut row1 = vec![0.0; n_cols];
@@ -283,7 +286,7 @@ fn solve_integer_linear_programming_branch_and_bound(
                 a1.push(row1);
                 stack.push(a1);
 
-                let ceil_v = fractional_val.ceil();
+                let ceil_v = libm::ceil(fractional_val);
                 let mut row2 = vec![0.0; n_cols];
                 row2[idx] = -1.0;
                 row2[n_cols - 1] = -ceil_v;
@@ -299,7 +302,7 @@ fn solve_integer_linear_programming_branch_and_bound(
     if best_val == INF {
         0
     } else {
-        best_val.round() as i64
+        libm::round(best_val) as i64
     }
 }
 
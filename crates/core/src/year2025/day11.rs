@@ -1,5 +1,7 @@
 use crate::common::tuple_window_iterator::TupleWindowIteratorExt;
-use std::collections::HashMap;
+use alloc::format;
+use alloc::string::{String, ToString};
+use hashbrown::HashMap;
 
 use crate::common::id_assigner;
 use crate::input::Input;
@@ -11,7 +13,7 @@ type Cache = HashMap<(u16, u16), u64>;
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut id_assigner = id_assigner::IdAssigner::<MAX_SIZE, str>::new("");
-    let mut graph: Graph = std::array::from_fn(|_| BitSet::new());
+    let mut graph: Graph = core::array::from_fn(|_| BitSet::new());
     let mut start_position = u16::MAX;
     let start_name = input.part_values("you", "svr");
     let mut out_position = u16::MAX;
@@ -1,4 +1,6 @@
 use crate::input::{Input, on_error};
+use alloc::format;
+use alloc::string::{String, ToString};
 
 pub fn solve(input: &Input) -> Result<u64, String> {
     let mut presents = [PresentShape { bits: 0 }; 6];
@@ -1,4 +1,5 @@
 #include <advent-of-code.hpp>
+#include <advent-of-code-solver.hpp>
 #include <exception>
 #include <iostream>
 #include <string>
@@ -18,6 +19,45 @@ void test(uint16_t year, uint8_t day, uint8_t part, std::string input) {
     //printf("%d-%d-%d: Input='%s' -> ok=%s, output='%s'\n", year, day, part, input, ok ? "true" : "false", message);
 }
 
+std::string describe_error(const aoc::SolverError& e) {
+    std::string kind;
+    if (dynamic_cast<const aoc::InvalidInputError*>(&e)) {
+        kind = "InvalidInputError";
+    } else if (dynamic_cast<const aoc::UnsupportedPuzzleError*>(&e)) {
+        kind = "UnsupportedPuzzleError";
+    } else if (dynamic_cast<const aoc::TimeoutError*>(&e)) {
+        kind = "TimeoutError";
+    } else {
+        kind = "SolverError";
+    }
+    return kind + ": '" + e.what() + "'";
+}
+
+void test_solver(uint16_t year, uint8_t day, uint8_t part, std::string input) {
+    std::cout << "Solver " << year << '-' << (int) day << '-' << (int) part << ": Input='" << input << "' -> ";
+    try {
+        auto result = aoc::Solver::solve(year, day, part, input);
+        // The timing varies between runs, so only check that it is sane:
+        std::cout << "answer='" << std::string(result.answer) << "', timed=" << (result.elapsed_micros < 60000000 ? "true" : "false") << std::endl;
+    } catch (const aoc::SolverError& e) {
+        std::cout << describe_error(e) << std::endl;
+    }
+}
+
+void test_solve_both(uint16_t year, uint8_t day, std::string input) {
+    std::cout << "Solver " << year << '-' << (int) day << ": Input='" << input << "' -> ";
+    try {
+        auto results = aoc::Solver::solve_both(year, day, input);
+        std::cout << "answers=[";
+        for (size_t i = 0; i < results.size(); i++) {
+            std::cout << (i == 0 ? "'" : ", '") << std::string(results[i].answer) << "'";
+        }
+        std::cout << "]" << std::endl;
+    } catch (const aoc::SolverError& e) {
+        std::cout << describe_error(e) << std::endl;
+    }
+}
+
 int main() {
     test(2019, 1, 1, "14");
     test(2019, 1, 1, "hej");
@@ -26,5 +66,23 @@ int main() {
     // test(2019, 1, 1, NULL);
     test(2019, 1, 1, "\xc3\x28");
     test(2021, 7, 2, "16,1,2,0,4,2,7,1,2,14");
+
+    test_solver(2019, 1, 1, "14");
+    test_solver(2019, 1, 1, "hej");
+    test_solver(2019, 1, 1, "\xc3\x28");
+    test_solver(2019, 26, 1, "14");
+    test_solver(2019, 1, 3, "14");
+    test_solver(2017, 18, 1, "jgz 1 0");
+
+    test_solve_both(2019, 1, "1969");
+    test_solve_both(2015, 25, "To continue, please consult the code grid in the manual.  Enter the code at row 1, column 2.");
+    test_solve_both(2019, 1, "hej");
+
+    auto puzzles = aoc::Solver::puzzles();
+    auto& first = puzzles.front();
+    auto& last = puzzles.back();
+    std::cout << "puzzles: " << puzzles.size()
+        << ", first=" << first.year << '-' << (int) first.day << " (" << (int) first.parts << " parts)"
+        << ", last=" << last.year << '-' << (int) last.day << " (" << (int) last.parts << " parts)" << std::endl;
     return 0;
 }
@@ -0,0 +1,83 @@
+#pragma once
+
+// An idiomatic C++ interface over the functions bridged from Rust in advent-of-code.hpp,
+// mapping error kinds to exception classes.
+
+#include <advent-of-code.hpp>
+#include <cstdint>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <vector>
+
+namespace aoc {
+
+/// Base class of exceptions thrown when a problem could not be solved.
+class SolverError : public std::runtime_error {
+public:
+    explicit SolverError(const std::string& message) : std::runtime_error(message) {}
+};
+
+/// Thrown when the input could not be parsed or solved.
+class InvalidInputError : public SolverError {
+public:
+    using SolverError::SolverError;
+};
+
+/// Thrown when there is no solution for the specified year, day and part.
+class UnsupportedPuzzleError : public SolverError {
+public:
+    using SolverError::SolverError;
+};
+
+/// Thrown when the solver gave up after exceeding its computation budget.
+class TimeoutError : public SolverError {
+public:
+    using SolverError::SolverError;
+};
+
+/// A solver of Advent of Code problems.
+class Solver {
+public:
+    /// Solves the specified problem, throwing a SolverError subclass on failure.
+    static SolveResult solve(std::uint16_t year, std::uint8_t day, std::uint8_t part, std::string_view input) {
+        return check(try_solve(year, day, part, as_slice(input)));
+    }
+
+    /// Solves both parts of the specified problem, returning one result for each part.
+    static std::vector<SolveResult> solve_both(std::uint16_t year, std::uint8_t day, std::string_view input) {
+        std::vector<SolveResult> results;
+        for (auto& result : try_solve_both(year, day, as_slice(input))) {
+            results.push_back(check(std::move(result)));
+        }
+        return results;
+    }
+
+    /// Returns the problems which have solutions, ordered by year and day.
+    static std::vector<Puzzle> puzzles() {
+        auto puzzles = ::aoc::puzzles();
+        return std::vector<Puzzle>(puzzles.begin(), puzzles.end());
+    }
+
+private:
+    static rust::Slice<const std::uint8_t> as_slice(std::string_view input) {
+        return rust::Slice<const std::uint8_t>(reinterpret_cast<const std::uint8_t*>(input.data()), input.size());
+    }
+
+    static SolveResult check(SolveResult result) {
+        auto message = std::string(result.error_message);
+        switch (result.error_kind) {
+        case ErrorKind::None:
+            return result;
+        case ErrorKind::InvalidInput:
+            throw InvalidInputError(message);
+        case ErrorKind::UnsupportedPuzzle:
+            throw UnsupportedPuzzleError(message);
+        case ErrorKind::Timeout:
+            throw TimeoutError(message);
+        }
+        throw SolverError(message);
+    }
+};
+
+} // namespace aoc
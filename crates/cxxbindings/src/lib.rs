@@ -1,11 +1,60 @@
+use std::time::Instant;
+
 #[cxx::bridge(namespace = "aoc")]
 mod ffi {
     #![allow(clippy::items_after_statements)]
+
+    /// The kind of error which prevented a problem from being solved.
+    #[derive(Debug)]
+    enum ErrorKind {
+        /// No error - the problem was solved.
+        None,
+        /// The input could not be parsed or solved.
+        InvalidInput,
+        /// There is no solution for the specified year, day and part.
+        UnsupportedPuzzle,
+        /// The solver gave up after exceeding its computation budget.
+        Timeout,
+    }
+
+    /// The outcome of solving a problem.
+    #[derive(Debug)]
+    struct SolveResult {
+        /// The computed answer, or empty if not solved.
+        answer: String,
+        /// The error which prevented the problem from being solved, if any.
+        error_kind: ErrorKind,
+        /// A description of the error, or empty if solved.
+        error_message: String,
+        /// The time spent solving, in microseconds.
+        elapsed_micros: u64,
+    }
+
+    /// A problem which has a solution.
+    #[derive(Debug)]
+    struct Puzzle {
+        /// The year of the problem, as in 2018 or 2019.
+        year: u16,
+        /// The day of the problem, from 1 to 25.
+        day: u8,
+        /// The number of parts: 1 for the last day of a year, otherwise 2.
+        parts: u8,
+    }
+
     extern "Rust" {
         pub fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String>;
+
+        pub fn try_solve(year: u16, day: u8, part: u8, input: &[u8]) -> SolveResult;
+
+        pub fn try_solve_both(year: u16, day: u8, input: &[u8]) -> Vec<SolveResult>;
+
+        pub fn puzzles() -> Vec<Puzzle>;
     }
 }
 
+use advent_of_code_service::{ErrorKind as ServiceErrorKind, SolveError, solve_input};
+use ffi::{ErrorKind, Puzzle, SolveResult};
+
 /// Returns the answer for the specified problem and input.
 ///
 /// Arguments:
@@ -22,3 +71,84 @@ mod ffi {
 fn solve(year: u16, day: u8, part: u8, input: &str) -> Result<String, String> {
     advent_of_code::solve(year, day, part, input)
 }
+
+/// Solves the specified problem without raising exceptions, for `aoc::Solver` to map
+/// errors to exception classes.
+///
+/// The input is received as bytes, as a C++ `std::string` need not be copied to be passed
+/// as a `rust::Slice<const uint8_t>`.
+fn try_solve(year: u16, day: u8, part: u8, input: &[u8]) -> SolveResult {
+    let start = Instant::now();
+    let result = solve_checked(year, day, part, input);
+    let elapsed_micros = u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX);
+    match result {
+        Ok(answer) => SolveResult {
+            answer,
+            error_kind: ErrorKind::None,
+            error_message: String::new(),
+            elapsed_micros,
+        },
+        Err((error_kind, error_message)) => SolveResult {
+            answer: String::new(),
+            error_kind,
+            error_message,
+            elapsed_micros,
+        },
+    }
+}
+
+/// Solves both parts of the specified problem, returning a result for each part.
+///
+/// Solving stops at the first failing part, whose result is then the last one returned.
+fn try_solve_both(year: u16, day: u8, input: &[u8]) -> Vec<SolveResult> {
+    let parts = advent_of_code::puzzles()
+        .find(|p| p.year == year && p.day == day)
+        .map_or(1, |p| p.parts);
+    let mut results = Vec::with_capacity(parts.into());
+    for part in 1..=parts {
+        let result = try_solve(year, day, part, input);
+        let failed = result.error_kind != ErrorKind::None;
+        results.push(result);
+        if failed {
+            break;
+        }
+    }
+    results
+}
+
+fn puzzles() -> Vec<Puzzle> {
+    advent_of_code::puzzles()
+        .map(|p| Puzzle {
+            year: p.year,
+            day: p.day,
+            parts: p.parts,
+        })
+        .collect()
+}
+
+fn solve_checked(
+    year: u16,
+    day: u8,
+    part: u8,
+    input: &[u8],
+) -> Result<String, (ErrorKind, String)> {
+    let into_error = |error: SolveError| {
+        let kind = match error.kind {
+            ServiceErrorKind::InvalidInput | ServiceErrorKind::Cancelled => ErrorKind::InvalidInput,
+            ServiceErrorKind::UnsupportedPuzzle => ErrorKind::UnsupportedPuzzle,
+            ServiceErrorKind::Timeout => ErrorKind::Timeout,
+        };
+        (kind, error.message)
+    };
+
+    if !advent_of_code::is_supported(year, day, part) {
+        return Err(into_error(SolveError::unsupported(year, day, part)));
+    }
+    let input = std::str::from_utf8(input).map_err(|error| {
+        (
+            ErrorKind::InvalidInput,
+            format!("Invalid UTF-8 input: {error}"),
+        )
+    })?;
+    solve_input(year, day, part, input).map_err(into_error)
+}
@@ -16,11 +16,11 @@ fi
 HEADER_PATH=../../../target/cxxbridge/advent-of-code-cxx/src/
 ln -f -s lib.rs.h $HEADER_PATH/advent-of-code.hpp
 
-$CXX -I $HEADER_PATH -L ../../../target/debug/ main.cpp $LIBS -o ../target/example
+$CXX -I $HEADER_PATH -I ../include/ -L ../../../target/debug/ main.cpp $LIBS -o ../target/example
 
 ../target/example > generated-output.txt
 diff -u expected-output.txt generated-output.txt
 
 if [ `uname` = Linux ]; then
-    valgrind  ../target/example
+    valgrind --error-exitcode=1 --leak-check=full ../target/example > /dev/null
 fi
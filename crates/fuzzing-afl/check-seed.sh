@@ -5,8 +5,10 @@ set -u
 cd ../core
 cargo build
 for input in src/year*/day*input.txt; do
-	for year in 2018 2019; do
-		for day in {1..25}; do
+	for year in {2015..2025}; do
+		# Advent of Code has 12 days from 2025:
+		last_day=$(( year >= 2025 ? 12 : 25 ))
+		for day in $(seq 1 $last_day); do
 			for part in 1 2; do
 				echo "$year $day $part < $input"
 				cargo run -q $year $day $part < $input
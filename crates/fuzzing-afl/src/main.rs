@@ -1,16 +1,11 @@
 #[macro_use]
 extern crate afl;
-use advent_of_code::solve;
+use advent_of_code_fuzzing::FuzzCase;
 
 fn main() {
     fuzz!(|data: &[u8]| {
-        if data.len() >= 4
-            && let Ok(input_string) = std::str::from_utf8(&data[3..])
-        {
-            let year = 2015 + u16::from(data[0] % 10);
-            let day = 1 + data[1] % 25;
-            let part = 1 + data[2] % 2;
-            let _ = solve(year, day, part, input_string);
+        if let Some(case) = FuzzCase::from_bytes(data) {
+            let _ = case.solve();
         }
     });
 }
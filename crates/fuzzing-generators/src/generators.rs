@@ -0,0 +1,188 @@
+use arbitrary::{Result, Unstructured};
+use std::fmt::Write;
+
+/// Generates a syntactically valid input for a puzzle.
+pub type Generator = fn(&mut Unstructured<'_>) -> Result<String>;
+
+/// Returns the generator for inputs to the specified puzzle, if there is one.
+pub fn generator_for(year: u16, day: u8) -> Option<Generator> {
+    Some(match (year, day) {
+        (2018, 15) => cave_map,
+        (2019, 2 | 5 | 7 | 9 | 11 | 13 | 15 | 17 | 19 | 21 | 23 | 25) => intcode_program,
+        (2020, 20) => tile_set,
+        (2022, 23) => elf_grove,
+        (2024, 24) => gate_list,
+        _ => return None,
+    })
+}
+
+/// Generates a grid with each cell given by `cell(u, x, y)`.
+fn grid(
+    u: &mut Unstructured<'_>,
+    width: usize,
+    height: usize,
+    mut cell: impl FnMut(&mut Unstructured<'_>, usize, usize) -> Result<u8>,
+) -> Result<String> {
+    let mut result = String::with_capacity((width + 1) * height);
+    for y in 0..height {
+        for x in 0..width {
+            result.push(char::from(cell(u, x, y)?));
+        }
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+/// An Intcode program (2019), consisting of instructions with valid opcodes and
+/// parameter modes, mostly referring to addresses within the program.
+///
+/// Jumps only go forward to the start of an instruction, so that the program does not
+/// loop until running out of its instruction budget (unless it modifies itself).
+pub fn intcode_program(u: &mut Unstructured<'_>) -> Result<String> {
+    // Opcodes with their number of parameters and whether the last one is written to:
+    const INSTRUCTIONS: [(i64, u32, bool); 10] = [
+        (1, 3, true),
+        (2, 3, true),
+        (3, 1, true),
+        (4, 1, false),
+        (5, 2, false),
+        (6, 2, false),
+        (7, 3, true),
+        (8, 3, true),
+        (9, 1, false),
+        (99, 0, false),
+    ];
+
+    let instruction_count = u.int_in_range(1..=64)?;
+    let mut instructions = Vec::with_capacity(instruction_count);
+    let mut starts = vec![0];
+    for _ in 0..instruction_count {
+        let instruction = *u.choose(&INSTRUCTIONS)?;
+        instructions.push(instruction);
+        starts.push(starts[starts.len() - 1] + 1 + i64::from(instruction.1));
+    }
+    let max_address = starts[instruction_count];
+
+    let mut words = Vec::new();
+    for (index, &(opcode, parameter_count, writes)) in instructions.iter().enumerate() {
+        let mut parameters = Vec::new();
+        let mut instruction = opcode;
+        for parameter in 0..parameter_count {
+            let (mode, value) = if matches!(opcode, 5 | 6) && parameter == 1 {
+                (1, *u.choose(&starts[index + 1..])?)
+            } else if writes && parameter == parameter_count - 1 {
+                (*u.choose(&[0, 2])?, u.int_in_range(-8..=max_address)?)
+            } else {
+                (u.int_in_range(0..=2)?, u.int_in_range(-8..=max_address)?)
+            };
+            instruction += mode * 10_i64.pow(parameter + 2);
+            parameters.push(value);
+        }
+        words.push(instruction);
+        words.extend(parameters);
+    }
+
+    let mut result = String::new();
+    for (index, word) in words.iter().enumerate() {
+        if index > 0 {
+            result.push(',');
+        }
+        let _ = write!(result, "{word}");
+    }
+    Ok(result)
+}
+
+/// A cave map (2018 day 15), surrounded by walls with goblins and elves inside.
+pub fn cave_map(u: &mut Unstructured<'_>) -> Result<String> {
+    let width = u.int_in_range(3..=24)?;
+    let height = u.int_in_range(3..=24)?;
+    grid(u, width, height, |u, x, y| {
+        Ok(if x == 0 || y == 0 || x == width - 1 || y == height - 1 {
+            b'#'
+        } else {
+            *u.choose(b".....##GE")?
+        })
+    })
+}
+
+/// A set of image tiles (2020 day 20), with a square number of 10x10 tiles.
+pub fn tile_set(u: &mut Unstructured<'_>) -> Result<String> {
+    let side = u.int_in_range(1..=4)?;
+    let mut result = String::new();
+    for index in 0..side * side {
+        if index > 0 {
+            result.push('\n');
+        }
+        let id = 1000 + 10 * index + u.int_in_range(0..=9)?;
+        let _ = writeln!(result, "Tile {id}:");
+        result.push_str(&grid(u, 10, 10, |u, _, _| Ok(*u.choose(b".#")?))?);
+    }
+    Ok(result)
+}
+
+/// A grove with elves (2022 day 23).
+pub fn elf_grove(u: &mut Unstructured<'_>) -> Result<String> {
+    let width = u.int_in_range(1..=32)?;
+    let height = u.int_in_range(1..=32)?;
+    grid(u, width, height, |u, _, _| Ok(*u.choose(b"..#")?))
+}
+
+/// A list of initial wire values followed by logic gates (2024 day 24). Gates only
+/// use wires defined before them, so that the circuit is free of cycles.
+pub fn gate_list(u: &mut Unstructured<'_>) -> Result<String> {
+    let bits = u.int_in_range(1..=8)?;
+    let mut result = String::new();
+    let mut wires = Vec::new();
+    for prefix in ['x', 'y'] {
+        for bit in 0..bits {
+            let wire = format!("{prefix}{bit:02}");
+            let _ = writeln!(result, "{wire}: {}", u8::from(u.arbitrary::<bool>()?));
+            wires.push(wire);
+        }
+    }
+    result.push('\n');
+
+    let gate_count = u.int_in_range(1..=5 * bits)?;
+    let mut next_z = 0;
+    for index in 0..gate_count {
+        let first = u.choose(&wires)?.clone();
+        let operation = u.choose(&["AND", "OR", "XOR"])?;
+        let second = u.choose(&wires)?.clone();
+        let output = if next_z <= bits && u.ratio(1, 3)? {
+            next_z += 1;
+            format!("z{:02}", next_z - 1)
+        } else {
+            // Three letters, with the first one never being x, y or z:
+            let letters = [b'a' + (index / 26) as u8, b'a' + (index % 26) as u8, b'q'];
+            String::from_utf8_lossy(&letters).into_owned()
+        };
+        let _ = writeln!(result, "{first} {operation} {second} -> {output}");
+        wires.push(output);
+    }
+    Ok(result)
+}
+
+#[test]
+fn generated_inputs() {
+    let data: Vec<u8> = (0..8192_u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 11) as u8)
+        .collect();
+    let mut u = Unstructured::new(&data);
+
+    let program = intcode_program(&mut u).unwrap();
+    assert!(program.split(',').all(|word| word.parse::<i64>().is_ok()));
+
+    let map = cave_map(&mut u).unwrap();
+    let lines: Vec<&str> = map.lines().collect();
+    assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+    assert!(lines[0].bytes().all(|b| b == b'#'));
+
+    let tiles = tile_set(&mut u).unwrap();
+    let tile_count = tiles.split("\n\n").count();
+    assert!([1, 4, 9, 16].contains(&tile_count));
+
+    let gates = gate_list(&mut u).unwrap();
+    let (values, gates) = gates.split_once("\n\n").unwrap();
+    assert!(values.lines().all(|line| line.contains(": ")));
+    assert!(gates.lines().all(|line| line.contains(" -> ")));
+}
@@ -0,0 +1,203 @@
+#![forbid(unsafe_code)]
+/*!
+Structured input generators for fuzzing the [advent-of-code](https://docs.rs/advent-of-code/) solutions.
+
+Raw bytes given as puzzle input almost never get past parsing, so for puzzles with a
+[generator](generator_for) the fuzzers produce syntactically valid inputs - such as an
+Intcode program or a cave map - which exercise the solver logic instead.
+
+A fuzz case starts with a 3 byte header:
+
+- The first two bytes select the puzzle, as a little-endian index into [`advent_of_code::puzzles()`].
+- In the third byte, [`PART_TWO_FLAG`] selects the second part (if the puzzle has two parts) and
+  [`STRUCTURED_FLAG`] selects the generator (if the puzzle has one) instead of using the
+  remaining bytes as input.
+*/
+mod generators;
+
+pub use generators::{Generator, generator_for};
+
+use advent_of_code::Puzzle;
+use arbitrary::{Arbitrary, Unstructured};
+
+/// Flag in the third header byte selecting the second part of a puzzle.
+pub const PART_TWO_FLAG: u8 = 0b01;
+/// Flag in the third header byte selecting generated input over raw bytes.
+pub const STRUCTURED_FLAG: u8 = 0b10;
+
+/// A puzzle and input to solve in a fuzzing run.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzCase {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+}
+
+struct Header {
+    puzzle: Puzzle,
+    part: u8,
+    generator: Option<Generator>,
+}
+
+impl Header {
+    fn arbitrary(u: &mut Unstructured<'_>) -> arbitrary::Result<Self> {
+        let bytes = u.bytes(3)?;
+        let puzzles: Vec<Puzzle> = advent_of_code::puzzles().collect();
+        let index = usize::from(u16::from_le_bytes([bytes[0], bytes[1]])) % puzzles.len();
+        let puzzle = puzzles[index];
+        let part = if puzzle.parts == 2 && bytes[2] & PART_TWO_FLAG != 0 {
+            2
+        } else {
+            1
+        };
+        let generator =
+            generator_for(puzzle.year, puzzle.day).filter(|_| bytes[2] & STRUCTURED_FLAG != 0);
+        Ok(Self {
+            puzzle,
+            part,
+            generator,
+        })
+    }
+
+    const fn into_case(self, input: String) -> FuzzCase {
+        FuzzCase {
+            year: self.puzzle.year,
+            day: self.puzzle.day,
+            part: self.part,
+            input,
+        }
+    }
+}
+
+impl<'a> Arbitrary<'a> for FuzzCase {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let header = Header::arbitrary(u)?;
+        let input = match header.generator {
+            Some(generate) => generate(u)?,
+            None => String::from_utf8_lossy(<&[u8]>::arbitrary(u)?).into_owned(),
+        };
+        Ok(header.into_case(input))
+    }
+
+    fn arbitrary_take_rest(mut u: Unstructured<'a>) -> arbitrary::Result<Self> {
+        let header = Header::arbitrary(&mut u)?;
+        let input = match header.generator {
+            Some(generate) => generate(&mut u)?,
+            None => String::from_utf8_lossy(u.take_rest()).into_owned(),
+        };
+        Ok(header.into_case(input))
+    }
+}
+
+impl FuzzCase {
+    /// Creates a fuzz case from the data given by a fuzzer, if there is enough of it.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        Self::arbitrary_take_rest(Unstructured::new(data)).ok()
+    }
+
+    /// Returns the data which [`from_bytes`](Self::from_bytes) creates this case from when
+    /// using the input as is, for seeding a corpus. Returns `None` for an unknown puzzle.
+    pub fn to_seed(&self) -> Option<Vec<u8>> {
+        let index = advent_of_code::puzzles()
+            .position(|puzzle| puzzle.year == self.year && puzzle.day == self.day)?;
+        let index = u16::try_from(index).ok()?.to_le_bytes();
+        let flags = if self.part == 2 { PART_TWO_FLAG } else { 0 };
+        let mut seed = vec![index[0], index[1], flags];
+        seed.extend_from_slice(self.input.as_bytes());
+        Some(seed)
+    }
+
+    /// Creates a fuzz case with generated input for a puzzle having more than one of its
+    /// [implementations](advent_of_code::implementations) enabled, for differential fuzzing.
+    pub fn differential_from_bytes(data: &[u8]) -> Option<Self> {
+        let mut u = Unstructured::new(data);
+        let puzzles: Vec<(Puzzle, Generator)> = advent_of_code::puzzles()
+            .filter(|puzzle| advent_of_code::implementations(puzzle.year, puzzle.day).len() > 1)
+            .filter_map(|puzzle| Some((puzzle, generator_for(puzzle.year, puzzle.day)?)))
+            .collect();
+        let &(puzzle, generate) = u.choose(&puzzles).ok()?;
+        let part_two = puzzle.parts == 2 && u.arbitrary::<bool>().ok()?;
+        Some(Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            part: if part_two { 2 } else { 1 },
+            input: generate(&mut u).ok()?,
+        })
+    }
+
+    /// Solves the case, which is expected to never panic.
+    pub fn solve(&self) -> Result<String, String> {
+        advent_of_code::solve(self.year, self.day, self.part, &self.input)
+    }
+
+    /// Solves the case with all implementations of the puzzle, which are expected to agree.
+    pub fn check_implementations(&self) -> Result<Option<String>, String> {
+        advent_of_code::check_implementations(self.year, self.day, self.part, &self.input)
+    }
+}
+
+#[test]
+fn seed_round_trip() {
+    for (year, day, part) in [(2015, 1, 1), (2019, 25, 1), (2025, 12, 1), (2024, 24, 2)] {
+        let case = FuzzCase {
+            year,
+            day,
+            part,
+            input: "14\n".to_string(),
+        };
+        let seed = case.to_seed().unwrap();
+        assert_eq!(seed[2] & STRUCTURED_FLAG, 0);
+        assert_eq!(FuzzCase::from_bytes(&seed), Some(case));
+    }
+    assert_eq!(FuzzCase::from_bytes(&[0, 0]), None);
+}
+
+#[test]
+fn structured_cases() {
+    let data: Vec<u8> = (0..4096_u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+        .collect();
+    for (index, puzzle) in advent_of_code::puzzles().enumerate() {
+        if generator_for(puzzle.year, puzzle.day).is_none() {
+            continue;
+        }
+        let index = u16::try_from(index).unwrap().to_le_bytes();
+        for offset in 0..8 {
+            let flags = if offset % 2 == 0 {
+                STRUCTURED_FLAG
+            } else {
+                STRUCTURED_FLAG | PART_TWO_FLAG
+            };
+            let mut bytes = vec![index[0], index[1], flags];
+            bytes.extend_from_slice(&data[offset * 64..]);
+            let case = FuzzCase::from_bytes(&bytes).unwrap();
+            assert_eq!((case.year, case.day), (puzzle.year, puzzle.day));
+
+            // The generated input should reach the solver logic, so it must not be
+            // rejected like input which cannot be parsed:
+            let parse_error = advent_of_code::solve(case.year, case.day, case.part, "x").err();
+            match case.solve() {
+                Ok(_) => {}
+                Err(error) => assert_ne!(
+                    Some(&error),
+                    parse_error.as_ref(),
+                    "{}/{} part {}:\n{}",
+                    case.year,
+                    case.day,
+                    case.part,
+                    case.input
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn differential_cases() {
+    // Among puzzles with a generator, 2022 day 23 always has a heap-allocated implementation
+    // besides the one enabled by features:
+    let case = FuzzCase::differential_from_bytes(&[7; 256]).unwrap();
+    assert_eq!((case.year, case.day), (2022, 23));
+    assert!(case.check_implementations().is_ok());
+}
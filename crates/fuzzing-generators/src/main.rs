@@ -0,0 +1,41 @@
+//! Creates a seed corpus for the fuzzers from the puzzle inputs in the core crate.
+use advent_of_code_fuzzing::FuzzCase;
+use std::path::Path;
+
+fn main() -> Result<(), String> {
+    let usage = "usage: create-seed-corpus <output-directory>";
+    let output_dir = std::env::args().nth(1).ok_or(usage)?;
+    let output_dir = Path::new(&output_dir);
+    std::fs::create_dir_all(output_dir)
+        .map_err(|e| format!("Cannot create {output_dir:?}: {e}"))?;
+
+    let core_src = Path::new(env!("CARGO_MANIFEST_DIR")).join("../core/src");
+    let mut count = 0;
+    for puzzle in advent_of_code::puzzles() {
+        let input_path = core_src.join(format!(
+            "year{}/day{:02}_input.txt",
+            puzzle.year, puzzle.day
+        ));
+        let Ok(input) = std::fs::read_to_string(&input_path) else {
+            continue;
+        };
+        for part in 1..=puzzle.parts {
+            let case = FuzzCase {
+                year: puzzle.year,
+                day: puzzle.day,
+                part,
+                input: input.clone(),
+            };
+            let seed = case.to_seed().ok_or("Puzzle missing from catalog")?;
+            let seed_path = output_dir.join(format!(
+                "{}_day{:02}_part{part}.txt",
+                puzzle.year, puzzle.day
+            ));
+            std::fs::write(&seed_path, seed)
+                .map_err(|e| format!("Cannot write {seed_path:?}: {e}"))?;
+            count += 1;
+        }
+    }
+    println!("Wrote {count} seeds to {}", output_dir.display());
+    Ok(())
+}
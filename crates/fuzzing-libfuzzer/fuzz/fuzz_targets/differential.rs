@@ -0,0 +1,14 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+
+use advent_of_code_fuzzing::FuzzCase;
+
+// Checks that alternative implementations of a puzzle, such as the scalar and SIMD
+// ones of 2022 day 23, give the same answers.
+fuzz_target!(|data: &[u8]| {
+    if let Some(case) = FuzzCase::differential_from_bytes(data) {
+        if let Err(disagreement) = case.check_implementations() {
+            panic!("{disagreement}\nInput:\n{}", case.input);
+        }
+    }
+});
@@ -1,5 +1,4 @@
-use advent_of_code::solve;
-use std::convert::TryInto;
+use advent_of_code_service::{ErrorKind, NoCache, SolveError, SolveRequest, solve};
 use tonic::{Code, Request, Response, Status, transport::Server};
 
 use advent::solver_server::{Solver, SolverServer};
@@ -26,36 +25,32 @@ impl Solver for SolverImpl {
     ) -> Result<Response<ProblemOutput>, Status> {
         let input: ProblemInput = request.into_inner();
 
-        let year: u16 = match input.year.try_into() {
-            Ok(value) => value,
-            Err(_) => {
-                return Err(Status::new(Code::OutOfRange, "year too big"));
-            }
+        let (Ok(year), Ok(day), Ok(part)) = (
+            u16::try_from(input.year),
+            u8::try_from(input.day),
+            u8::try_from(input.part),
+        ) else {
+            let error = SolveError::unsupported(input.year, input.day, input.part);
+            return Err(Status::new(Code::OutOfRange, error.message));
         };
 
-        let day: u8 = match input.day.try_into() {
-            Ok(value) => value,
-            Err(_) => {
-                return Err(Status::new(Code::OutOfRange, "day too big"));
-            }
-        };
-
-        let part: u8 = match input.part.try_into() {
-            Ok(value) => value,
-            Err(_) => {
-                return Err(Status::new(Code::OutOfRange, "part too big"));
-            }
-        };
-
-        let result = solve(year, day, part, &input.input);
-
-        match result {
-            Err(error_string) => Err(Status::new(Code::InvalidArgument, error_string)),
+        let request = SolveRequest::new(year, day, part, input.input);
+        match solve(&request, &NoCache).into_result() {
             Ok(answer) => Ok(Response::new(ProblemOutput { answer })),
+            Err(error) => Err(Status::new(status_code(error.kind), error.message)),
         }
     }
 }
 
+const fn status_code(kind: ErrorKind) -> Code {
+    match kind {
+        ErrorKind::InvalidInput => Code::InvalidArgument,
+        ErrorKind::UnsupportedPuzzle => Code::OutOfRange,
+        ErrorKind::Timeout => Code::DeadlineExceeded,
+        ErrorKind::Cancelled => Code::Cancelled,
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     #![allow(clippy::expect_used)]
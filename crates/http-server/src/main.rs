@@ -1,14 +1,13 @@
 use axum::body::Body;
 use axum::{
-    Router,
+    Json, Router,
     extract::Path,
-    http::StatusCode,
-    response::Response,
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
     routing::{get, post},
 };
-use std::collections::HashMap;
 
-use advent_of_code::solve_raw;
+use advent_of_code_service::{CORS_HEADERS, NoCache, SolveRequest, SolveResponse, puzzles, solve};
 
 #[tokio::main]
 async fn main() {
@@ -17,7 +16,12 @@ async fn main() {
 
     let app = Router::new()
         .route("/", get(handle_get))
-        .route("/solve/{year}/{day}/{part}", post(handle_post));
+        .route("/puzzles", get(handle_puzzles))
+        .route("/solve", post(handle_solve_json).options(handle_options))
+        .route(
+            "/solve/{year}/{day}/{part}",
+            post(handle_post).options(handle_options),
+        );
 
     let port = "8080";
     println!("Running on port {port}");
@@ -39,23 +43,42 @@ async fn handle_get() -> Response<Body> {
         .unwrap()
 }
 
-async fn handle_post(Path(params): Path<HashMap<String, String>>, body: String) -> Response<Body> {
-    #![allow(clippy::unwrap_used)]
-    let year = params.get("year").unwrap();
-    let day = params.get("day").unwrap();
-    let part = params.get("part").unwrap();
-    match solve_raw(year, day, part, &body) {
-        Ok(solution) => Response::builder()
-            .status(StatusCode::OK)
-            .header("Access-Control-Allow-Origin", "*")
-            .header("Content-Type", "text/plain")
-            .body(Body::from(solution))
-            .unwrap(),
-        Err(error) => Response::builder()
-            .status(StatusCode::BAD_REQUEST)
-            .header("Access-Control-Allow-Origin", "*")
-            .header("content-type", "text/plain")
-            .body(Body::from(error))
-            .unwrap(),
-    }
+async fn handle_options() -> impl IntoResponse {
+    (StatusCode::NO_CONTENT, CORS_HEADERS)
+}
+
+async fn handle_puzzles() -> impl IntoResponse {
+    (CORS_HEADERS, Json(puzzles()))
+}
+
+async fn handle_post(
+    Path((year, day, part)): Path<(String, String, String)>,
+    body: String,
+) -> impl IntoResponse {
+    let result = SolveRequest::parse(&year, &day, &part, body)
+        .and_then(|request| solve(&request, &NoCache).into_result());
+    let (status, text) = match result {
+        Ok(answer) => (StatusCode::OK, answer),
+        Err(error) => (status_code(error.http_status()), error.message),
+    };
+    (
+        status,
+        CORS_HEADERS,
+        [(header::CONTENT_TYPE, "text/plain")],
+        text,
+    )
+}
+
+/// Solves a problem posted as a JSON `SolveRequest`, responding with a JSON `SolveResponse`.
+async fn handle_solve_json(Json(request): Json<SolveRequest>) -> impl IntoResponse {
+    let response: SolveResponse = solve(&request, &NoCache);
+    let status = response
+        .error
+        .as_ref()
+        .map_or(StatusCode::OK, |error| status_code(error.http_status()));
+    (status, CORS_HEADERS, Json(response))
+}
+
+fn status_code(status: u16) -> StatusCode {
+    StatusCode::from_u16(status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
 }
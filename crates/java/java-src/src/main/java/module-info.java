@@ -0,0 +1,6 @@
+/**
+ * Solutions to <a href="https://adventofcode.com/">Advent of Code</a> problems, see {@link net.fornwall.aoc.Solver}.
+ */
+module net.fornwall.aoc {
+    exports net.fornwall.aoc;
+}
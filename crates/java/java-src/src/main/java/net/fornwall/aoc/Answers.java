@@ -0,0 +1,10 @@
+package net.fornwall.aoc;
+
+/**
+ * The answers to both parts of a problem, as returned by {@link Solver#solveBoth(int, int, String)}.
+ *
+ * @param partOne the answer to the first part
+ * @param partTwo the answer to the second part, or null for a problem with a single part
+ */
+public record Answers(String partOne, String partTwo) {
+}
@@ -0,0 +1,12 @@
+package net.fornwall.aoc;
+
+/**
+ * Exception thrown when the input to a problem could not be parsed or solved.
+ */
+public class InvalidInputException extends SolverException {
+
+    InvalidInputException(String message) {
+        super(message);
+    }
+
+}
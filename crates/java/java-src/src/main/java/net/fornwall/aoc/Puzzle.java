@@ -0,0 +1,11 @@
+package net.fornwall.aoc;
+
+/**
+ * A problem which has a solution, as returned by {@link Solver#listPuzzles()}.
+ *
+ * @param year  the year of the problem
+ * @param day   the day of the problem (1-25)
+ * @param parts the number of parts: 1 for the last day of a year, otherwise 2
+ */
+public record Puzzle(int year, int day, int parts) {
+}
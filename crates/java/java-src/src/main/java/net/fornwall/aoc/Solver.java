@@ -1,5 +1,10 @@
 package net.fornwall.aoc;
 
+import java.util.ArrayList;
+import java.util.Collections;
+import java.util.List;
+import java.util.concurrent.CompletableFuture;
+
 /**
  * A solver of <a href="https://adventofcode.com">Advent of Code</a> problems.
  * <p>
@@ -11,6 +16,8 @@ public final class Solver {
         JarNativeLibraryLoader.loadLibraryFromJar("advent_of_code_java");
     }
 
+    private static final List<Puzzle> PUZZLES = createPuzzleList();
+
     private Solver() {
     }
 
@@ -26,4 +33,77 @@ public final class Solver {
      */
     public static native String solve(int year, int day, int part, String input) throws SolverException;
 
+    /**
+     * Solve both parts of the specified problem with the given input.
+     *
+     * @param year  the year of the problem being solved
+     * @param day   the day of the problem being solved (1-25)
+     * @param input the input text to the problem
+     * @return the answers to the specified problem and input
+     * @throws SolverException in case of an error happened and the problem could not be solved
+     */
+    public static Answers solveBoth(int year, int day, String input) throws SolverException {
+        var answers = solveBoth0(year, day, input);
+        return new Answers(answers[0], answers[1]);
+    }
+
+    /**
+     * Solve the specified problem with the given input on a native thread pool.
+     * <p>
+     * The returned future is completed on a thread of the pool, so dependent actions which
+     * are not cheap should be added using the async methods of {@link CompletableFuture}.
+     *
+     * @param year  the year of the problem being solved
+     * @param day   the day of the problem being solved (1-25)
+     * @param part  the part of the problem being solved (1 or 2)
+     * @param input the input text to the problem
+     * @return a future completed with the answer, or exceptionally with a {@link SolverException}
+     */
+    public static CompletableFuture<String> solveAsync(int year, int day, int part, String input) {
+        var pending = new PendingAnswer();
+        solveAsync0(year, day, part, input, pending);
+        return pending;
+    }
+
+    /**
+     * List the problems which have solutions.
+     *
+     * @return an unmodifiable list of puzzles, ordered by year and day
+     */
+    public static List<Puzzle> listPuzzles() {
+        return PUZZLES;
+    }
+
+    private static List<Puzzle> createPuzzleList() {
+        var values = listPuzzles0();
+        var puzzles = new ArrayList<Puzzle>(values.length / 3);
+        for (int i = 0; i < values.length; i += 3) {
+            puzzles.add(new Puzzle(values[i], values[i + 1], values[i + 2]));
+        }
+        return Collections.unmodifiableList(puzzles);
+    }
+
+    private static native String[] solveBoth0(int year, int day, String input) throws SolverException;
+
+    private static native void solveAsync0(int year, int day, int part, String input, PendingAnswer pending);
+
+    private static native int[] listPuzzles0();
+
+    /**
+     * A future completed from the native thread pool.
+     */
+    private static final class PendingAnswer extends CompletableFuture<String> {
+
+        // Called from src/lib.rs:
+        @SuppressWarnings("unused")
+        void resolve(int errorKind, String text) {
+            if (errorKind == 0) {
+                complete(text);
+            } else {
+                completeExceptionally(SolverException.fromKind(errorKind, text));
+            }
+        }
+
+    }
+
 }
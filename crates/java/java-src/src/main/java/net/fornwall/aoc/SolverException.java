@@ -4,11 +4,28 @@ package net.fornwall.aoc;
  * Exception thrown when an Advent of Code solution can not be solved by the
  *
  * {@link net.fornwall.aoc.Solver#solve(int, int, int, String)}
+ * <p>
+ * The cause of the error is indicated by the subclasses {@link InvalidInputException},
+ * {@link UnsupportedPuzzleException} and {@link SolverTimeoutException}.
  */
 public class SolverException extends RuntimeException {
 
+    // Error kinds, which must match the constants in src/lib.rs:
+    private static final int ERROR_INVALID_INPUT = 1;
+    private static final int ERROR_UNSUPPORTED_PUZZLE = 2;
+    private static final int ERROR_TIMEOUT = 3;
+
     SolverException(String message) {
         super(message);
     }
 
+    static SolverException fromKind(int kind, String message) {
+        return switch (kind) {
+            case ERROR_INVALID_INPUT -> new InvalidInputException(message);
+            case ERROR_UNSUPPORTED_PUZZLE -> new UnsupportedPuzzleException(message);
+            case ERROR_TIMEOUT -> new SolverTimeoutException(message);
+            default -> new SolverException(message);
+        };
+    }
+
 }
@@ -0,0 +1,13 @@
+package net.fornwall.aoc;
+
+/**
+ * Exception thrown when a solver gave up after exceeding its computation budget,
+ * as when simulating a program in the input which does not terminate.
+ */
+public class SolverTimeoutException extends SolverException {
+
+    SolverTimeoutException(String message) {
+        super(message);
+    }
+
+}
@@ -0,0 +1,14 @@
+package net.fornwall.aoc;
+
+/**
+ * Exception thrown when there is no solution for the specified year, day and part.
+ *
+ * @see Solver#listPuzzles()
+ */
+public class UnsupportedPuzzleException extends SolverException {
+
+    UnsupportedPuzzleException(String message) {
+        super(message);
+    }
+
+}
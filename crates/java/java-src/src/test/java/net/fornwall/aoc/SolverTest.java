@@ -1,5 +1,10 @@
 package net.fornwall.aoc;
 
+import java.util.concurrent.CompletionException;
+import java.util.concurrent.ExecutionException;
+import java.util.concurrent.TimeUnit;
+import java.util.stream.IntStream;
+
 import org.junit.jupiter.api.Assertions;
 import org.junit.jupiter.api.Test;
 
@@ -17,4 +22,50 @@ public class SolverTest {
         Assertions.assertThrows(SolverException.class, () -> Solver.solve(2019, 1, 1, "hello"));
     }
 
+    @Test
+    void testExceptionSubclasses() {
+        Assertions.assertThrows(UnsupportedPuzzleException.class, () -> Solver.solve(2019, -1, 1, "14"));
+        Assertions.assertThrows(UnsupportedPuzzleException.class, () -> Solver.solve(2019, 26, 1, "14"));
+        Assertions.assertThrows(UnsupportedPuzzleException.class, () -> Solver.solve(2019, 1, 3, "14"));
+        var invalid = Assertions.assertThrows(InvalidInputException.class, () -> Solver.solve(2019, 1, 1, "hello"));
+        Assertions.assertEquals("Line 1: invalid digit found in string", invalid.getMessage());
+        Assertions.assertThrows(SolverTimeoutException.class, () -> Solver.solve(2017, 18, 1, "jgz 1 0"));
+    }
+
+    @Test
+    void testSolveBoth() {
+        Assertions.assertEquals(new Answers("654", "966"), Solver.solveBoth(2019, 1, "1969"));
+        var codeGrid = "To continue, please consult the code grid in the manual.  Enter the code at row 1, column 2.";
+        Assertions.assertEquals(new Answers("18749137", null), Solver.solveBoth(2015, 25, codeGrid));
+        Assertions.assertThrows(InvalidInputException.class, () -> Solver.solveBoth(2019, 1, "hello"));
+    }
+
+    @Test
+    void testListPuzzles() {
+        var puzzles = Solver.listPuzzles();
+        Assertions.assertEquals(262, puzzles.size());
+        Assertions.assertEquals(new Puzzle(2015, 1, 2), puzzles.get(0));
+        Assertions.assertEquals(new Puzzle(2025, 12, 1), puzzles.get(puzzles.size() - 1));
+        Assertions.assertThrows(UnsupportedOperationException.class, () -> puzzles.remove(0));
+    }
+
+    @Test
+    void testSolveAsync() throws Exception {
+        var futures = IntStream.range(100, 200)
+                .mapToObj(mass -> Solver.solveAsync(2019, 1, 1, Integer.toString(mass)))
+                .toList();
+        for (int i = 0; i < futures.size(); i++) {
+            var mass = 100 + i;
+            Assertions.assertEquals(Integer.toString(mass / 3 - 2), futures.get(i).get(10, TimeUnit.SECONDS));
+        }
+
+        var failed = Solver.solveAsync(2019, 1, 1, "hello");
+        var exception = Assertions.assertThrows(ExecutionException.class, () -> failed.get(10, TimeUnit.SECONDS));
+        Assertions.assertInstanceOf(InvalidInputException.class, exception.getCause());
+
+        var unsupported = Solver.solveAsync(2019, 26, 1, "14");
+        var joinException = Assertions.assertThrows(CompletionException.class, unsupported::join);
+        Assertions.assertInstanceOf(UnsupportedPuzzleException.class, joinException.getCause());
+    }
+
 }
@@ -1,12 +1,24 @@
 #![allow(clippy::expect_used)]
 #![allow(clippy::panic)]
-use advent_of_code::solve;
-use jni::EnvUnowned;
+use std::sync::OnceLock;
+use std::sync::mpsc::{Sender, channel};
+use std::sync::{Arc, Mutex};
+
+use advent_of_code::puzzles;
+use advent_of_code_service::{ErrorKind, solve_input};
 use jni::errors::ThrowRuntimeExAndDefault;
-use jni::jni_str;
-use jni::objects::{JClass, JString};
-use jni::strings::JNIString;
-use jni::sys::jstring;
+use jni::objects::{JClass, JIntArray, JObject, JObjectArray, JString};
+use jni::strings::{JNIStr, JNIString};
+use jni::sys::{jintArray, jobjectArray, jstring};
+use jni::{EnvUnowned, JValue, jni_sig, jni_str};
+
+// Error kinds, which must match SolverException.fromKind():
+const ERROR_INVALID_INPUT: i32 = 1;
+const ERROR_UNSUPPORTED_PUZZLE: i32 = 2;
+const ERROR_TIMEOUT: i32 = 3;
+const ERROR_INTERNAL: i32 = 4;
+
+type SolveResult = Result<String, (i32, String)>;
 
 #[unsafe(no_mangle)]
 pub extern "system" fn Java_net_fornwall_aoc_Solver_solve(
@@ -21,27 +33,175 @@ pub extern "system" fn Java_net_fornwall_aoc_Solver_solve(
         .with_env(|env| -> jni::errors::Result<_> {
             let input_str: String = input.to_string();
 
-            let exception_message = match convert_params(year, day, part) {
-                Ok((year, day, part)) => match solve(year, day, part, &input_str) {
-                    Ok(output) => {
-                        return Ok(env
-                            .new_string(output)
-                            .expect("Unable to create output string")
-                            .into_raw());
+            match solve_checked(year, day, part, &input_str) {
+                Ok(output) => Ok(env
+                    .new_string(output)
+                    .expect("Unable to create output string")
+                    .into_raw()),
+                Err((kind, message)) => {
+                    env.throw_new(exception_class(kind), JNIString::from(message))?;
+                    Ok(::std::ptr::null_mut())
+                }
+            }
+        })
+        .resolve::<ThrowRuntimeExAndDefault>()
+}
+
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_net_fornwall_aoc_Solver_solveBoth0(
+    mut unowned_env: EnvUnowned,
+    _class: JClass,
+    year: i32,
+    day: i32,
+    input: JString,
+) -> jobjectArray {
+    unowned_env
+        .with_env(|env| -> jni::errors::Result<_> {
+            let input_str: String = input.to_string();
+
+            let answers = solve_checked(year, day, 1, &input_str).and_then(|part_one| {
+                let has_part_two = puzzles()
+                    .any(|p| i32::from(p.year) == year && i32::from(p.day) == day && p.parts == 2);
+                let part_two = if has_part_two {
+                    Some(solve_checked(year, day, 2, &input_str)?)
+                } else {
+                    None
+                };
+                Ok((part_one, part_two))
+            });
+
+            match answers {
+                Ok((part_one, part_two)) => {
+                    let array = JObjectArray::<JString>::new(env, 2, JString::null())?;
+                    let part_one = env.new_string(part_one)?;
+                    array.set_element(env, 0, part_one)?;
+                    if let Some(part_two) = part_two {
+                        let part_two = env.new_string(part_two)?;
+                        array.set_element(env, 1, part_two)?;
                     }
-                    Err(msg) => msg,
-                },
-                Err(message) => message,
-            };
-
-            let class_name = jni_str!("net/fornwall/aoc/SolverException");
-            let s = JNIString::from(exception_message);
-            env.throw_new(class_name, &s)?;
-            Ok(::std::ptr::null_mut())
+                    Ok(array.into_raw())
+                }
+                Err((kind, message)) => {
+                    env.throw_new(exception_class(kind), JNIString::from(message))?;
+                    Ok(::std::ptr::null_mut())
+                }
+            }
+        })
+        .resolve::<ThrowRuntimeExAndDefault>()
+}
+
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_net_fornwall_aoc_Solver_listPuzzles0(
+    mut unowned_env: EnvUnowned,
+    _class: JClass,
+) -> jintArray {
+    unowned_env
+        .with_env(|env| -> jni::errors::Result<_> {
+            // Flattened (year, day, parts) triples:
+            let values = puzzles()
+                .flat_map(|p| [i32::from(p.year), i32::from(p.day), i32::from(p.parts)])
+                .collect::<Vec<_>>();
+            let array = JIntArray::new(env, values.len())?;
+            array.set_region(env, 0, &values)?;
+            Ok(array.into_raw())
         })
         .resolve::<ThrowRuntimeExAndDefault>()
 }
 
+#[unsafe(no_mangle)]
+pub extern "system" fn Java_net_fornwall_aoc_Solver_solveAsync0(
+    mut unowned_env: EnvUnowned,
+    _class: JClass,
+    year: i32,
+    day: i32,
+    part: i32,
+    input: JString,
+    pending: JObject,
+) {
+    unowned_env
+        .with_env(|env| -> jni::errors::Result<_> {
+            let input_str: String = input.to_string();
+            let vm = env.get_java_vm()?;
+            let pending = env.new_global_ref(pending)?;
+
+            let job = Box::new(move || {
+                let result =
+                    std::panic::catch_unwind(|| solve_checked(year, day, part, &input_str))
+                        .unwrap_or_else(|_| Err((ERROR_INTERNAL, "Solver panicked".to_string())));
+                let (kind, text) = match result {
+                    Ok(answer) => (0, answer),
+                    Err(error) => error,
+                };
+                // Attach only while completing, so that idle pool threads do not keep the JVM alive:
+                let _ = vm.attach_current_thread_for_scope(|env| -> jni::errors::Result<()> {
+                    let text = env.new_string(text)?;
+                    env.call_method(
+                        &pending,
+                        jni_str!("resolve"),
+                        jni_sig!("(ILjava/lang/String;)V"),
+                        &[JValue::Int(kind), JValue::Object(&text)],
+                    )?;
+                    Ok(())
+                });
+            });
+            thread_pool()
+                .send(job)
+                .expect("Solver thread pool has shut down");
+            Ok(())
+        })
+        .resolve::<ThrowRuntimeExAndDefault>()
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Returns the sender of jobs to a lazily started pool with one thread per available core.
+fn thread_pool() -> &'static Sender<Job> {
+    static POOL: OnceLock<Sender<Job>> = OnceLock::new();
+    POOL.get_or_init(|| {
+        let (sender, receiver) = channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let threads = std::thread::available_parallelism().map_or(2, std::num::NonZero::get);
+        for i in 0..threads {
+            let receiver = Arc::clone(&receiver);
+            std::thread::Builder::new()
+                .name(format!("advent-of-code-solver-{i}"))
+                .spawn(move || {
+                    loop {
+                        let job = receiver.lock().ok().and_then(|r| r.recv().ok());
+                        match job {
+                            Some(job) => job(),
+                            None => return,
+                        }
+                    }
+                })
+                .expect("Unable to start solver thread");
+        }
+        sender
+    })
+}
+
+fn solve_checked(year: i32, day: i32, part: i32, input: &str) -> SolveResult {
+    let (year, day, part) =
+        convert_params(year, day, part).map_err(|message| (ERROR_UNSUPPORTED_PUZZLE, message))?;
+    solve_input(year, day, part, input).map_err(|error| {
+        let code = match error.kind {
+            ErrorKind::InvalidInput | ErrorKind::Cancelled => ERROR_INVALID_INPUT,
+            ErrorKind::UnsupportedPuzzle => ERROR_UNSUPPORTED_PUZZLE,
+            ErrorKind::Timeout => ERROR_TIMEOUT,
+        };
+        (code, error.message)
+    })
+}
+
+const fn exception_class(kind: i32) -> &'static JNIStr {
+    match kind {
+        ERROR_INVALID_INPUT => jni_str!("net/fornwall/aoc/InvalidInputException"),
+        ERROR_UNSUPPORTED_PUZZLE => jni_str!("net/fornwall/aoc/UnsupportedPuzzleException"),
+        ERROR_TIMEOUT => jni_str!("net/fornwall/aoc/SolverTimeoutException"),
+        _ => jni_str!("net/fornwall/aoc/SolverException"),
+    }
+}
+
 fn convert_params(year: i32, day: i32, part: i32) -> Result<(u16, u8, u8), String> {
     Ok((
         u16::try_from(year).map_err(|_| format!("Invalid year: {year}"))?,
@@ -1 +1,20 @@
-def solve(year: int, day: int, part: int, input: str) -> str: ...
+class AdventOfCodeError(ValueError): ...
+class ParseError(AdventOfCodeError): ...
+class UnsupportedPuzzleError(AdventOfCodeError): ...
+class TimeoutError(AdventOfCodeError): ...
+
+class Puzzle:
+    @property
+    def year(self) -> int: ...
+    @property
+    def day(self) -> int: ...
+    @property
+    def parts(self) -> int: ...
+
+def solve(year: int | str, day: int | str, part: int | str, input: str) -> str: ...
+def solve_both(year: int | str, day: int | str, input: str) -> tuple[str, str | None]: ...
+def solve_many(
+    problems: list[tuple[int | str, int | str, int | str, str]],
+    return_exceptions: bool = False,
+) -> list[str | AdventOfCodeError]: ...
+def puzzles() -> list[Puzzle]: ...
@@ -10,7 +10,7 @@ export OSTYPE=linux
 . env/bin/activate
 pip install -r requirements.txt
 python setup.py develop
-PYTHONPATH=. python tests/test_solve.py
+PYTHONPATH=. python -m pytest tests
 
 # About to test cli
 OUTPUT=`echo 14 | PYTHONPATH=. ./env/bin/advent-of-code-py 2019 1 1`
@@ -4,11 +4,59 @@ use core::fmt::Display;
 
 use pyo3::FromPyObject;
 use pyo3::PyAny;
+use pyo3::create_exception;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
 use pyo3::wrap_pyfunction;
 
-use ::advent_of_code::solve_raw;
+use ::advent_of_code::puzzles as all_puzzles;
+use advent_of_code_service::{ErrorKind, SolveRequest, solve_input};
+
+create_exception!(
+    advent_of_code,
+    AdventOfCodeError,
+    PyValueError,
+    "Base class for errors raised when solving a problem."
+);
+create_exception!(
+    advent_of_code,
+    ParseError,
+    AdventOfCodeError,
+    "Raised when the input could not be parsed or solved."
+);
+create_exception!(
+    advent_of_code,
+    UnsupportedPuzzleError,
+    AdventOfCodeError,
+    "Raised when there is no solution for the specified year, day and part."
+);
+create_exception!(
+    advent_of_code,
+    TimeoutError,
+    AdventOfCodeError,
+    "Raised when a solver gave up after exceeding its computation budget."
+);
+
+/// A failure to solve a problem, kept free of Python objects so that it can be
+/// produced while the GIL is released.
+#[derive(Clone)]
+struct SolveError(advent_of_code_service::SolveError);
+
+impl From<advent_of_code_service::SolveError> for SolveError {
+    fn from(error: advent_of_code_service::SolveError) -> Self {
+        Self(error)
+    }
+}
+
+impl From<SolveError> for PyErr {
+    fn from(SolveError(error): SolveError) -> Self {
+        match error.kind {
+            ErrorKind::InvalidInput | ErrorKind::Cancelled => ParseError::new_err(error.message),
+            ErrorKind::UnsupportedPuzzle => UnsupportedPuzzleError::new_err(error.message),
+            ErrorKind::Timeout => TimeoutError::new_err(error.message),
+        }
+    }
+}
 
 fn try_to_string<'a, T: Display + FromPyObject<'a, 'a>>(
     object: &'a pyo3::Bound<'a, pyo3::PyAny>,
@@ -24,6 +72,24 @@ fn try_to_string<'a, T: Display + FromPyObject<'a, 'a>>(
     "".to_string()
 }
 
+/// Parses year, day and part given either as integers or strings, and checks that the puzzle is supported.
+fn parse_puzzle<'py>(
+    year: &Bound<'py, PyAny>,
+    day: &Bound<'py, PyAny>,
+    part: &Bound<'py, PyAny>,
+) -> Result<(u16, u8, u8), SolveError> {
+    let year_value = try_to_string::<u16>(year);
+    let day_value = try_to_string::<u8>(day);
+    let part_value = try_to_string::<u8>(part);
+    let request = SolveRequest::parse(&year_value, &day_value, &part_value, "")?;
+    request.validate()?;
+    Ok((request.year, request.day, request.part))
+}
+
+fn solve_parsed(year: u16, day: u8, part: u8, input: &str) -> Result<String, SolveError> {
+    Ok(solve_input(year, day, part, input)?)
+}
+
 /// Returns the answer for the specified problem and input.
 ///
 /// Args:
@@ -36,19 +102,184 @@ fn try_to_string<'a, T: Display + FromPyObject<'a, 'a>>(
 /// str: The computed answer as text.
 ///
 /// Raises:
-/// ValueError: If the input was invalid.
+/// UnsupportedPuzzleError: If there is no solution for the problem.
+/// ParseError: If the input was invalid.
+/// TimeoutError: If the solver gave up on the input.
 #[pyfunction]
 #[pyo3(text_signature = "(year, day, part, input)")]
 pub fn solve<'py>(
+    py: Python<'py>,
     year: &Bound<'py, PyAny>,
     day: &Bound<'py, PyAny>,
     part: &Bound<'py, PyAny>,
     input: &str,
 ) -> PyResult<String> {
-    let year_value = try_to_string::<u16>(year);
-    let day_value = try_to_string::<u8>(day);
-    let part_value = try_to_string::<u8>(part);
-    solve_raw(&year_value, &day_value, &part_value, input).map_err(PyValueError::new_err)
+    let (year, day, part) = parse_puzzle(year, day, part)?;
+    py.detach(|| solve_parsed(year, day, part, input))
+        .map_err(PyErr::from)
+}
+
+/// Returns the answers to both parts of the specified problem.
+///
+/// The GIL is released while solving, so other Python threads may run meanwhile.
+///
+/// Args:
+/// year (int): The year of the problem, as in 2018 or 2019.
+/// day (int): The day of the problem, from 1 to 25.
+/// input (str): The input to the problem.
+///
+/// Returns:
+/// tuple[str, str | None]: The answers to part one and two, where the latter is
+/// None for problems with a single part.
+///
+/// Raises:
+/// UnsupportedPuzzleError: If there is no solution for the problem.
+/// ParseError: If the input was invalid.
+/// TimeoutError: If the solver gave up on the input.
+#[pyfunction]
+#[pyo3(text_signature = "(year, day, input)")]
+pub fn solve_both<'py>(
+    py: Python<'py>,
+    year: &Bound<'py, PyAny>,
+    day: &Bound<'py, PyAny>,
+    input: &str,
+) -> PyResult<(String, Option<String>)> {
+    let one = 1_u8.into_pyobject(py)?;
+    let (year, day, _) = parse_puzzle(year, day, &one)?;
+    let parts = all_puzzles()
+        .find(|p| p.year == year && p.day == day)
+        .map_or(2, |p| p.parts);
+    py.detach(|| -> Result<_, SolveError> {
+        let part_one = solve_parsed(year, day, 1, input)?;
+        let part_two = if parts == 2 {
+            Some(solve_parsed(year, day, 2, input)?)
+        } else {
+            None
+        };
+        Ok((part_one, part_two))
+    })
+    .map_err(PyErr::from)
+}
+
+/// A (year, day, part, input) tuple passed to solve_many().
+type Problem<'py> = (
+    Bound<'py, PyAny>,
+    Bound<'py, PyAny>,
+    Bound<'py, PyAny>,
+    String,
+);
+
+/// Solves a batch of problems in parallel.
+///
+/// The GIL is released while solving, and the problems are spread over all available cores.
+///
+/// Args:
+/// problems (list[tuple[int, int, int, str]]): The problems to solve, as (year, day, part, input) tuples.
+/// return_exceptions (bool): Whether to return exceptions in the result list instead of raising the first one.
+///
+/// Returns:
+/// list[str]: The computed answers, in the same order as the problems.
+///
+/// Raises:
+/// UnsupportedPuzzleError: If there is no solution for a problem.
+/// ParseError: If an input was invalid.
+/// TimeoutError: If a solver gave up on an input.
+#[pyfunction]
+#[pyo3(signature = (problems, return_exceptions = false))]
+pub fn solve_many<'py>(
+    py: Python<'py>,
+    problems: Vec<Problem<'py>>,
+    return_exceptions: bool,
+) -> PyResult<Vec<Py<PyAny>>> {
+    let jobs = problems
+        .iter()
+        .map(|(year, day, part, input)| {
+            parse_puzzle(year, day, part).map(|puzzle| (puzzle, input.as_str()))
+        })
+        .collect::<Vec<_>>();
+
+    let results = py.detach(|| {
+        let threads = std::thread::available_parallelism()
+            .map_or(1, std::num::NonZero::get)
+            .min(jobs.len())
+            .max(1);
+        let chunk_size = jobs.len().div_ceil(threads).max(1);
+        std::thread::scope(|scope| {
+            // All threads need to be spawned before joining any of them:
+            #[allow(clippy::needless_collect)]
+            let handles = jobs
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|job| match job {
+                                Ok(((year, day, part), input)) => {
+                                    solve_parsed(*year, *day, *part, input)
+                                }
+                                Err(error) => Err(error.clone()),
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .flat_map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect::<Vec<_>>()
+        })
+    });
+
+    results
+        .into_iter()
+        .map(|result| match result {
+            Ok(answer) => Ok(answer.into_pyobject(py)?.into_any().unbind()),
+            Err(error) if return_exceptions => Ok(PyErr::from(error).into_value(py).into_any()),
+            Err(error) => Err(error.into()),
+        })
+        .collect()
+}
+
+/// A problem which has a solution in this module.
+#[pyclass(frozen, get_all, eq, hash, module = "advent_of_code")]
+#[derive(PartialEq, Eq, Hash)]
+pub struct Puzzle {
+    /// The year of the problem, as in 2018 or 2019.
+    year: u16,
+    /// The day of the problem, from 1 to 25.
+    day: u8,
+    /// The number of parts: 1 for the last day of a year, otherwise 2.
+    parts: u8,
+}
+
+#[pymethods]
+impl Puzzle {
+    fn __repr__(&self) -> String {
+        format!(
+            "Puzzle(year={}, day={}, parts={})",
+            self.year, self.day, self.parts
+        )
+    }
+}
+
+/// Returns the problems which have solutions in this module, ordered by year and day.
+///
+/// Returns:
+/// list[Puzzle]: The supported problems.
+#[pyfunction]
+#[pyo3(text_signature = "()")]
+pub fn puzzles() -> Vec<Puzzle> {
+    all_puzzles()
+        .map(|puzzle| Puzzle {
+            year: puzzle.year,
+            day: puzzle.day,
+            parts: puzzle.parts,
+        })
+        .collect()
 }
 
 // This defines a python module. pyo3 will copy the rust doc comment
@@ -57,7 +288,7 @@ pub fn solve<'py>(
 
 /// Solve Advent of Code problems.
 ///
-/// This module provides a function to compute answers for
+/// This module provides functions to compute answers for
 /// Advent of Code (https://adventofcode.com) problems.
 ///
 /// See https://github.com/fornwall/advent-of-code for source code.
@@ -68,8 +299,20 @@ pub fn solve<'py>(
 /// >>> solve(year=2019, day=1, part=1, input='14')
 /// '2'
 #[pymodule]
-pub fn advent_of_code(_py: Python, m: &Bound<PyModule>) -> PyResult<()> {
+pub fn advent_of_code(py: Python, m: &Bound<PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(solve))?;
+    m.add_wrapped(wrap_pyfunction!(solve_both))?;
+    m.add_wrapped(wrap_pyfunction!(solve_many))?;
+    m.add_wrapped(wrap_pyfunction!(puzzles))?;
+    m.add_class::<Puzzle>()?;
+
+    m.add("AdventOfCodeError", py.get_type::<AdventOfCodeError>())?;
+    m.add("ParseError", py.get_type::<ParseError>())?;
+    m.add(
+        "UnsupportedPuzzleError",
+        py.get_type::<UnsupportedPuzzleError>(),
+    )?;
+    m.add("TimeoutError", py.get_type::<TimeoutError>())?;
 
     Ok(())
 }
@@ -1,8 +1,84 @@
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
 import advent_of_code
+from advent_of_code import (
+    AdventOfCodeError,
+    ParseError,
+    TimeoutError,
+    UnsupportedPuzzleError,
+)
+
+
+def test_solve():
+    assert advent_of_code.solve("2019", "1", "1", "12") == "2"
+    assert advent_of_code.solve(2019, 1, 1, "12") == "2"
+    assert advent_of_code.solve(2019, 1, 1, "14") == "2"
+    assert advent_of_code.solve(2019, 1, 1, "1969") == "654"
+    assert advent_of_code.solve(2019, 1, 1, "100756") == "33583"
+    assert (
+        advent_of_code.solve(2019, 9, 1, "104,1125899906842624,99")
+        == "1125899906842624"
+    )
+
+
+def test_solve_both():
+    assert advent_of_code.solve_both(2019, 1, "1969") == ("654", "966")
+    code_grid = "To continue, please consult the code grid in the manual.  Enter the code at row 1, column 2."
+    assert advent_of_code.solve_both(2015, 25, code_grid) == ("18749137", None)
+
+
+def test_errors():
+    with pytest.raises(ParseError, match="invalid digit"):
+        advent_of_code.solve(2019, 1, 1, "hej")
+    with pytest.raises(UnsupportedPuzzleError):
+        advent_of_code.solve(2019, 26, 1, "14")
+    with pytest.raises(UnsupportedPuzzleError):
+        advent_of_code.solve(2019, 1, 3, "14")
+    with pytest.raises(UnsupportedPuzzleError):
+        advent_of_code.solve("twenty", 1, 1, "14")
+    with pytest.raises(TimeoutError, match="Aborted after"):
+        advent_of_code.solve(2017, 18, 1, "jgz 1 0")
+
+    # All errors are value errors, as raised by earlier versions:
+    for error in (ParseError, UnsupportedPuzzleError, TimeoutError):
+        assert issubclass(error, AdventOfCodeError)
+        assert issubclass(error, ValueError)
+
+
+def test_solve_many():
+    problems = [(2019, 1, 1, "14"), ("2019", "1", "2", "1969"), (2019, 1, 1, "hej")]
+    with pytest.raises(ParseError):
+        advent_of_code.solve_many(problems)
+
+    results = advent_of_code.solve_many(problems, return_exceptions=True)
+    assert results[:2] == ["2", "966"]
+    assert isinstance(results[2], ParseError)
+
+    many = [(2019, 1, 1, str(mass)) for mass in range(100, 1100)]
+    assert advent_of_code.solve_many(many) == [
+        str(mass // 3 - 2) for mass in range(100, 1100)
+    ]
+    assert advent_of_code.solve_many([]) == []
+
+
+def test_threads():
+    with ThreadPoolExecutor(max_workers=4) as executor:
+        answers = list(
+            executor.map(
+                lambda mass: advent_of_code.solve(2019, 1, 1, str(mass)),
+                range(100, 200),
+            )
+        )
+    assert answers == [str(mass // 3 - 2) for mass in range(100, 200)]
+
 
-assert advent_of_code.solve("2019", "1", "1", "12") == "2"
-assert advent_of_code.solve(2019, 1, 1, "12") == "2"
-assert advent_of_code.solve(2019, 1, 1, "14") == "2"
-assert advent_of_code.solve(2019, 1, 1, "1969") == "654"
-assert advent_of_code.solve(2019, 1, 1, "100756") == "33583"
-assert advent_of_code.solve(2019, 9, 1, "104,1125899906842624,99") == "1125899906842624"
+def test_puzzles():
+    puzzles = advent_of_code.puzzles()
+    assert len(puzzles) == 262
+    first = puzzles[0]
+    assert (first.year, first.day, first.parts) == (2015, 1, 2)
+    last = puzzles[-1]
+    assert (last.year, last.day, last.parts) == (2025, 12, 1)
+    assert repr(first) == "Puzzle(year=2015, day=1, parts=2)"
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// A store of previously computed answers.
+pub trait AnswerCache {
+    fn get(&self, key: &str) -> Option<String>;
+    fn put(&self, key: &str, answer: &str);
+}
+
+/// A store of previously computed answers which is accessed asynchronously, such as a
+/// key-value store over the network.
+// Implementations such as for single threaded runtimes need not return Send futures:
+#[allow(async_fn_in_trait)]
+pub trait AsyncAnswerCache {
+    async fn get(&self, key: &str) -> Option<String>;
+    async fn put(&self, key: &str, answer: &str);
+}
+
+/// Adapts a synchronous cache, whose futures complete on their first poll.
+pub struct Synchronous<'a, C>(pub &'a C);
+
+#[allow(clippy::future_not_send)]
+impl<C: AnswerCache> AsyncAnswerCache for Synchronous<'_, C> {
+    async fn get(&self, key: &str) -> Option<String> {
+        self.0.get(key)
+    }
+
+    async fn put(&self, key: &str, answer: &str) {
+        self.0.put(key, answer);
+    }
+}
+
+/// A cache which does not store anything.
+pub struct NoCache;
+
+impl AnswerCache for NoCache {
+    fn get(&self, _key: &str) -> Option<String> {
+        None
+    }
+
+    fn put(&self, _key: &str, _answer: &str) {}
+}
+
+/// An unbounded cache in memory.
+#[derive(Default)]
+pub struct MemoryCache {
+    answers: Mutex<HashMap<String, String>>,
+}
+
+impl MemoryCache {
+    pub fn len(&self) -> usize {
+        self.answers.lock().map_or(0, |answers| answers.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl AnswerCache for MemoryCache {
+    fn get(&self, key: &str) -> Option<String> {
+        self.answers.lock().ok()?.get(key).cloned()
+    }
+
+    fn put(&self, key: &str, answer: &str) {
+        if let Ok(mut answers) = self.answers.lock() {
+            answers.insert(key.to_string(), answer.to_string());
+        }
+    }
+}
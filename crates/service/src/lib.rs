@@ -0,0 +1,241 @@
+#![forbid(unsafe_code)]
+/*!
+A transport-agnostic service layer over the [advent-of-code](https://docs.rs/advent-of-code/) solutions,
+shared by the servers and bindings so that they validate requests, classify errors and
+represent results the same way.
+
+# Example
+
+```rust
+use advent_of_code_service::{MemoryCache, SolveRequest, solve};
+let cache = MemoryCache::default();
+let response = solve(&SolveRequest::new(2019, 1, 1, "14"), &cache);
+assert_eq!(response.into_result(), Ok("2".to_string()));
+```
+*/
+mod cache;
+mod model;
+
+pub use advent_of_code::ErrorKind;
+pub use cache::{AnswerCache, AsyncAnswerCache, MemoryCache, NoCache, Synchronous};
+pub use model::{Answers, Puzzle, SolveError, SolveRequest, SolveResponse};
+
+use sha2::{Digest, Sha256};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+/// The headers allowing the API to be used from web pages on any origin.
+pub const CORS_HEADERS: [(&str, &str); 3] = [
+    ("Access-Control-Allow-Origin", "*"),
+    ("Access-Control-Allow-Methods", "GET, POST, OPTIONS"),
+    ("Access-Control-Allow-Headers", "Content-Type"),
+];
+
+/// Returns the key under which the answer to a request is cached.
+///
+/// The version is part of the key, so that a deployment with fixed solvers does
+/// not serve answers computed by an earlier one.
+pub fn cache_key(request: &SolveRequest) -> String {
+    let digest = Sha256::digest(format!(
+        "{}/{}/{}/{}",
+        request.year, request.day, request.part, request.input
+    ));
+    let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+    format!("{}:{hex}", env!("CARGO_PKG_VERSION"))
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn measure<T>(function: impl FnOnce() -> T) -> (T, Option<u64>) {
+    let start = std::time::Instant::now();
+    let result = function();
+    let elapsed = u64::try_from(start.elapsed().as_micros()).unwrap_or(u64::MAX);
+    (result, Some(elapsed))
+}
+
+// There is no clock available through std on wasm32-unknown-unknown:
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+fn measure<T>(function: impl FnOnce() -> T) -> (T, Option<u64>) {
+    (function(), None)
+}
+
+/// Validates and solves a problem with a borrowed input, for adapters receiving the input
+/// without owning it, which would otherwise need to copy it into a [SolveRequest].
+///
+/// # Example
+/// ```
+/// use advent_of_code_service::{ErrorKind, solve_input};
+/// assert_eq!(solve_input(2019, 1, 1, "14"), Ok("2".to_string()));
+/// assert_eq!(solve_input(2019, 26, 1, "14").unwrap_err().kind, ErrorKind::UnsupportedPuzzle);
+/// ```
+pub fn solve_input(year: u16, day: u8, part: u8, input: &str) -> Result<String, SolveError> {
+    if !advent_of_code::is_supported(year, day, part) {
+        return Err(SolveError::unsupported(year, day, part));
+    }
+    advent_of_code::solve(year, day, part, input)
+        .map_err(|message| SolveError::from_problem_message(year, day, part, message))
+}
+
+/// Validates and solves a request, looking up and storing the answer in `cache`.
+///
+/// The time spent solving is measured, except for cached answers and on
+/// `wasm32-unknown-unknown` where no clock is available.
+pub fn solve(request: &SolveRequest, cache: &impl AnswerCache) -> SolveResponse {
+    complete_now(solve_async(request, &Synchronous(cache)))
+}
+
+/// Runs a future which never suspends, such as one only awaiting a [Synchronous] cache,
+/// to completion on the current thread.
+///
+/// # Panics
+///
+/// Panics if the future suspends.
+pub fn complete_now<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("A future expected to complete on its first poll suspended"),
+    }
+}
+
+/// Validates and solves a request like [solve], but with a cache accessed asynchronously.
+///
+/// The future is `Send` if the futures of the cache are.
+#[allow(clippy::future_not_send)]
+pub async fn solve_async(request: &SolveRequest, cache: &impl AsyncAnswerCache) -> SolveResponse {
+    if let Err(error) = request.validate() {
+        return Err(error).into();
+    }
+
+    let key = cache_key(request);
+    if let Some(answer) = cache.get(&key).await {
+        return Ok(answer).into();
+    }
+
+    let (result, elapsed_micros) = measure(|| request.solve());
+    if let Ok(answer) = &result {
+        cache.put(&key, answer).await;
+    }
+    SolveResponse {
+        elapsed_micros,
+        ..result.into()
+    }
+}
+
+/// Returns the answers to both parts of the specified problem.
+///
+/// # Example
+/// ```
+/// use advent_of_code_service::{Answers, NoCache, solve_both};
+/// assert_eq!(
+///     solve_both(2019, 1, "1969", &NoCache),
+///     Ok(Answers { part_one: "654".to_string(), part_two: Some("966".to_string()) })
+/// );
+/// ```
+pub fn solve_both(
+    year: u16,
+    day: u8,
+    input: &str,
+    cache: &impl AnswerCache,
+) -> Result<Answers, SolveError> {
+    let mut request = SolveRequest::new(year, day, 1, input);
+    let part_one = solve(&request, cache).into_result()?;
+    let has_part_two = advent_of_code::puzzles()
+        .any(|puzzle| puzzle.year == year && puzzle.day == day && puzzle.parts == 2);
+    let part_two = if has_part_two {
+        request.part = 2;
+        Some(solve(&request, cache).into_result()?)
+    } else {
+        None
+    };
+    Ok(Answers { part_one, part_two })
+}
+
+/// Returns the problems which have solutions, ordered by year and day.
+pub fn puzzles() -> Vec<Puzzle> {
+    advent_of_code::puzzles().map(Puzzle::from).collect()
+}
+
+/// Returns a JSON Schema describing the JSON representation of the model types.
+pub fn json_schema() -> serde_json::Value {
+    let mut generator = schemars::generate::SchemaSettings::draft2020_12().into_generator();
+    generator.subschema_for::<SolveRequest>();
+    generator.subschema_for::<SolveResponse>();
+    generator.subschema_for::<Answers>();
+    generator.subschema_for::<Puzzle>();
+    serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "Advent of Code service",
+        "$defs": generator.take_definitions(true),
+    })
+}
+
+#[test]
+fn solve_and_cache() {
+    let cache = MemoryCache::default();
+
+    let request = SolveRequest::new(2019, 1, 1, "14");
+    let response = solve(&request, &cache);
+    assert_eq!(response.answer.as_deref(), Some("2"));
+    assert!(response.elapsed_micros.is_some());
+    assert_eq!(cache.get(&cache_key(&request)).as_deref(), Some("2"));
+
+    cache.put(&cache_key(&request), "cached");
+    let response = solve(&request, &cache);
+    assert_eq!(response.answer.as_deref(), Some("cached"));
+    assert_eq!(response.elapsed_micros, None);
+
+    for (request, kind, status) in [
+        (
+            SolveRequest::new(2019, 1, 1, "14\nhej"),
+            ErrorKind::InvalidInput,
+            400,
+        ),
+        (
+            SolveRequest::new(2019, 26, 1, "14"),
+            ErrorKind::UnsupportedPuzzle,
+            404,
+        ),
+        (
+            SolveRequest::new(2017, 18, 1, "jgz 1 0"),
+            ErrorKind::Timeout,
+            422,
+        ),
+    ] {
+        let error = solve(&request, &cache).into_result().unwrap_err();
+        assert_eq!((error.kind, error.http_status()), (kind, status));
+    }
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn json() {
+    let request: SolveRequest =
+        serde_json::from_str(r#"{"year":2019,"day":1,"part":1,"input":"14\nhej"}"#).unwrap();
+    let response = solve(&request, &NoCache);
+    assert_eq!(
+        serde_json::to_string(&SolveResponse {
+            elapsed_micros: None,
+            ..response
+        })
+        .unwrap(),
+        r#"{"error":{"kind":"invalidInput","message":"Line 2: invalid digit found in string","line":2}}"#
+    );
+    assert_eq!(
+        serde_json::to_string(&solve_both(2019, 1, "1969", &NoCache).unwrap()).unwrap(),
+        r#"{"partOne":"654","partTwo":"966"}"#
+    );
+}
+
+#[test]
+fn schema() {
+    // Run with UPDATE_SCHEMA=1 to update the checked in schema after changing the model.
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/schema.json");
+    let generated = format!("{:#}\n", json_schema());
+    if std::env::var_os("UPDATE_SCHEMA").is_some() {
+        std::fs::write(path, &generated).unwrap();
+    }
+    assert_eq!(std::fs::read_to_string(path).unwrap(), generated);
+}
@@ -0,0 +1,226 @@
+use advent_of_code::{ErrorKind, error_position, is_supported};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// A request to solve a problem.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SolveRequest {
+    /// The year of the problem, as in 2018 or 2019.
+    pub year: u16,
+    /// The day of the problem, from 1 to 25 (or to 12 from 2025).
+    pub day: u8,
+    /// The part of the problem, either 1 or 2.
+    pub part: u8,
+    /// The input to the problem.
+    pub input: String,
+}
+
+impl SolveRequest {
+    pub fn new(year: u16, day: u8, part: u8, input: impl Into<String>) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            input: input.into(),
+        }
+    }
+
+    /// Parses a request with the problem given as text, such as path parameters.
+    ///
+    /// # Example
+    /// ```
+    /// use advent_of_code_service::{ErrorKind, SolveRequest};
+    /// assert!(SolveRequest::parse("2019", "1", "1", "14").is_ok());
+    /// let error = SolveRequest::parse("2019", "first", "1", "14").unwrap_err();
+    /// assert_eq!(error.kind, ErrorKind::UnsupportedPuzzle);
+    /// ```
+    pub fn parse(
+        year: &str,
+        day: &str,
+        part: &str,
+        input: impl Into<String>,
+    ) -> Result<Self, SolveError> {
+        match (year.parse(), day.parse(), part.parse()) {
+            (Ok(year), Ok(day), Ok(part)) => Ok(Self::new(year, day, part, input)),
+            _ => Err(SolveError::unsupported(year, day, part)),
+        }
+    }
+
+    /// Checks that there is a solution for the requested problem.
+    pub fn validate(&self) -> Result<(), SolveError> {
+        if is_supported(self.year, self.day, self.part) {
+            Ok(())
+        } else {
+            Err(SolveError::unsupported(self.year, self.day, self.part))
+        }
+    }
+
+    /// Validates the request and solves the problem.
+    pub fn solve(&self) -> Result<String, SolveError> {
+        crate::solve_input(self.year, self.day, self.part, &self.input)
+    }
+}
+
+// Serializes the ErrorKind of the core crate by its name:
+/// The kind of error which prevented a problem from being solved.
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(remote = "ErrorKind", rename_all = "camelCase")]
+enum ErrorKindDef {
+    /// The input could not be parsed or solved.
+    InvalidInput,
+    /// There is no solution for the specified year, day and part.
+    UnsupportedPuzzle,
+    /// The solver gave up after exceeding its computation budget.
+    Timeout,
+    /// Solving was cancelled.
+    Cancelled,
+}
+
+/// An error which prevented a problem from being solved.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SolveError {
+    #[serde(with = "ErrorKindDef")]
+    pub kind: ErrorKind,
+    /// A description of the error.
+    pub message: String,
+    /// The line of the input causing the error, starting from 1, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// The column of the input causing the error, starting from 1, if known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<u32>,
+}
+
+impl SolveError {
+    pub fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            line: None,
+            column: None,
+        }
+    }
+
+    pub fn unsupported(year: impl Display, day: impl Display, part: impl Display) -> Self {
+        Self::new(
+            ErrorKind::UnsupportedPuzzle,
+            format!("Unsupported year={year}, day={day}, part={part}"),
+        )
+    }
+
+    /// Classifies an error message from solving a request, extracting the position from
+    /// messages starting as in "Line 3: ..." or "Line 3, column 7: ...".
+    ///
+    /// # Example
+    /// ```
+    /// use advent_of_code_service::{ErrorKind, SolveError, SolveRequest};
+    /// let request = SolveRequest::new(2019, 1, 1, "14\nhej");
+    /// let error = SolveError::from_message(&request, "Line 2, column 1: Invalid number".to_string());
+    /// assert_eq!((error.kind, error.line, error.column), (ErrorKind::InvalidInput, Some(2), Some(1)));
+    /// ```
+    pub fn from_message(request: &SolveRequest, message: String) -> Self {
+        Self::from_problem_message(request.year, request.day, request.part, message)
+    }
+
+    pub(crate) fn from_problem_message(year: u16, day: u8, part: u8, message: String) -> Self {
+        let kind = ErrorKind::of(year, day, part, &message);
+        let (line, column) = error_position(&message);
+        Self {
+            kind,
+            message,
+            line,
+            column,
+        }
+    }
+
+    /// The HTTP status code to respond with for this error.
+    pub const fn http_status(&self) -> u16 {
+        match self.kind {
+            ErrorKind::InvalidInput => 400,
+            ErrorKind::UnsupportedPuzzle => 404,
+            ErrorKind::Timeout => 422,
+            // Client Closed Request, as used by nginx:
+            ErrorKind::Cancelled => 499,
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// The response to a request to solve a problem, with either an answer or an error.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SolveResponse {
+    /// The answer, if the problem was solved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    /// The error, if the problem could not be solved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<SolveError>,
+    /// The time spent solving in microseconds, if measured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub elapsed_micros: Option<u64>,
+}
+
+impl SolveResponse {
+    pub fn into_result(self) -> Result<String, SolveError> {
+        match (self.answer, self.error) {
+            (Some(answer), None) => Ok(answer),
+            (_, Some(error)) => Err(error),
+            (None, None) => Err(SolveError::new(
+                ErrorKind::InvalidInput,
+                "Neither answer nor error in response",
+            )),
+        }
+    }
+}
+
+impl From<Result<String, SolveError>> for SolveResponse {
+    fn from(result: Result<String, SolveError>) -> Self {
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer), None),
+            Err(error) => (None, Some(error)),
+        };
+        Self {
+            answer,
+            error,
+            elapsed_micros: None,
+        }
+    }
+}
+
+/// The answers to both parts of a problem.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Answers {
+    pub part_one: String,
+    /// Absent for problems with a single part.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub part_two: Option<String>,
+}
+
+/// A problem which has a solution.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    /// 1 for the last day of a year, otherwise 2.
+    pub parts: u8,
+}
+
+impl From<advent_of_code::Puzzle> for Puzzle {
+    fn from(puzzle: advent_of_code::Puzzle) -> Self {
+        Self {
+            year: puzzle.year,
+            day: puzzle.day,
+            parts: puzzle.parts,
+        }
+    }
+}
@@ -10,6 +10,7 @@ mv target/nodejs/advent_of_code_wasm_bg.wasm-optimized target/nodejs/advent_of_c
 
 cp README.md target/nodejs/
 cp cli.js target/nodejs/
+cp solver-pool.js solver-worker.js target/nodejs/
 
 PACKAGE_VERSION=`cargo metadata --format-version 1 | jq -r '.packages[] | select(.name=="advent-of-code").version'`
 
@@ -36,6 +37,8 @@ cat > package.json << EOF
     "advent_of_code_wasm.js",
     "advent_of_code_wasm_bg.js",
     "advent_of_code_wasm.d.ts",
+    "solver-pool.js",
+    "solver-worker.js",
     "LICENSE.txt"
   ],
   "main": "advent_of_code_wasm.js",
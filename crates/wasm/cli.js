@@ -1,18 +1,29 @@
 #!/usr/bin/env node
 
 const fs = require("fs");
-const solve = require("advent-of-code-wasm").solve;
+const { solve, solveBoth } = require("advent-of-code-wasm");
 
-const year = parseInt(process.argv[2]);
-const day = parseInt(process.argv[3]);
-const part = parseInt(process.argv[4]);
+const args = process.argv.slice(2).map((arg) => Number(arg));
+if (![2, 3].includes(args.length) || !args.every(Number.isInteger)) {
+  console.error("usage: advent-of-code-wasm YEAR DAY [PART] < INPUT");
+  process.exit(1);
+}
+const [year, day, part] = args;
 
 const input = fs.readFileSync(0, "utf8");
 
 try {
-  const output = solve(year, day, part, input);
-  console.log(output);
+  if (part === undefined) {
+    const answers = solveBoth(year, day, input);
+    console.log(answers.partOne);
+    if (answers.partTwo !== undefined) {
+      console.log(answers.partTwo);
+    }
+  } else {
+    console.log(solve(year, day, part, input));
+  }
 } catch (e) {
-  console.error(e.message);
-  process.exit(1);
+  const position = e.line === undefined ? "" : ` (line ${e.line})`;
+  console.error(`${e.kind ?? "error"}${position}: ${e.message}`);
+  process.exit(e.kind === "unsupportedPuzzle" ? 2 : 1);
 }
@@ -1,14 +1,15 @@
-const solve = require("advent-of-code-rs-wasm").solve;
+const { corsHeaders, solve } = require("advent-of-code-rs-wasm");
 
 exports.handler = async function (event, context) {
-  const headers = {
-    "Access-Control-Allow-Origin": "*",
-  };
+  const headers = corsHeaders();
 
-  if (event.httpMethod !== "POST") {
+  if (event.httpMethod === "OPTIONS") {
+    return { statusCode: 204, headers, body: "" };
+  } else if (event.httpMethod !== "POST") {
     return {
-      statusCode: 400,
+      statusCode: 405,
       headers: {
+        ...headers,
         Allow: "POST",
       },
       body: "This endpoint only supports HTTP POST.",
@@ -16,14 +17,17 @@ exports.handler = async function (event, context) {
   }
 
   const pathParts = event.path.substring(1).split("/");
-  if (pathParts.length != 4) {
+  const numbers = pathParts.slice(1).map((part) => Number(part));
+  if (pathParts.length != 4 || !numbers.every(Number.isInteger)) {
     return {
       statusCode: 400,
       headers,
-      body: "Invalid path - expected /solve/{YEAR}/{DAY}/{PART}, was: " + path,
+      body:
+        "Invalid path - expected /solve/{YEAR}/{DAY}/{PART}, was: " +
+        event.path,
     };
   }
-  const [, year, day, part] = pathParts;
+  const [year, day, part] = numbers;
 
   try {
     const input = event.body;
@@ -35,7 +39,7 @@ exports.handler = async function (event, context) {
     };
   } catch (e) {
     return {
-      statusCode: 400,
+      statusCode: e.httpStatus ?? 500,
       headers,
       body: e.message,
     };
@@ -17,17 +17,13 @@ function sumDown(map) {
 
 async function updatePage() {
   const baselineJson = await baselineJsonPromise;
-  const benchmarks = Object.entries(baselineJson);
 
   const dataMap = {};
-  for (const [_benchmarkName, benchmarkInfo] of benchmarks) {
-    const year = benchmarkInfo["year"];
-    const day = benchmarkInfo["day"];
-    const part = benchmarkInfo["part"];
-    const cycles = benchmarkInfo["cycles"];
+  for (const measurement of baselineJson.measurements) {
+    const { year, day, part, instructions } = measurement;
     if (!dataMap[year]) dataMap[year] = {};
     if (!dataMap[year][day]) dataMap[year][day] = {};
-    dataMap[year][day][part] = cycles;
+    dataMap[year][day][part] = instructions;
   }
 
   const data = {
@@ -1,4 +1,5 @@
 import gistMapping from "./gist-mapping.json";
+import { SolverPool } from "../solver-pool.js";
 
 const yearElement = document.getElementById("year");
 const dayElement = document.getElementById("day");
@@ -11,7 +12,6 @@ const showElement = document.getElementById("run-visualizer");
 
 const workers = {
   api: null,
-  wasm: null,
 };
 
 function reloadApiWorker() {
@@ -19,20 +19,82 @@ function reloadApiWorker() {
   workers.api = new Worker(new URL("./worker-api.js", import.meta.url), {
     name: "api-solver",
   });
-  workers.api.onmessage = onWorkerMessage;
-}
-function reloadWasmWorker() {
-  if (workers.wasm) workers.wasm.terminate();
-  workers.wasm = new Worker(new URL("./worker-wasm.js", import.meta.url), {
-    name: "wasm-solver",
-  });
-  workers.wasm.onmessage = onWorkerMessage;
+  workers.api.onmessage = (e) => onAnswer(e.data);
 }
 
 reloadApiWorker();
-reloadWasmWorker();
+
+const wasmPool = new SolverPool({
+  createWorker: () =>
+    new Worker(new URL("./worker-solver.js", import.meta.url), {
+      name: "wasm-solver",
+    }),
+  size: 1,
+});
+const wasmJob = { controller: null };
+
+function cancelWasmJob() {
+  wasmJob.controller?.abort();
+  wasmJob.controller = null;
+}
+
+async function solveWasm(year, day, part, input) {
+  cancelWasmJob();
+  const controller = new AbortController();
+  wasmJob.controller = controller;
+
+  const onProgress = (progress) => {
+    if (wasmJob.controller === controller) {
+      outputElement.textContent = `The answer is being computed... ${Math.round(
+        progress * 100,
+      )}%`;
+    }
+  };
+
+  const problem = { worker: "wasm", year, day, part, input };
+  const startTime = performance.now();
+  try {
+    const answer = await wasmPool.solveAsync(year, day, part, input, {
+      onProgress,
+      signal: controller.signal,
+    });
+    const executionTime = performance.now() - startTime;
+    console.log(
+      `Wasm ${year}-${day}-${part} solution in: ${executionTime.toFixed(2)} ms`,
+    );
+    onAnswer({ ...problem, output: answer, executionTime });
+  } catch (error) {
+    if (controller.signal.aborted) {
+      // Cancelled by a newer problem or by an API answer.
+      return;
+    }
+    console.error(error);
+    // Errors thrown by the solvers have a kind, while the worker reports
+    // failures of the WebAssembly itself as internal errors:
+    const isInternalError = error.kind === "internal";
+    onAnswer({
+      ...problem,
+      output: isInternalError
+        ? "• WebAssembly: " + error.message
+        : error.message,
+      isError: true,
+      isInternalError,
+      executionTime: performance.now() - startTime,
+    });
+  }
+}
 
 const wasmWorking = { value: true };
+
+// Start the worker and initialize the WebAssembly before the first problem is
+// solved, so that it is not included in the execution time:
+wasmPool.solveAsync(2019, 1, 1, "14").catch((error) => {
+  if (error.kind === "internal") {
+    console.warn("WebAssembly not working", error);
+    wasmWorking.value = false;
+    showElement.disabled = !isVisualisationEnabled();
+  }
+});
 const currentProblem = {
   year: 0,
   day: 0,
@@ -42,88 +104,82 @@ const currentProblem = {
   isInternalError: false,
 };
 
-function onWorkerMessage(e) {
-  if ("wasmWorking" in e.data) {
-    if (!e.data.wasmWorking) {
-      wasmWorking.value = false;
-    }
-  } else {
-    const {
-      worker,
-      isError,
-      isInternalError,
-      output,
-      executionTime,
-      year,
-      day,
-      part,
-      input,
-    } = e.data;
-
-    const secondAnswer =
-      currentProblem.year === year &&
-      currentProblem.day == day &&
-      currentProblem.part === part &&
-      currentProblem.input == input;
-
-    let extraOutput = "";
-
-    if (isInternalError) {
-      if (currentProblem.isInternalError) {
-        // Both workers have failed with internal errors.
-        extraOutput = "\n" + currentProblem.output;
-      } else if (secondAnswer) {
-        // This was the second answer, but first one was ok - ignore.
-        return;
-      } else {
-        // If this was the first worker, await other one which may have better luck.
-        currentProblem.isInternalError = true;
-        currentProblem.output = output;
-        return;
-      }
-    }
-
-    if (secondAnswer) {
-      // This is the second message about this problem.
+function onAnswer(answer) {
+  const {
+    worker,
+    isError,
+    isInternalError,
+    output,
+    executionTime,
+    year,
+    day,
+    part,
+    input,
+  } = answer;
+
+  const secondAnswer =
+    currentProblem.year === year &&
+    currentProblem.day == day &&
+    currentProblem.part === part &&
+    currentProblem.input == input;
+
+  let extraOutput = "";
+
+  if (isInternalError) {
+    if (currentProblem.isInternalError) {
+      // Both workers have failed with internal errors.
+      extraOutput = "\n" + currentProblem.output;
+    } else if (secondAnswer) {
+      // This was the second answer, but first one was ok - ignore.
       return;
-    } else if (worker == "api") {
-      // We got a API response first and can abort the Wasm worker.
-      reloadWasmWorker();
-    } else if (worker == "wasm") {
-      // We got a Wasm response first and can abort the API worker.
-      reloadApiWorker();
-    }
-
-    currentProblem.isInternalError = false;
-    currentProblem.year = year;
-    currentProblem.day = day;
-    currentProblem.part = part;
-    currentProblem.input = input;
-
-    runButton.classList.remove("in-progress");
-    runButton.disabled = false;
-
-    const roundedTime = Math.round(executionTime);
-    executionTimeElement.textContent = `${
-      roundedTime == 0
-        ? executionTime.toFixed(2)
-        : roundedTime.toLocaleString("en")
-    } ms`;
-
-    outputElement.classList.remove("alert-info");
-    if (isError) {
-      outputElement.classList.add("alert-danger");
-      outputElement.classList.remove("alert-success");
     } else {
-      outputElement.classList.add("alert-success");
-      outputElement.classList.remove("alert-danger");
+      // If this was the first worker, await other one which may have better luck.
+      currentProblem.isInternalError = true;
+      currentProblem.output = output;
+      return;
     }
-    outputElement.textContent =
-      (isInternalError ? "⚠ Internal Error ⚠\n\n" : "") + output + extraOutput;
-    outputElement.scrollIntoView();
-    outputElement.classList.add("blink");
-    outputElement.focus();
   }
+
+  if (secondAnswer) {
+    // This is the second message about this problem.
+    return;
+  } else if (worker == "api") {
+    // We got a API response first and can cancel the Wasm solver.
+    cancelWasmJob();
+  } else if (worker == "wasm") {
+    // We got a Wasm response first and can abort the API worker.
+    reloadApiWorker();
+  }
+
+  currentProblem.isInternalError = false;
+  currentProblem.year = year;
+  currentProblem.day = day;
+  currentProblem.part = part;
+  currentProblem.input = input;
+
+  runButton.classList.remove("in-progress");
+  runButton.disabled = false;
+
+  const roundedTime = Math.round(executionTime);
+  executionTimeElement.textContent = `${
+    roundedTime == 0
+      ? executionTime.toFixed(2)
+      : roundedTime.toLocaleString("en")
+  } ms`;
+
+  outputElement.classList.remove("alert-info");
+  if (isError) {
+    outputElement.classList.add("alert-danger");
+    outputElement.classList.remove("alert-success");
+  } else {
+    outputElement.classList.add("alert-success");
+    outputElement.classList.remove("alert-danger");
+  }
+  outputElement.textContent =
+    (isInternalError ? "⚠ Internal Error ⚠\n\n" : "") + output + extraOutput;
+  outputElement.scrollIntoView();
+  outputElement.classList.add("blink");
+  outputElement.focus();
 }
 
 function execute() {
@@ -148,7 +204,7 @@ function execute() {
     currentProblem.output = null;
     currentProblem.isInternalError = false;
     workers.api.postMessage({ year, day, part, input });
-    workers.wasm.postMessage({ year, day, part, input });
+    solveWasm(year, day, part, input);
     outputElement.classList.remove("alert-danger");
     outputElement.classList.remove("alert-success");
     outputElement.classList.add("alert-info");
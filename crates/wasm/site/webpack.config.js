@@ -54,6 +54,19 @@ export default {
     filename: "bundle-[name]-[contenthash].js",
     chunkFilename: "chunk-[name]-[contenthash].js",
   },
+  module: {
+    rules: [
+      {
+        // The solver pool is CommonJS, shared with the Node.js package:
+        test: /solver-pool\.js$/,
+        type: "javascript/auto",
+      },
+    ],
+  },
+  resolve: {
+    // Only used by solveAsync() on Node.js:
+    fallback: { os: false, path: false, worker_threads: false },
+  },
   devServer: {
     static: ".",
     allowedHosts: "all",
@@ -0,0 +1,16 @@
+// Worker side of ../solver-pool.js in the browser, solving one problem at a
+// time like ../solver-worker.js does on Node.js.
+import init, { solveWithControl } from "./generated/advent_of_code_wasm.js";
+import { workerReply } from "../solver-pool.js";
+
+const wasmReady = init();
+
+self.onmessage = async (message) => {
+  const solve = await wasmReady.then(
+    () => solveWithControl,
+    (error) => () => {
+      throw new Error(`WebAssembly not working: ${error}`);
+    },
+  );
+  postMessage(workerReply(message.data, solve));
+};
@@ -0,0 +1,312 @@
+// A pool of workers solving problems off the calling thread, with progress
+// and cancellation, on Node.js worker threads or browser Web Workers.
+//
+// Each job gets a small control block in a SharedArrayBuffer, laid out like
+// the header of the ring buffer used by the visualizer (see
+// site/show/ringbuffer.js):
+//
+// - CONTROL_CANCEL_OFFSET: Set to 1 by this side to request cancellation.
+// - CONTROL_PROGRESS_OFFSET: Progress in millionths, stored by the solver.
+//
+// The ring buffer itself is not used, as it streams data one way out of the
+// memory of a WebAssembly module built with shared memory, while solving
+// only needs these two values and works with the regular build.
+//
+// A worker receives messages { id, year, day, part, input, control } and
+// replies with { id, answer } or
+// { id, error: { kind, message, line, column, httpStatus } }, as created by
+// workerReply(). See solver-worker.js for Node.js and site/worker-solver.js
+// for browsers.
+const CONTROL_ELEMENTS_LENGTH = 2;
+const CONTROL_CANCEL_OFFSET = 0;
+const CONTROL_PROGRESS_OFFSET = 1;
+
+const DEFAULT_PROGRESS_INTERVAL_MS = 50;
+const DEFAULT_CANCEL_GRACE_MS = 200;
+
+class AbortError extends Error {
+  constructor(message = "The operation was aborted") {
+    super(message);
+    this.name = "AbortError";
+  }
+}
+
+function addListener(worker, type, listener) {
+  if (typeof worker.on === "function") {
+    // Node.js worker_threads:
+    worker.on(type, listener);
+  } else {
+    // Web Worker:
+    worker.addEventListener(type, (event) =>
+      listener(type === "message" ? event.data : event),
+    );
+  }
+}
+
+/**
+ * Solves the problem of a message sent by a pool to a worker, returning the
+ * reply to post back.
+ *
+ * @param {object} message The message received by the worker.
+ * @param {Function} solveWithControl The function of the WebAssembly module.
+ */
+function workerReply(
+  { id, year, day, part, input, control },
+  solveWithControl,
+) {
+  try {
+    return { id, answer: solveWithControl(year, day, part, input, control) };
+  } catch (e) {
+    return {
+      id,
+      error: {
+        kind: e.kind ?? "internal",
+        message: e.message,
+        line: e.line,
+        column: e.column,
+        httpStatus: e.httpStatus,
+      },
+    };
+  }
+}
+
+/**
+ * Creates a control block, shared with the worker if possible.
+ *
+ * Pages which are not cross-origin isolated have no SharedArrayBuffer, in
+ * which case no progress is reported and a cancelled solver is stopped by
+ * terminating its worker.
+ */
+function createControl() {
+  const byteLength = CONTROL_ELEMENTS_LENGTH * Int32Array.BYTES_PER_ELEMENT;
+  return new Int32Array(
+    typeof SharedArrayBuffer === "function"
+      ? new SharedArrayBuffer(byteLength)
+      : new ArrayBuffer(byteLength),
+  );
+}
+
+function toError(error) {
+  const result = new Error(error.message);
+  result.name = "AocError";
+  result.kind = error.kind;
+  result.line = error.line;
+  result.column = error.column;
+  result.httpStatus = error.httpStatus;
+  return result;
+}
+
+class SolverPool {
+  /**
+   * @param {object} options
+   * @param {() => Worker} options.createWorker Creates a worker running solver-worker.js
+   *   on Node.js, or a Web Worker like site/worker-solver.js in browsers.
+   * @param {number} options.size The maximum number of workers.
+   * @param {number} [options.progressIntervalMs] How often to check for progress.
+   * @param {number} [options.cancelGraceMs] How long to wait for a cancelled solver to
+   * stop before terminating its worker, for solvers not checking for cancellation.
+   */
+  constructor({ createWorker, size, progressIntervalMs, cancelGraceMs }) {
+    this.createWorker = createWorker;
+    this.size = size;
+    this.progressIntervalMs =
+      progressIntervalMs ?? DEFAULT_PROGRESS_INTERVAL_MS;
+    this.cancelGraceMs = cancelGraceMs ?? DEFAULT_CANCEL_GRACE_MS;
+    this.idleWorkers = [];
+    this.workerCount = 0;
+    this.queue = [];
+    this.nextJobId = 1;
+  }
+
+  /**
+   * Solves a problem on a worker.
+   *
+   * @param {number} year
+   * @param {number} day
+   * @param {number} part
+   * @param {string} input
+   * @param {object} [options]
+   * @param {(progress: number) => void} [options.onProgress] Called with progress from 0 to 1.
+   * @param {AbortSignal} [options.signal] Cancels solving when aborted.
+   * @returns {Promise<string>} The answer.
+   */
+  solveAsync(year, day, part, input, { onProgress, signal } = {}) {
+    return new Promise((resolve, reject) => {
+      if (signal?.aborted) {
+        reject(signal.reason ?? new AbortError());
+        return;
+      }
+
+      const job = {
+        id: this.nextJobId++,
+        message: { year, day, part, input },
+        onProgress,
+        resolve,
+        reject,
+        control: createControl(),
+      };
+
+      if (signal) {
+        const onAbort = () =>
+          this._abort(job, signal.reason ?? new AbortError());
+        signal.addEventListener("abort", onAbort, { once: true });
+        job.removeAbortListener = () =>
+          signal.removeEventListener("abort", onAbort);
+      }
+
+      this.queue.push(job);
+      this._dispatch();
+    });
+  }
+
+  /** Terminates all workers, rejecting pending jobs. */
+  terminate() {
+    for (const job of this.queue) {
+      this._settle(job, () => job.reject(new AbortError("Pool terminated")));
+    }
+    this.queue = [];
+    for (const entry of this.idleWorkers) {
+      entry.worker.terminate();
+    }
+    this.idleWorkers = [];
+    this.terminated = true;
+  }
+
+  _dispatch() {
+    while (this.queue.length > 0) {
+      let entry = this.idleWorkers.pop();
+      if (!entry) {
+        if (this.workerCount >= this.size) {
+          return;
+        }
+        entry = this._startWorker();
+      }
+      this._run(entry, this.queue.shift());
+    }
+  }
+
+  _startWorker() {
+    const entry = { worker: this.createWorker(), job: null };
+    this.workerCount++;
+    addListener(entry.worker, "message", (reply) =>
+      this._onReply(entry, reply),
+    );
+    addListener(entry.worker, "error", (error) => {
+      const job = entry.job;
+      this._discardWorker(entry);
+      if (job) {
+        this._settle(job, () => job.reject(error));
+      }
+      this._dispatch();
+    });
+    return entry;
+  }
+
+  _run(entry, job) {
+    entry.job = job;
+    job.entry = entry;
+    entry.worker.ref?.();
+    entry.worker.postMessage({
+      id: job.id,
+      ...job.message,
+      control: job.control,
+    });
+
+    if (job.onProgress) {
+      let lastProgress = 0;
+      job.progressTimer = setInterval(() => {
+        const progress = Atomics.load(job.control, CONTROL_PROGRESS_OFFSET);
+        if (progress !== lastProgress) {
+          lastProgress = progress;
+          job.onProgress(progress / 1_000_000);
+        }
+      }, this.progressIntervalMs);
+    }
+  }
+
+  _onReply(entry, reply) {
+    const job = entry.job;
+    if (!job || reply.id !== job.id) {
+      return;
+    }
+    clearTimeout(entry.cancelTimer);
+    entry.job = null;
+    if (this.terminated) {
+      entry.worker.terminate();
+    } else {
+      // Idle workers should not keep a Node.js process alive:
+      entry.worker.unref?.();
+      this.idleWorkers.push(entry);
+    }
+    this._settle(job, () => {
+      if (reply.error) {
+        job.reject(toError(reply.error));
+      } else {
+        job.resolve(reply.answer);
+      }
+    });
+    this._dispatch();
+  }
+
+  _abort(job, reason) {
+    const queueIndex = this.queue.indexOf(job);
+    if (queueIndex !== -1) {
+      this.queue.splice(queueIndex, 1);
+    } else if (job.entry && job.entry.job === job) {
+      // Ask the solver to stop, terminating the worker if it does not in time:
+      const entry = job.entry;
+      Atomics.store(job.control, CONTROL_CANCEL_OFFSET, 1);
+      entry.cancelTimer = setTimeout(() => {
+        if (entry.job === job) {
+          this._discardWorker(entry);
+          this._dispatch();
+        }
+      }, this.cancelGraceMs);
+    }
+    this._settle(job, () => job.reject(reason));
+  }
+
+  _discardWorker(entry) {
+    clearTimeout(entry.cancelTimer);
+    entry.job = null;
+    entry.worker.terminate();
+    this.workerCount--;
+    const idleIndex = this.idleWorkers.indexOf(entry);
+    if (idleIndex !== -1) {
+      this.idleWorkers.splice(idleIndex, 1);
+    }
+  }
+
+  _settle(job, settle) {
+    if (job.settled) {
+      return;
+    }
+    job.settled = true;
+    clearInterval(job.progressTimer);
+    job.removeAbortListener?.();
+    settle();
+  }
+}
+
+let defaultPool = null;
+
+/**
+ * Solves a problem on a shared pool of Node.js worker threads, one per available core.
+ *
+ * See SolverPool.solveAsync() for the arguments.
+ */
+function solveAsync(year, day, part, input, options) {
+  if (!defaultPool) {
+    const { Worker } = require("worker_threads");
+    const os = require("os");
+    const path = require("path");
+    const workerPath = path.join(__dirname, "solver-worker.js");
+    defaultPool = new SolverPool({
+      createWorker: () => new Worker(workerPath),
+      size: os.availableParallelism?.() ?? os.cpus().length,
+    });
+  }
+  return defaultPool.solveAsync(year, day, part, input, options);
+}
+
+module.exports = { AbortError, SolverPool, solveAsync, workerReply };
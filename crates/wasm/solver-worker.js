@@ -0,0 +1,9 @@
+// Worker side of solver-pool.js, solving one problem at a time on a Node.js
+// worker thread.
+const { parentPort } = require("worker_threads");
+const { solveWithControl } = require("./advent_of_code_wasm.js");
+const { workerReply } = require("./solver-pool.js");
+
+parentPort.on("message", (message) => {
+  parentPort.postMessage(workerReply(message, solveWithControl));
+});
@@ -1,27 +1,274 @@
 #![allow(clippy::unused_unit)]
-use advent_of_code::solve_raw;
+use advent_of_code::{ErrorKind, puzzles, solve_with_progress};
+use advent_of_code_service::{
+    CORS_HEADERS, NoCache, SolveError, SolveRequest, solve_both as solve_both_parts,
+};
+use js_sys::{Array, Atomics, Int32Array, Object, Reflect};
 use wasm_bindgen::prelude::*;
 
-fn as_string(value: &JsValue) -> String {
-    value.as_string().unwrap_or_else(|| {
-        value
-            .as_f64()
-            .map_or_else(|| "".to_string(), |number| number.to_string())
-    })
+#[wasm_bindgen(typescript_custom_section)]
+const TYPESCRIPT_TYPES: &str = r#"
+/** The answers to both parts of a problem. */
+export interface Answers {
+  partOne: string;
+  /** Absent for problems with a single part. */
+  partTwo?: string;
+  /** The time spent solving in milliseconds, if requested with the timing option. */
+  elapsedMs?: number;
 }
 
+export interface SolveOptions {
+  /** Whether to include the time spent solving in the result. */
+  timing?: boolean;
+}
+
+/** A named intermediate value recorded while solving a problem. */
+export interface TraceEntry {
+  name: string;
+  value: string;
+}
+
+/** An answer together with the intermediate values explaining how it was found. */
+export interface TracedAnswer {
+  answer: string;
+  trace: TraceEntry[];
+}
+
+/** A problem which has a solution. */
+export interface Puzzle {
+  year: number;
+  day: number;
+  /** 1 for the last day of a year, otherwise 2. */
+  parts: number;
+}
+"#;
+
+/// The kind of error which prevented a problem from being solved.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub enum AocErrorKind {
+    /// The input could not be parsed or solved.
+    InvalidInput = "invalidInput",
+    /// There is no solution for the specified year, day and part.
+    UnsupportedPuzzle = "unsupportedPuzzle",
+    /// The solver gave up after exceeding its computation budget.
+    Timeout = "timeout",
+    /// Solving was cancelled.
+    Cancelled = "cancelled",
+}
+
+/// An error thrown when a problem could not be solved.
+#[wasm_bindgen]
+pub struct AocError(SolveError);
+
+// The wasm_bindgen macro does not support const functions:
+#[allow(clippy::missing_const_for_fn)]
+#[wasm_bindgen]
+impl AocError {
+    #[wasm_bindgen(getter)]
+    pub fn name(&self) -> String {
+        "AocError".to_string()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn kind(&self) -> AocErrorKind {
+        match self.0.kind {
+            ErrorKind::InvalidInput => AocErrorKind::InvalidInput,
+            ErrorKind::UnsupportedPuzzle => AocErrorKind::UnsupportedPuzzle,
+            ErrorKind::Timeout => AocErrorKind::Timeout,
+            ErrorKind::Cancelled => AocErrorKind::Cancelled,
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn message(&self) -> String {
+        self.0.message.clone()
+    }
+
+    /// The line of the input causing the error, starting from 1, if known.
+    #[wasm_bindgen(getter)]
+    pub fn line(&self) -> Option<u32> {
+        self.0.line
+    }
+
+    /// The column of the input causing the error, starting from 1, if known.
+    #[wasm_bindgen(getter)]
+    pub fn column(&self) -> Option<u32> {
+        self.0.column
+    }
+
+    /// The HTTP status code to respond with for this error.
+    #[wasm_bindgen(getter, js_name = httpStatus)]
+    pub fn http_status(&self) -> u16 {
+        self.0.http_status()
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        format!("AocError: {}", self.0.message)
+    }
+}
+
+impl From<SolveError> for AocError {
+    fn from(error: SolveError) -> Self {
+        Self(error)
+    }
+}
+
+fn to_request(year: u32, day: u32, part: u32, input: &str) -> Result<SolveRequest, AocError> {
+    match (u16::try_from(year), u8::try_from(day), u8::try_from(part)) {
+        (Ok(year), Ok(day), Ok(part)) => {
+            let request = SolveRequest::new(year, day, part, input);
+            request.validate()?;
+            Ok(request)
+        }
+        _ => Err(SolveError::unsupported(year, day, part).into()),
+    }
+}
+
+fn set_property(object: &Object, key: &str, value: &JsValue) -> Result<(), JsValue> {
+    Reflect::set(object, &JsValue::from_str(key), value).map(|_| ())
+}
+
+/// Returns the answer for the specified problem and input.
+///
+/// Throws an `AocError` if the problem could not be solved.
 #[wasm_bindgen]
-pub fn solve(
-    year: &JsValue,
-    day: &JsValue,
-    part: &JsValue,
+pub fn solve(year: u32, day: u32, part: u32, input: &str) -> Result<String, AocError> {
+    #[cfg(feature = "console-panic-hook")]
+    std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+
+    Ok(to_request(year, day, part, input)?.solve()?)
+}
+
+/// Returns the answer for the specified problem and input, together with the named
+/// intermediate values recorded by the solver. Requires the `trace` feature.
+///
+/// Throws an `AocError` if the problem could not be solved.
+#[cfg(feature = "trace")]
+#[wasm_bindgen(js_name = solveWithTrace, unchecked_return_type = "TracedAnswer")]
+pub fn solve_with_trace(year: u32, day: u32, part: u32, input: &str) -> Result<JsValue, JsValue> {
+    #[cfg(feature = "console-panic-hook")]
+    std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+
+    let request = to_request(year, day, part, input)?;
+    let (result, trace) = advent_of_code::solve_with_trace(
+        request.year,
+        request.day,
+        request.part,
+        input,
+        &advent_of_code::SolveOptions::default(),
+    );
+    let answer =
+        result.map_err(|message| AocError::from(SolveError::from_message(&request, message)))?;
+
+    let entries = Array::new();
+    for entry in trace {
+        let object = Object::new();
+        set_property(&object, "name", &entry.name.into())?;
+        set_property(&object, "value", &entry.value.into())?;
+        entries.push(&object);
+    }
+    let traced = Object::new();
+    set_property(&traced, "answer", &answer.into())?;
+    set_property(&traced, "trace", &entries)?;
+    Ok(traced.into())
+}
+
+/// Returns the answers to both parts of the specified problem.
+///
+/// Throws an `AocError` if the problem could not be solved.
+#[wasm_bindgen(js_name = solveBoth, unchecked_return_type = "Answers")]
+pub fn solve_both(
+    year: u32,
+    day: u32,
+    input: &str,
+    #[wasm_bindgen(unchecked_param_type = "SolveOptions | undefined")] options: &JsValue,
+) -> Result<JsValue, JsValue> {
+    #[cfg(feature = "console-panic-hook")]
+    std::panic::set_hook(Box::new(console_error_panic_hook::hook));
+
+    let timing =
+        options.is_object() && Reflect::get(options, &JsValue::from_str("timing"))?.is_truthy();
+    let start = timing.then(js_sys::Date::now);
+
+    let request = to_request(year, day, 1, input)?;
+    let solved =
+        solve_both_parts(request.year, request.day, input, &NoCache).map_err(AocError::from)?;
+
+    let answers = Object::new();
+    set_property(&answers, "partOne", &solved.part_one.into())?;
+    if let Some(part_two) = solved.part_two {
+        set_property(&answers, "partTwo", &part_two.into())?;
+    }
+    if let Some(start) = start {
+        set_property(&answers, "elapsedMs", &(js_sys::Date::now() - start).into())?;
+    }
+    Ok(answers.into())
+}
+
+/// Returns the problems which have solutions, ordered by year and day.
+#[wasm_bindgen(js_name = listPuzzles, unchecked_return_type = "Puzzle[]")]
+pub fn list_puzzles() -> Result<Array, JsValue> {
+    puzzles()
+        .map(|puzzle| {
+            let object = Object::new();
+            set_property(&object, "year", &puzzle.year.into())?;
+            set_property(&object, "day", &puzzle.day.into())?;
+            set_property(&object, "parts", &puzzle.parts.into())?;
+            Ok(JsValue::from(object))
+        })
+        .collect()
+}
+
+/// Returns the headers allowing the API to be used from web pages on any origin.
+#[wasm_bindgen(js_name = corsHeaders, unchecked_return_type = "Record<string, string>")]
+pub fn cors_headers() -> Result<Object, JsValue> {
+    let headers = Object::new();
+    for (name, value) in CORS_HEADERS {
+        set_property(&headers, name, &value.into())?;
+    }
+    Ok(headers)
+}
+
+// Offsets into the control array, which must match solver-pool.js:
+const CONTROL_CANCEL_OFFSET: u32 = 0;
+const CONTROL_PROGRESS_OFFSET: u32 = 1;
+
+/// Returns the answer for the specified problem and input, communicating with another
+/// thread through a control array, typically backed by a `SharedArrayBuffer`.
+///
+/// Long-running solvers store their progress, in millionths, at `CONTROL_PROGRESS_OFFSET`,
+/// and are cancelled if a non-zero value is stored at `CONTROL_CANCEL_OFFSET`.
+///
+/// Throws an `AocError` if the problem could not be solved.
+#[wasm_bindgen(js_name = solveWithControl)]
+pub fn solve_with_control(
+    year: u32,
+    day: u32,
+    part: u32,
     input: &str,
-) -> Result<advent_of_code::ResultType, JsValue> {
+    control: Int32Array,
+) -> Result<String, AocError> {
     #[cfg(feature = "console-panic-hook")]
     std::panic::set_hook(Box::new(console_error_panic_hook::hook));
 
-    let year = as_string(year);
-    let day = as_string(day);
-    let part = as_string(part);
-    solve_raw(&year, &day, &part, input).map_err(|error| JsValue::from(js_sys::Error::new(&error)))
+    let request = to_request(year, day, part, input)?;
+    solve_with_progress(
+        request.year,
+        request.day,
+        request.part,
+        input,
+        move |progress| {
+            let millionths =
+                progress.completed.min(progress.total) * 1_000_000 / progress.total.max(1);
+            let _ = Atomics::store(
+                &control,
+                CONTROL_PROGRESS_OFFSET,
+                i32::try_from(millionths).unwrap_or(1_000_000),
+            );
+            Atomics::load(&control, CONTROL_CANCEL_OFFSET).is_ok_and(|cancel| cancel == 0)
+        },
+    )
+    .map_err(|message| SolveError::from_message(&request, message).into())
 }
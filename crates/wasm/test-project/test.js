@@ -1,6 +1,74 @@
 const assert = require("assert").strict;
-const solve = require("advent-of-code-wasm").solve;
+const {
+  AocError,
+  solve,
+  solveBoth,
+  listPuzzles,
+} = require("advent-of-code-wasm");
 
 assert.equal(solve(2019, 1, 1, "14"), "2");
 assert.equal(solve("2019", "1", "1", "14"), "2");
 assert.equal(solve(2019, 3, 2, "R8,U5,L5,D3\nU7,R6,D4,L4"), "30");
+
+assert.deepEqual(solveBoth(2019, 1, "1969"), {
+  partOne: "654",
+  partTwo: "966",
+});
+const timed = solveBoth(2019, 1, "1969", { timing: true });
+assert.equal(typeof timed.elapsedMs, "number");
+
+const puzzles = listPuzzles();
+assert.equal(puzzles.length, 262);
+assert.deepEqual(puzzles[0], { year: 2015, day: 1, parts: 2 });
+assert.deepEqual(puzzles[puzzles.length - 1], {
+  year: 2025,
+  day: 12,
+  parts: 1,
+});
+
+assert.throws(
+  () => solve(2019, 1, 1, "14\nhej"),
+  (e) =>
+    e instanceof AocError &&
+    e.kind === "invalidInput" &&
+    e.line === 2 &&
+    e.column === undefined,
+);
+assert.throws(
+  () => solve(2019, 26, 1, "14"),
+  (e) => e instanceof AocError && e.kind === "unsupportedPuzzle",
+);
+assert.throws(
+  () => solve(2017, 18, 1, "jgz 1 0"),
+  (e) => e instanceof AocError && e.kind === "timeout",
+);
+
+const {
+  AbortError,
+  solveAsync,
+} = require("advent-of-code-wasm/solver-pool.js");
+
+(async () => {
+  assert.equal(await solveAsync(2019, 1, 1, "14"), "2");
+  await assert.rejects(
+    solveAsync(2019, 1, 1, "14\nhej"),
+    (e) => e.kind === "invalidInput" && e.line === 2,
+  );
+
+  const reportedProgress = [];
+  assert.equal(
+    await solveAsync(2015, 4, 2, "abcdef", {
+      onProgress: (progress) => reportedProgress.push(progress),
+    }),
+    "6742839",
+  );
+  assert.ok(reportedProgress.length > 0);
+  assert.ok(reportedProgress.every((p) => p > 0 && p <= 1));
+
+  const controller = new AbortController();
+  const cancelled = solveAsync(2015, 4, 2, "abcdef", {
+    onProgress: () => controller.abort(new AbortError()),
+    signal: controller.signal,
+  });
+  await assert.rejects(cancelled, (e) => e instanceof AbortError);
+})();
@@ -0,0 +1,240 @@
+//! Request handling independent of the Workers runtime, so that it can be tested natively.
+use advent_of_code_service::{
+    Answers, AsyncAnswerCache, SolveError, SolveRequest, puzzles, solve_async,
+};
+use serde::Serialize;
+
+#[cfg(test)]
+use advent_of_code_service::{
+    AnswerCache, MemoryCache, SolveResponse, Synchronous, cache_key, complete_now,
+};
+
+/// The maximum number of problems in a batch request.
+const MAX_BATCH_SIZE: usize = 25;
+
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Response {
+    fn text(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            content_type: "text/plain",
+            body: body.into(),
+        }
+    }
+
+    fn json(status: u16, value: &impl Serialize) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Self {
+                status,
+                content_type: "application/json",
+                body,
+            },
+            Err(error) => Self::text(500, error.to_string()),
+        }
+    }
+
+    fn error(error: SolveError) -> Self {
+        Self::text(error.http_status(), error.message)
+    }
+}
+
+async fn handle_solve(
+    cache: &impl AsyncAnswerCache,
+    year: &str,
+    day: &str,
+    part: &str,
+    input: &str,
+) -> Response {
+    let request = match SolveRequest::parse(year, day, part, input) {
+        Ok(request) => request,
+        Err(error) => return Response::error(error),
+    };
+    match solve_async(&request, cache).await.into_result() {
+        Ok(answer) => Response::text(200, answer),
+        Err(error) => Response::error(error),
+    }
+}
+
+async fn handle_solve_json(cache: &impl AsyncAnswerCache, body: &str) -> Response {
+    let request: SolveRequest = match serde_json::from_str(body) {
+        Ok(request) => request,
+        Err(error) => return Response::text(400, format!("Invalid request: {error}")),
+    };
+    let response = solve_async(&request, cache).await;
+    let status = response.error.as_ref().map_or(200, SolveError::http_status);
+    Response::json(status, &response)
+}
+
+async fn handle_solve_both(
+    cache: &impl AsyncAnswerCache,
+    year: &str,
+    day: &str,
+    input: &str,
+) -> Response {
+    let request = match SolveRequest::parse(year, day, "1", input) {
+        Ok(request) => request,
+        Err(error) => return Response::error(error),
+    };
+    let part_one = match solve_async(&request, cache).await.into_result() {
+        Ok(answer) => answer,
+        Err(error) => return Response::error(error),
+    };
+    let part_two = if has_part_two(&request) {
+        let request = SolveRequest { part: 2, ..request };
+        match solve_async(&request, cache).await.into_result() {
+            Ok(answer) => Some(answer),
+            Err(error) => return Response::error(error),
+        }
+    } else {
+        None
+    };
+    Response::json(200, &Answers { part_one, part_two })
+}
+
+fn has_part_two(request: &SolveRequest) -> bool {
+    puzzles()
+        .iter()
+        .any(|puzzle| puzzle.year == request.year && puzzle.day == request.day && puzzle.parts == 2)
+}
+
+async fn handle_batch(cache: &impl AsyncAnswerCache, body: &str) -> Response {
+    let requests: Vec<SolveRequest> = match serde_json::from_str(body) {
+        Ok(requests) => requests,
+        Err(error) => return Response::text(400, format!("Invalid batch request: {error}")),
+    };
+    if requests.len() > MAX_BATCH_SIZE {
+        return Response::text(
+            400,
+            format!("Too many problems in batch - at most {MAX_BATCH_SIZE} are allowed"),
+        );
+    }
+
+    let mut responses = Vec::with_capacity(requests.len());
+    for request in &requests {
+        responses.push(solve_async(request, cache).await);
+    }
+    Response::json(200, &responses)
+}
+
+/// Handles a request with the specified method, path and body.
+pub async fn handle(
+    cache: &impl AsyncAnswerCache,
+    method: &str,
+    path: &str,
+    body: &str,
+) -> Response {
+    let segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+    match (method, segments.as_slice()) {
+        ("GET", [""]) => Response {
+            status: 200,
+            content_type: "text/html",
+            body: "<h1>Advent of Code API</h1>\n\
+                   <p>Check the <a href='https://aoc.fornwall.net/api/openapi.json'>OpenAPI document</a>.</p>"
+                .to_string(),
+        },
+        // Preflight requests of web pages on other origins, which are answered by the CORS headers:
+        ("OPTIONS", _) => Response::text(204, ""),
+        ("GET", ["puzzles"]) => Response::json(200, &puzzles()),
+        ("GET", ["worker-version"]) => Response::text(200, env!("CARGO_PKG_VERSION")),
+        ("POST", ["solve"]) => handle_solve_json(cache, body).await,
+        ("POST", ["solve", year, day, part]) => handle_solve(cache, year, day, part, body).await,
+        ("POST", ["solve-both", year, day]) => handle_solve_both(cache, year, day, body).await,
+        ("POST", ["batch"]) => handle_batch(cache, body).await,
+        (_, [""] | ["puzzles" | "worker-version"]) => Response::text(405, "Only GET is supported"),
+        (_, ["solve"] | ["solve", _, _, _] | ["solve-both", _, _] | ["batch"]) => {
+            Response::text(405, "Only POST is supported")
+        }
+        _ => Response::text(404, format!("Not found: {path}")),
+    }
+}
+
+#[cfg(test)]
+fn handle_now(cache: &MemoryCache, method: &str, path: &str, body: &str) -> Response {
+    complete_now(handle(&Synchronous(cache), method, path, body))
+}
+
+#[test]
+fn solve_and_cache() {
+    let cache = MemoryCache::default();
+
+    let response = handle_now(&cache, "POST", "/solve/2019/1/1", "14");
+    assert_eq!((response.status, response.body.as_str()), (200, "2"));
+    let key = cache_key(&SolveRequest::new(2019, 1, 1, "14"));
+    assert_eq!(cache.get(&key), Some("2".to_string()));
+
+    // Answers are served from the cache:
+    cache.put(&key, "cached");
+    let response = handle_now(&cache, "POST", "/solve/2019/1/1", "14");
+    assert_eq!(response.body, "cached");
+
+    let response = handle_now(&cache, "POST", "/solve/2019/1/1", "14\nhej");
+    assert_eq!(response.status, 400);
+    let response = handle_now(&cache, "POST", "/solve/2019/26/1", "14");
+    assert_eq!(response.status, 404);
+    let response = handle_now(&cache, "POST", "/solve/2019/1/x", "14");
+    assert_eq!(response.status, 404);
+    let response = handle_now(&cache, "POST", "/solve/2017/18/1", "jgz 1 0");
+    assert_eq!(response.status, 422);
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn solve_json_and_batch() {
+    let cache = MemoryCache::default();
+
+    let response = handle_now(&cache, "POST", "/solve-both/2019/1", "1969");
+    assert_eq!(response.content_type, "application/json");
+    assert_eq!(response.body, r#"{"partOne":"654","partTwo":"966"}"#);
+    let response = handle_now(&cache, "POST", "/solve-both/2019/25", "");
+    assert_eq!(response.status, 400);
+
+    let response = handle_now(
+        &cache,
+        "POST",
+        "/batch",
+        r#"[{"year":2019,"day":1,"part":1,"input":"14"},{"year":2019,"day":1,"part":3,"input":"14"}]"#,
+    );
+    assert_eq!(response.status, 200);
+    let responses: Vec<SolveResponse> = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(responses[0].answer.as_deref(), Some("2"));
+    assert_eq!(
+        responses[1]
+            .error
+            .as_ref()
+            .map(|error| error.message.as_str()),
+        Some("Unsupported year=2019, day=1, part=3")
+    );
+    let response = handle_now(&cache, "POST", "/batch", "[{}]");
+    assert_eq!(response.status, 400);
+
+    let response = handle_now(
+        &cache,
+        "POST",
+        "/solve",
+        r#"{"year":2017,"day":18,"part":1,"input":"jgz 1 0"}"#,
+    );
+    assert_eq!(response.status, 422);
+    assert!(response.body.starts_with(r#"{"error":{"kind":"timeout","#));
+}
+
+#[test]
+fn routes() {
+    let cache = MemoryCache::default();
+
+    let response = handle_now(&cache, "GET", "/puzzles", "");
+    assert!(
+        response
+            .body
+            .starts_with(r#"[{"year":2015,"day":1,"parts":2},"#)
+    );
+    assert_eq!(handle_now(&cache, "GET", "/", "").status, 200);
+    assert_eq!(handle_now(&cache, "GET", "/solve/2019/1/1", "").status, 405);
+    assert_eq!(handle_now(&cache, "POST", "/puzzles", "").status, 405);
+    assert_eq!(handle_now(&cache, "GET", "/unknown", "").status, 404);
+    assert_eq!(handle_now(&cache, "OPTIONS", "/solve", "").status, 204);
+}
@@ -1,9 +1,13 @@
 #![allow(clippy::future_not_send)]
-use advent_of_code::solve_raw;
+use advent_of_code_service::{AsyncAnswerCache, CORS_HEADERS};
 use worker::*;
 
+mod handler;
 mod utils;
 
+/// The KV namespace caching answers, which is optional - see wrangler.toml.
+const CACHE_BINDING: &str = "ANSWERS";
+
 fn log_request(req: &Request) {
     console_log!(
         "{} - [{}], located at: {:?}, within: {}",
@@ -18,44 +22,45 @@ fn log_request(req: &Request) {
     );
 }
 
+struct KvCache(Option<kv::KvStore>);
+
+impl AsyncAnswerCache for KvCache {
+    async fn get(&self, key: &str) -> Option<String> {
+        let store = self.0.as_ref()?;
+        store.get(key).text().await.ok().flatten()
+    }
+
+    async fn put(&self, key: &str, answer: &str) {
+        // Failing to cache an answer should not fail the request:
+        if let Some(Ok(put)) = self.0.as_ref().map(|store| store.put(key, answer)) {
+            let _ = put.execute().await;
+        }
+    }
+}
+
 #[event(fetch)]
-#[allow(clippy::unwrap_used)]
-pub async fn main(req: Request, env: Env, _ctx: worker::Context) -> Result<Response> {
+pub async fn main(mut req: Request, env: Env, _ctx: worker::Context) -> Result<Response> {
     log_request(&req);
 
     // Optionally, get more helpful error messages written to the console in the case of a panic.
     utils::set_panic_hook();
 
-    // Optionally, use the Router to handle matching endpoints, use ":name" placeholders, or "*name"
-    // catch-alls to match on specific patterns. Alternatively, use `Router::with_data(D)` to
-    // provide arbitrary data that will be accessible in each route via the `ctx.data()` method.
-    let router = Router::new();
-
-    // Add as many routes as your Worker needs! Each route will get a `Request` for handling HTTP
-    // functionality and a `RouteContext` which you can use to  and get route parameters and
-    // Environment bindings like KV Stores, Durable Objects, Secrets, and Variables.
-    router
-        .get("/", |_, _| Response::ok("Hello from Workers!"))
-        .post_async("/solve/:year/:day/:part", |mut req, ctx| async move {
-            let year = ctx.param("year").unwrap();
-            let day = ctx.param("day").unwrap();
-            let part = ctx.param("part").unwrap();
-            let input = req.text().await?;
-
-            let response = match solve_raw(year, day, part, &input) {
-                Ok(answer) => Response::ok(answer),
-                Err(message) => Response::error(message, 400),
-            }
-            .unwrap();
-
-            let headers = Headers::new();
-            headers.append("Access-Control-Allow-Origin", "*").unwrap();
-            Ok(response.with_headers(headers))
-        })
-        .get("/worker-version", |_, _ctx| {
-            let version = env!("CARGO_PKG_VERSION");
-            Response::ok(version)
-        })
-        .run(req, env)
-        .await
+    let method = req.method().to_string();
+    let body = if req.method() == Method::Post {
+        req.text().await?
+    } else {
+        String::new()
+    };
+    let cache = KvCache(env.kv(CACHE_BINDING).ok());
+
+    let response = handler::handle(&cache, &method, &req.path(), &body).await;
+
+    let headers = Headers::new();
+    for (name, value) in CORS_HEADERS {
+        headers.set(name, value)?;
+    }
+    headers.set("Content-Type", response.content_type)?;
+    Ok(Response::ok(response.body)?
+        .with_status(response.status)
+        .with_headers(headers))
 }